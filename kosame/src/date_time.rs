@@ -0,0 +1,125 @@
+//! Types of the PostgreSQL `date`, `time` and `interval` types, which have no equivalent in the
+//! standard library.
+//!
+//! The types hold the values the way PostgreSQL stores them. `date` and `time` convert to
+//! [`SystemTime`] and [`Duration`] respectively.
+//!
+//! With the `serde` feature, the types are serialized the way they are stored as well, not in
+//! ISO 8601 format: a [`Date`] as the number of days since 2000-01-01, a [`Time`] as the
+//! microseconds since midnight, and an [`Interval`] as its months, days and microseconds.
+
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Seconds from the Unix epoch to 2000-01-01, the epoch of PostgreSQL dates.
+const POSTGRES_EPOCH_SECONDS: u64 = 946_684_800;
+
+/// A PostgreSQL `date`, the number of days since 2000-01-01. It is serialized as that number,
+/// e.g. `0` for 2000-01-01.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct Date(i32);
+
+impl Date {
+    /// The date `infinity`, which is later than all other dates.
+    pub const INFINITY: Self = Self(i32::MAX);
+
+    /// The date `-infinity`, which is earlier than all other dates.
+    pub const NEG_INFINITY: Self = Self(i32::MIN);
+
+    #[inline]
+    #[must_use]
+    pub fn from_days_since_2000(days: i32) -> Self {
+        Self(days)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn days_since_2000(self) -> i32 {
+        self.0
+    }
+
+    /// The start of the day in UTC, or `None` for infinite dates.
+    #[must_use]
+    pub fn to_system_time(self) -> Option<SystemTime> {
+        if self == Self::INFINITY || self == Self::NEG_INFINITY {
+            return None;
+        }
+        let epoch = UNIX_EPOCH + Duration::from_secs(POSTGRES_EPOCH_SECONDS);
+        let offset = Duration::from_secs(u64::from(self.0.unsigned_abs()) * 86_400);
+        if self.0 >= 0 {
+            epoch.checked_add(offset)
+        } else {
+            epoch.checked_sub(offset)
+        }
+    }
+}
+
+/// A PostgreSQL `time` without time zone, the number of microseconds since midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct Time(i64);
+
+impl Time {
+    #[inline]
+    #[must_use]
+    pub fn from_micros(micros: i64) -> Self {
+        Self(micros)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn micros(self) -> i64 {
+        self.0
+    }
+
+    /// The time since midnight.
+    #[inline]
+    #[must_use]
+    pub fn to_duration(self) -> Duration {
+        Duration::from_micros(self.0.unsigned_abs())
+    }
+}
+
+/// A PostgreSQL `interval`. Months and days are stored separately from the rest, because their
+/// length depends on the date the interval is added to.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct Interval {
+    months: i32,
+    days: i32,
+    micros: i64,
+}
+
+impl Interval {
+    #[inline]
+    #[must_use]
+    pub fn new(months: i32, days: i32, micros: i64) -> Self {
+        Self {
+            months,
+            days,
+            micros,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn months(self) -> i32 {
+        self.months
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn days(self) -> i32 {
+        self.days
+    }
+
+    /// The rest of the interval after the months and days, in microseconds.
+    #[inline]
+    #[must_use]
+    pub fn micros(self) -> i64 {
+        self.micros
+    }
+}
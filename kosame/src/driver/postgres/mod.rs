@@ -1,5 +1,7 @@
 use crate::driver::Connection;
 
+// The synchronous client satisfies the async `Connection` trait by blocking.
+#[allow(clippy::unused_async_trait_impl)]
 impl Connection for postgres::Client {
     type Dialect = kosame_sql::postgres::Dialect;
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
@@ -19,6 +21,7 @@ impl Connection for postgres::Client {
     }
 }
 
+#[allow(clippy::unused_async_trait_impl)]
 impl Connection for postgres::Transaction<'_> {
     type Dialect = kosame_sql::postgres::Dialect;
     type Params<'a> = Vec<&'a (dyn postgres_types::ToSql + std::marker::Sync + 'a)>;
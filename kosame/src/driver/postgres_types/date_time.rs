@@ -0,0 +1,106 @@
+use bytes::{BufMut, BytesMut};
+use postgres_protocol::types;
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+use crate::date_time::{Date, Interval, Time};
+
+impl<'a> FromSql<'a> for Date {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::DATE
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        types::date_from_sql(raw).map(Self::from_days_since_2000)
+    }
+}
+
+impl ToSql for Date {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        types::date_to_sql(self.days_since_2000(), out);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::DATE
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Time {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TIME
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        types::time_from_sql(raw).map(Self::from_micros)
+    }
+}
+
+impl ToSql for Time {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        types::time_to_sql(self.micros(), out);
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TIME
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Interval {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let Ok(raw) = <[u8; 16]>::try_from(raw) else {
+            return Err("invalid interval length".into());
+        };
+        let (micros, rest) = raw.split_at(8);
+        let (days, months) = rest.split_at(4);
+        Ok(Self::new(
+            i32::from_be_bytes(months.try_into().unwrap()),
+            i32::from_be_bytes(days.try_into().unwrap()),
+            i64::from_be_bytes(micros.try_into().unwrap()),
+        ))
+    }
+}
+
+impl ToSql for Interval {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        out.put_i64(self.micros());
+        out.put_i32(self.days());
+        out.put_i32(self.months());
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::INTERVAL
+    }
+
+    postgres_types::to_sql_checked!();
+}
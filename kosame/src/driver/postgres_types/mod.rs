@@ -1,8 +1,17 @@
+use std::ops::Range;
+
+use bytes::BytesMut;
 use fallible_iterator::FallibleIterator;
 pub use postgres_protocol::types::int4_from_sql;
+use postgres_types::IsNull;
 pub use postgres_types::{FromSql, ToSql, Type};
 pub use tokio_postgres::Row;
 
+use crate::query::{Cursor, CursorRow, CursorValue};
+
+mod date_time;
+mod text_search;
+
 #[macro_export]
 macro_rules! pg_table {
     ($($tokens:tt)*) => {
@@ -82,8 +91,9 @@ where
 
         let array = postgres_protocol::types::array_from_sql(raw)?;
         let mut dimensions = array.dimensions();
+        // Empty arrays have no dimensions at all.
         let Some(dimension) = dimensions.next()? else {
-            return Err("array has no dimensions".into());
+            return Ok(Self::new(None));
         };
         if dimensions.next()?.is_some() {
             return Err("array has too many dimensions".into());
@@ -106,6 +116,22 @@ where
     }
 }
 
+/// Borrows a value as a bind parameter. Generated code bounds on this trait rather than on
+/// [`ToSql`] directly, so that the bound mentions a lifetime and an unsatisfied bound disables the
+/// impl instead of failing to compile.
+pub trait AsToSql<'a> {
+    fn as_to_sql(&'a self) -> &'a (dyn ToSql + Sync + 'a);
+}
+
+impl<'a, T> AsToSql<'a> for T
+where
+    T: ToSql + Sync + 'a,
+{
+    fn as_to_sql(&'a self) -> &'a (dyn ToSql + Sync + 'a) {
+        self
+    }
+}
+
 pub fn record_field_from_sql<'a, T>(
     buf: &'a [u8],
     offset: &mut usize,
@@ -130,3 +156,103 @@ where
         result
     }
 }
+
+impl ToSql for CursorValue {
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        // Cursors come back from clients, so the bytes are checked to be a valid value of the
+        // keyset column's type before they are sent.
+        match &self.0 {
+            Some(bytes) if is_valid_cursor_value(ty, bytes) => {
+                out.extend_from_slice(bytes);
+                Ok(IsNull::No)
+            }
+            Some(_) => Err("the cursor does not match the types of the keyset columns".into()),
+            None => Ok(IsNull::Yes),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(
+            *ty,
+            Type::BOOL
+                | Type::INT2
+                | Type::INT4
+                | Type::INT8
+                | Type::OID
+                | Type::FLOAT4
+                | Type::FLOAT8
+                | Type::NUMERIC
+                | Type::TEXT
+                | Type::VARCHAR
+                | Type::BPCHAR
+                | Type::NAME
+                | Type::BYTEA
+                | Type::UUID
+                | Type::DATE
+                | Type::TIME
+                | Type::TIMETZ
+                | Type::TIMESTAMP
+                | Type::TIMESTAMPTZ
+                | Type::INTERVAL
+        )
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+/// Checks that the bytes are a valid binary value of one of the types [`CursorValue`] accepts.
+fn is_valid_cursor_value(ty: &Type, bytes: &[u8]) -> bool {
+    match *ty {
+        Type::BOOL => bytes.len() == 1,
+        Type::INT2 => bytes.len() == 2,
+        Type::INT4 | Type::OID | Type::FLOAT4 | Type::DATE => bytes.len() == 4,
+        Type::INT8 | Type::FLOAT8 | Type::TIME | Type::TIMESTAMP | Type::TIMESTAMPTZ => {
+            bytes.len() == 8
+        }
+        Type::TIMETZ => bytes.len() == 12,
+        Type::UUID | Type::INTERVAL => bytes.len() == 16,
+        // The header holds the number of base 10000 digits that follow it.
+        Type::NUMERIC => {
+            bytes.len() >= 8
+                && bytes.len() == 8 + 2 * usize::from(u16::from_be_bytes([bytes[0], bytes[1]]))
+        }
+        Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME => {
+            std::str::from_utf8(bytes).is_ok()
+        }
+        Type::BYTEA => true,
+        _ => false,
+    }
+}
+
+impl<'a> FromSql<'a> for CursorValue {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Self(Some(raw.to_vec())))
+    }
+
+    fn from_sql_null(_ty: &Type) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        Ok(Self(None))
+    }
+
+    fn accepts(_ty: &Type) -> bool {
+        true
+    }
+}
+
+impl CursorRow for Row {
+    fn cursor(&self, columns: Range<usize>) -> crate::Result<Cursor> {
+        columns
+            .map(|index| {
+                self.try_get::<_, CursorValue>(index)
+                    .map_err(|e| crate::Error::Driver(Box::new(e)))
+            })
+            .collect::<crate::Result<_>>()
+            .map(Cursor::new)
+    }
+}
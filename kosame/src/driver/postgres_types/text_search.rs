@@ -0,0 +1,634 @@
+//! The binary wire format of the full-text search types, which holds the lexemes that the text
+//! representation of [`TsVector`] and [`TsQuery`] is printed from and parsed into.
+
+use std::{borrow::Cow, iter::Peekable, str::Chars};
+
+use bytes::{BufMut, BytesMut};
+use postgres_types::{FromSql, IsNull, ToSql, Type};
+
+use crate::text_search::{TsQuery, TsVector};
+
+impl<'a> FromSql<'a> for TsVector {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TS_VECTOR
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        mut raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let count = read_i32(&mut raw)?;
+        let mut text = String::new();
+        for index in 0..count {
+            if index > 0 {
+                text.push(' ');
+            }
+            push_lexeme(&mut text, read_cstr(&mut raw)?);
+            let positions = read_u16(&mut raw)?;
+            for index in 0..positions {
+                let position = read_u16(&mut raw)?;
+                text.push(if index == 0 { ':' } else { ',' });
+                text.push_str(&(position & 0x3fff).to_string());
+                match position >> 14 {
+                    3 => text.push('A'),
+                    2 => text.push('B'),
+                    1 => text.push('C'),
+                    _ => {}
+                }
+            }
+        }
+        Ok(Self::new(text))
+    }
+}
+
+impl<'a> FromSql<'a> for TsQuery {
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TSQUERY
+    }
+
+    fn from_sql(
+        _ty: &Type,
+        mut raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let count = read_i32(&mut raw)?;
+        let mut text = String::new();
+        if count > 0 {
+            let item = read_ts_query_item(&mut raw)?;
+            fmt_ts_query_item(&mut text, &item);
+        }
+        Ok(Self::new(text))
+    }
+}
+
+impl ToSql for TsVector {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let mut chars = self.as_str().chars().peekable();
+        let mut lexemes = Vec::<(String, Vec<u16>)>::new();
+        while skip_whitespace(&mut chars) {
+            let lexeme = read_lexeme(&mut chars, |char| char == ':')?;
+            let mut positions = Vec::new();
+            if chars.next_if_eq(&':').is_some() {
+                loop {
+                    let position = read_number(&mut chars)?;
+                    if position == 0 {
+                        return Err("tsvector positions start at 1".into());
+                    }
+                    // PostgreSQL clamps positions to the largest one it can store.
+                    let position = u16::try_from(position.min(0x3fff)).unwrap();
+                    let weight = match chars.next_if(|char| "AaBbCcDd".contains(*char)) {
+                        Some('A' | 'a') => 3,
+                        Some('B' | 'b') => 2,
+                        Some('C' | 'c') => 1,
+                        _ => 0,
+                    };
+                    positions.push(weight << 14 | position);
+                    if chars.next_if_eq(&',').is_none() {
+                        break;
+                    }
+                }
+            }
+            lexemes.push((lexeme, positions));
+        }
+
+        // PostgreSQL expects the lexemes sorted bytewise without duplicates, and the positions of
+        // each lexeme sorted without duplicates, keeping the strongest weight of a position.
+        lexemes.sort_by(|(a, _), (b, _)| a.cmp(b));
+        lexemes.dedup_by(|(lexeme, positions), (kept_lexeme, kept_positions)| {
+            let duplicate = lexeme == kept_lexeme;
+            if duplicate {
+                kept_positions.append(positions);
+            }
+            duplicate
+        });
+        out.put_i32(i32::try_from(lexemes.len())?);
+        for (lexeme, mut positions) in lexemes {
+            positions.sort_by_key(|position| (position & 0x3fff, u16::MAX - (position >> 14)));
+            positions.dedup_by_key(|position| *position & 0x3fff);
+            positions.truncate(256);
+            put_cstr(out, &lexeme)?;
+            out.put_u16(u16::try_from(positions.len()).unwrap());
+            for position in positions {
+                out.put_u16(position);
+            }
+        }
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TS_VECTOR
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+impl ToSql for TsQuery {
+    fn to_sql(
+        &self,
+        _ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn std::error::Error + Sync + Send>> {
+        let mut chars = self.as_str().chars().peekable();
+        // A query without lexemes is accepted by PostgreSQL and matches nothing.
+        if !skip_whitespace(&mut chars) {
+            out.put_i32(0);
+            return Ok(IsNull::No);
+        }
+        let item = parse_ts_query_or(&mut chars)?;
+        if skip_whitespace(&mut chars) {
+            return Err("syntax error in tsquery".into());
+        }
+        out.put_i32(i32::try_from(item.len())?);
+        item.write(out)?;
+        Ok(IsNull::No)
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        *ty == Type::TSQUERY
+    }
+
+    postgres_types::to_sql_checked!();
+}
+
+/// A node of a `tsquery` as sent by PostgreSQL in prefix order.
+enum TsQueryItem<'a> {
+    Value {
+        lexeme: Cow<'a, str>,
+        weight: u8,
+        prefix: bool,
+    },
+    Not(Box<TsQueryItem<'a>>),
+    Binary {
+        operator: TsQueryOperator,
+        lhs: Box<TsQueryItem<'a>>,
+        rhs: Box<TsQueryItem<'a>>,
+    },
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum TsQueryOperator {
+    And,
+    Or,
+    Phrase(i16),
+}
+
+impl TsQueryItem<'_> {
+    /// Binding strength of the item as used by PostgreSQL when printing a `tsquery`.
+    fn priority(&self) -> u8 {
+        match self {
+            Self::Value { .. } => 5,
+            Self::Not(_) => 4,
+            Self::Binary {
+                operator: TsQueryOperator::Phrase(_),
+                ..
+            } => 3,
+            Self::Binary {
+                operator: TsQueryOperator::And,
+                ..
+            } => 2,
+            Self::Binary {
+                operator: TsQueryOperator::Or,
+                ..
+            } => 1,
+        }
+    }
+
+    /// Number of items the node is sent as.
+    fn len(&self) -> usize {
+        match self {
+            Self::Value { .. } => 1,
+            Self::Not(operand) => 1 + operand.len(),
+            Self::Binary { lhs, rhs, .. } => 1 + lhs.len() + rhs.len(),
+        }
+    }
+
+    fn write(&self, out: &mut BytesMut) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+        match self {
+            Self::Value {
+                lexeme,
+                weight,
+                prefix,
+            } => {
+                out.put_u8(1);
+                out.put_u8(*weight);
+                out.put_u8(u8::from(*prefix));
+                put_cstr(out, lexeme)?;
+            }
+            Self::Not(operand) => {
+                out.put_u8(2);
+                out.put_u8(1);
+                operand.write(out)?;
+            }
+            Self::Binary { operator, lhs, rhs } => {
+                out.put_u8(2);
+                match operator {
+                    TsQueryOperator::And => out.put_u8(2),
+                    TsQueryOperator::Or => out.put_u8(3),
+                    TsQueryOperator::Phrase(distance) => {
+                        out.put_u8(4);
+                        out.put_i16(*distance);
+                    }
+                }
+                rhs.write(out)?;
+                lhs.write(out)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn read_ts_query_item<'a>(
+    raw: &mut &'a [u8],
+) -> Result<TsQueryItem<'a>, Box<dyn std::error::Error + Sync + Send>> {
+    match read_u8(raw)? {
+        1 => {
+            let weight = read_u8(raw)?;
+            let prefix = read_u8(raw)? != 0;
+            let lexeme = Cow::Borrowed(read_cstr(raw)?);
+            Ok(TsQueryItem::Value {
+                lexeme,
+                weight,
+                prefix,
+            })
+        }
+        2 => {
+            let operator = match read_u8(raw)? {
+                1 => return Ok(TsQueryItem::Not(Box::new(read_ts_query_item(raw)?))),
+                2 => TsQueryOperator::And,
+                3 => TsQueryOperator::Or,
+                4 => TsQueryOperator::Phrase(read_i16(raw)?),
+                operator => return Err(format!("unknown tsquery operator {operator}").into()),
+            };
+            // The right operand is sent before the left one.
+            let rhs = Box::new(read_ts_query_item(raw)?);
+            let lhs = Box::new(read_ts_query_item(raw)?);
+            Ok(TsQueryItem::Binary { operator, lhs, rhs })
+        }
+        ty => Err(format!("unknown tsquery item type {ty}").into()),
+    }
+}
+
+fn fmt_ts_query_item(text: &mut String, item: &TsQueryItem) {
+    let fmt_operand = |text: &mut String, operand: &TsQueryItem, parenthesize: bool| {
+        if parenthesize {
+            text.push_str("( ");
+            fmt_ts_query_item(text, operand);
+            text.push_str(" )");
+        } else {
+            fmt_ts_query_item(text, operand);
+        }
+    };
+
+    match item {
+        TsQueryItem::Value {
+            lexeme,
+            weight,
+            prefix,
+        } => {
+            push_lexeme(text, lexeme);
+            if *prefix || *weight != 0 {
+                text.push(':');
+            }
+            if *prefix {
+                text.push('*');
+            }
+            for (bit, letter) in [(8, 'A'), (4, 'B'), (2, 'C'), (1, 'D')] {
+                if weight & bit != 0 {
+                    text.push(letter);
+                }
+            }
+        }
+        TsQueryItem::Not(operand) => {
+            text.push('!');
+            fmt_operand(text, operand, operand.priority() < item.priority());
+        }
+        TsQueryItem::Binary { operator, lhs, rhs } => {
+            fmt_operand(text, lhs, lhs.priority() < item.priority());
+            match operator {
+                TsQueryOperator::And => text.push_str(" & "),
+                TsQueryOperator::Or => text.push_str(" | "),
+                TsQueryOperator::Phrase(1) => text.push_str(" <-> "),
+                TsQueryOperator::Phrase(distance) => {
+                    text.push_str(" <");
+                    text.push_str(&distance.to_string());
+                    text.push_str("> ");
+                }
+            }
+            // Only the phrase operator is not associative.
+            let parenthesize = rhs.priority() < item.priority()
+                || matches!(operator, TsQueryOperator::Phrase(_))
+                    && rhs.priority() == item.priority();
+            fmt_operand(text, rhs, parenthesize);
+        }
+    }
+}
+
+fn parse_ts_query_or<'a>(
+    chars: &mut Peekable<Chars>,
+) -> Result<TsQueryItem<'a>, Box<dyn std::error::Error + Sync + Send>> {
+    let mut lhs = parse_ts_query_and(chars)?;
+    while skip_whitespace(chars) && chars.next_if_eq(&'|').is_some() {
+        let rhs = parse_ts_query_and(chars)?;
+        lhs = TsQueryItem::Binary {
+            operator: TsQueryOperator::Or,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+    }
+    Ok(lhs)
+}
+
+fn parse_ts_query_and<'a>(
+    chars: &mut Peekable<Chars>,
+) -> Result<TsQueryItem<'a>, Box<dyn std::error::Error + Sync + Send>> {
+    let mut lhs = parse_ts_query_phrase(chars)?;
+    while skip_whitespace(chars) && chars.next_if_eq(&'&').is_some() {
+        let rhs = parse_ts_query_phrase(chars)?;
+        lhs = TsQueryItem::Binary {
+            operator: TsQueryOperator::And,
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+    }
+    Ok(lhs)
+}
+
+fn parse_ts_query_phrase<'a>(
+    chars: &mut Peekable<Chars>,
+) -> Result<TsQueryItem<'a>, Box<dyn std::error::Error + Sync + Send>> {
+    let mut lhs = parse_ts_query_not(chars)?;
+    while skip_whitespace(chars) && chars.next_if_eq(&'<').is_some() {
+        let distance = if chars.next_if_eq(&'-').is_some() {
+            1
+        } else {
+            i16::try_from(read_number(chars)?)
+                .ok()
+                .filter(|distance| *distance <= 16384)
+                .ok_or("tsquery phrase distances cannot exceed 16384")?
+        };
+        if chars.next_if_eq(&'>').is_none() {
+            return Err("syntax error in tsquery".into());
+        }
+        let rhs = parse_ts_query_not(chars)?;
+        lhs = TsQueryItem::Binary {
+            operator: TsQueryOperator::Phrase(distance),
+            lhs: Box::new(lhs),
+            rhs: Box::new(rhs),
+        };
+    }
+    Ok(lhs)
+}
+
+fn parse_ts_query_not<'a>(
+    chars: &mut Peekable<Chars>,
+) -> Result<TsQueryItem<'a>, Box<dyn std::error::Error + Sync + Send>> {
+    if !skip_whitespace(chars) {
+        return Err("unexpected end of tsquery".into());
+    }
+    if chars.next_if_eq(&'!').is_some() {
+        return Ok(TsQueryItem::Not(Box::new(parse_ts_query_not(chars)?)));
+    }
+    if chars.next_if_eq(&'(').is_some() {
+        let item = parse_ts_query_or(chars)?;
+        if !skip_whitespace(chars) || chars.next_if_eq(&')').is_none() {
+            return Err("syntax error in tsquery".into());
+        }
+        return Ok(item);
+    }
+
+    let lexeme = read_lexeme(chars, |char| "&|!()<:".contains(char))?;
+    let mut weight = 0;
+    let mut prefix = false;
+    if chars.next_if_eq(&':').is_some() {
+        while let Some(flag) = chars.next_if(|char| "*AaBbCcDd".contains(*char)) {
+            match flag {
+                '*' => prefix = true,
+                'A' | 'a' => weight |= 8,
+                'B' | 'b' => weight |= 4,
+                'C' | 'c' => weight |= 2,
+                _ => weight |= 1,
+            }
+        }
+    }
+    Ok(TsQueryItem::Value {
+        lexeme: Cow::Owned(lexeme),
+        weight,
+        prefix,
+    })
+}
+
+/// Skips whitespace and returns whether any characters are left.
+fn skip_whitespace(chars: &mut Peekable<Chars>) -> bool {
+    while chars.next_if(|char| char.is_whitespace()).is_some() {}
+    chars.peek().is_some()
+}
+
+/// Reads a lexeme that is either quoted, with `''` standing for a quote, or ends at whitespace or
+/// a character matching `is_end`. Backslashes escape the following character in both forms.
+fn read_lexeme(
+    chars: &mut Peekable<Chars>,
+    is_end: impl Fn(char) -> bool,
+) -> Result<String, Box<dyn std::error::Error + Sync + Send>> {
+    let mut lexeme = String::new();
+    if chars.next_if_eq(&'\'').is_some() {
+        loop {
+            match chars.next() {
+                Some('\'') if chars.next_if_eq(&'\'').is_none() => break,
+                Some('\\') => lexeme.push(chars.next().ok_or("unterminated lexeme")?),
+                Some(char) => lexeme.push(char),
+                None => return Err("unterminated lexeme".into()),
+            }
+        }
+    } else {
+        while let Some(char) = chars.next_if(|char| !char.is_whitespace() && !is_end(*char)) {
+            match char {
+                '\'' => return Err("quotes inside of unquoted lexemes must be escaped".into()),
+                '\\' => lexeme.push(chars.next().ok_or("unterminated lexeme")?),
+                char => lexeme.push(char),
+            }
+        }
+    }
+    if lexeme.is_empty() {
+        return Err("lexemes cannot be empty".into());
+    }
+    Ok(lexeme)
+}
+
+fn read_number(
+    chars: &mut Peekable<Chars>,
+) -> Result<u32, Box<dyn std::error::Error + Sync + Send>> {
+    let mut digits = String::new();
+    while let Some(digit) = chars.next_if(char::is_ascii_digit) {
+        digits.push(digit);
+    }
+    if digits.is_empty() {
+        return Err("expected a number".into());
+    }
+    // Numbers too large for `u32` are clamped like positions, or rejected like distances.
+    Ok(digits.parse().unwrap_or(u32::MAX))
+}
+
+fn put_cstr(
+    out: &mut BytesMut,
+    string: &str,
+) -> Result<(), Box<dyn std::error::Error + Sync + Send>> {
+    if string.contains('\0') {
+        return Err("lexemes cannot contain null characters".into());
+    }
+    out.put_slice(string.as_bytes());
+    out.put_u8(0);
+    Ok(())
+}
+
+/// Pushes a lexeme quoted the way PostgreSQL prints it in `tsvector` and `tsquery` values.
+fn push_lexeme(text: &mut String, lexeme: &str) {
+    text.push('\'');
+    for char in lexeme.chars() {
+        if char == '\'' || char == '\\' {
+            text.push(char);
+        }
+        text.push(char);
+    }
+    text.push('\'');
+}
+
+fn read_bytes<const N: usize>(
+    raw: &mut &[u8],
+) -> Result<[u8; N], Box<dyn std::error::Error + Sync + Send>> {
+    let Some((bytes, rest)) = raw.split_first_chunk::<N>() else {
+        return Err("unexpected end of buffer".into());
+    };
+    *raw = rest;
+    Ok(*bytes)
+}
+
+fn read_u8(raw: &mut &[u8]) -> Result<u8, Box<dyn std::error::Error + Sync + Send>> {
+    read_bytes::<1>(raw).map(|[byte]| byte)
+}
+
+fn read_u16(raw: &mut &[u8]) -> Result<u16, Box<dyn std::error::Error + Sync + Send>> {
+    read_bytes(raw).map(u16::from_be_bytes)
+}
+
+fn read_i16(raw: &mut &[u8]) -> Result<i16, Box<dyn std::error::Error + Sync + Send>> {
+    read_bytes(raw).map(i16::from_be_bytes)
+}
+
+fn read_i32(raw: &mut &[u8]) -> Result<i32, Box<dyn std::error::Error + Sync + Send>> {
+    read_bytes(raw).map(i32::from_be_bytes)
+}
+
+fn read_cstr<'a>(raw: &mut &'a [u8]) -> Result<&'a str, Box<dyn std::error::Error + Sync + Send>> {
+    let Some(end) = raw.iter().position(|byte| *byte == 0) else {
+        return Err("unterminated string".into());
+    };
+    let string = std::str::from_utf8(&raw[..end])?;
+    *raw = &raw[end + 1..];
+    Ok(string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes the output of PostgreSQL's `tsvectorsend` and `tsquerysend` written as hex.
+    fn hex(hex: &str) -> Vec<u8> {
+        (0..hex.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&hex[index..index + 2], 16).unwrap())
+            .collect()
+    }
+
+    fn encode(value: &impl ToSql, ty: &Type) -> Vec<u8> {
+        let mut out = BytesMut::new();
+        value.to_sql(ty, &mut out).unwrap();
+        out.to_vec()
+    }
+
+    fn assert_ts_vector(text: &str, raw: &str, printed: &str) {
+        let raw = hex(raw);
+        let encoded = encode(&TsVector::new(text.to_string()), &Type::TS_VECTOR);
+        assert_eq!(encoded, raw);
+        let decoded = TsVector::from_sql(&Type::TS_VECTOR, &raw).unwrap();
+        assert_eq!(decoded.as_str(), printed);
+        assert_eq!(encode(&decoded, &Type::TS_VECTOR), raw);
+    }
+
+    fn assert_ts_query(text: &str, raw: &str, printed: &str) {
+        let raw = hex(raw);
+        let encoded = encode(&TsQuery::new(text.to_string()), &Type::TSQUERY);
+        assert_eq!(encoded, raw);
+        let decoded = TsQuery::from_sql(&Type::TSQUERY, &raw).unwrap();
+        assert_eq!(decoded.as_str(), printed);
+        assert_eq!(encode(&decoded, &Type::TSQUERY), raw);
+    }
+
+    #[test]
+    fn ts_vector() {
+        assert_ts_vector(
+            "'fat':2,4A 'rat':3 'it''s':1B,5C",
+            "000000036661740000020002c00469742773000002800140057261740000010003",
+            "'fat':2,4A 'it''s':1B,5C 'rat':3",
+        );
+        assert_ts_vector("b a:1", "0000000261000001000162000000", "'a':1 'b'");
+        assert_ts_vector("", "00000000", "");
+    }
+
+    #[test]
+    fn ts_vector_normalization() {
+        // Duplicate lexemes are merged, positions are sorted and keep their strongest weight.
+        assert_eq!(
+            encode(&TsVector::new("b:3 a b:1,3A".to_string()), &Type::TS_VECTOR),
+            hex("0000000261000000620000020001c003"),
+        );
+        assert_eq!(
+            encode(&TsVector::new("a:20000".to_string()), &Type::TS_VECTOR),
+            hex("00000001610000013fff"),
+        );
+    }
+
+    #[test]
+    fn ts_vector_errors() {
+        for text in ["''", "a:0", "'a", "a:", "it's"] {
+            let mut out = BytesMut::new();
+            assert!(
+                TsVector::new(text.to_string())
+                    .to_sql(&Type::TS_VECTOR, &mut out)
+                    .is_err(),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn ts_query() {
+        assert_ts_query(
+            "fat & (rat | !cat) <2> 'it''s':*AB",
+            "00000008020202040002010c01697427730002030201010000636174000100007261740001000066617400",
+            "'fat' & ( 'rat' | !'cat' ) <2> 'it''s':*AB",
+        );
+        assert_ts_query(
+            "a <-> (b <-> c)",
+            "000000050204000102040001010000630001000062000100006100",
+            "'a' <-> ( 'b' <-> 'c' )",
+        );
+        assert_ts_query("!a", "0000000202010100006100", "!'a'");
+        assert_ts_query("  ", "00000000", "");
+    }
+
+    #[test]
+    fn ts_query_errors() {
+        for text in ["a &", "(a", "a b", "a <1 b", "a <20000> b", "&"] {
+            let mut out = BytesMut::new();
+            assert!(
+                TsQuery::new(text.to_string())
+                    .to_sql(&Type::TSQUERY, &mut out)
+                    .is_err(),
+                "{text}"
+            );
+        }
+    }
+}
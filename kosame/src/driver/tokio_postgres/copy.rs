@@ -0,0 +1,306 @@
+use std::{borrow::Borrow, marker::PhantomData, pin::Pin, task::Poll};
+
+use bytes::Bytes;
+use futures_core::Stream;
+use kosame_repr::schema::Table;
+use kosame_sql::FmtSql;
+use postgres_types::{ToSql, Type};
+use tokio_postgres::{
+    CopyInSink, CopyOutStream, Statement,
+    binary_copy::{BinaryCopyInWriter, BinaryCopyOutStream},
+};
+pub use tokio_postgres::{Error, binary_copy::BinaryCopyOutRow};
+
+/// A row type that covers every column of a table, in order, so that it can be copied in and out
+/// of the table with the binary `COPY` format. It is implemented by the `Select` struct that
+/// `table!` generates for every table.
+pub trait CopyRow {
+    /// The table that the row belongs to.
+    const TABLE: Table<'static>;
+}
+
+/// A [`CopyRow`] that can be written to a `COPY ... FROM STDIN`.
+pub trait CopyInRow<'a>: CopyRow {
+    /// The values of the columns of the row, in the order of [`CopyRow::TABLE`].
+    fn copy_in_values(&'a self) -> Vec<&'a (dyn ToSql + Sync + 'a)>;
+}
+
+/// A [`CopyRow`] that can be read from a `COPY ... TO STDOUT`.
+pub trait CopyOutRow<'a>: CopyRow + Sized {
+    fn from_copy_out_row(row: &'a BinaryCopyOutRow) -> Result<Self, Error>;
+}
+
+/// The clients that `COPY` statements can be run on.
+pub trait CopyClient {
+    fn prepare(&self, sql: &str) -> impl Future<Output = Result<Statement, Error>> + Send;
+
+    fn copy_in(&self, sql: &str) -> impl Future<Output = Result<CopyInSink<Bytes>, Error>> + Send;
+
+    fn copy_out(&self, sql: &str) -> impl Future<Output = Result<CopyOutStream, Error>> + Send;
+}
+
+impl CopyClient for tokio_postgres::Client {
+    fn prepare(&self, sql: &str) -> impl Future<Output = Result<Statement, Error>> + Send {
+        tokio_postgres::Client::prepare(self, sql)
+    }
+
+    fn copy_in(&self, sql: &str) -> impl Future<Output = Result<CopyInSink<Bytes>, Error>> + Send {
+        tokio_postgres::Client::copy_in(self, sql)
+    }
+
+    fn copy_out(&self, sql: &str) -> impl Future<Output = Result<CopyOutStream, Error>> + Send {
+        tokio_postgres::Client::copy_out(self, sql)
+    }
+}
+
+impl CopyClient for tokio_postgres::Transaction<'_> {
+    fn prepare(&self, sql: &str) -> impl Future<Output = Result<Statement, Error>> + Send {
+        tokio_postgres::Transaction::prepare(self, sql)
+    }
+
+    fn copy_in(&self, sql: &str) -> impl Future<Output = Result<CopyInSink<Bytes>, Error>> + Send {
+        tokio_postgres::Transaction::copy_in(self, sql)
+    }
+
+    fn copy_out(&self, sql: &str) -> impl Future<Output = Result<CopyOutStream, Error>> + Send {
+        tokio_postgres::Transaction::copy_out(self, sql)
+    }
+}
+
+/// Writes the rows into the table of `R` with `COPY ... FROM STDIN` and returns the number of
+/// copied rows.
+pub async fn copy_in<R, C, I>(client: &C, rows: I) -> crate::Result<u64>
+where
+    R: for<'a> CopyInRow<'a>,
+    C: CopyClient,
+    I: IntoIterator,
+    I::Item: Borrow<R>,
+{
+    let types = column_types::<R>(client).await?;
+
+    let sink = client
+        .copy_in(&copy_in_sql::<R>()?)
+        .await
+        .map_err(driver_error)?;
+    let writer = BinaryCopyInWriter::new(sink, &types);
+    let mut writer = std::pin::pin!(writer);
+    for row in rows {
+        writer
+            .as_mut()
+            .write(&row.borrow().copy_in_values())
+            .await
+            .map_err(driver_error)?;
+    }
+    writer.finish().await.map_err(driver_error)
+}
+
+/// Reads all rows of the table of `R` with `COPY ... TO STDOUT`. The rows are streamed from the
+/// database as they are read from the returned [`CopyOut`].
+pub async fn copy_out<R, C>(client: &C) -> crate::Result<CopyOut<R>>
+where
+    R: for<'a> CopyOutRow<'a>,
+    C: CopyClient,
+{
+    let types = column_types::<R>(client).await?;
+
+    let stream = client
+        .copy_out(&copy_out_sql::<R>()?)
+        .await
+        .map_err(driver_error)?;
+    Ok(CopyOut {
+        stream: Box::pin(BinaryCopyOutStream::new(stream, &types)),
+        row: PhantomData,
+    })
+}
+
+/// The rows of a `COPY ... TO STDOUT`, created by [`copy_out`].
+pub struct CopyOut<R> {
+    stream: Pin<Box<BinaryCopyOutStream>>,
+    row: PhantomData<fn() -> R>,
+}
+
+impl<R> CopyOut<R>
+where
+    R: for<'a> CopyOutRow<'a>,
+{
+    /// Reads the next row, or returns `None` once all rows have been read.
+    pub async fn next(&mut self) -> Option<crate::Result<R>> {
+        std::future::poll_fn(|cx| Pin::new(&mut *self).poll_next(cx)).await
+    }
+
+    /// Reads all remaining rows.
+    pub async fn into_vec(mut self) -> crate::Result<Vec<R>> {
+        let mut rows = Vec::new();
+        while let Some(row) = self.next().await {
+            rows.push(row?);
+        }
+        Ok(rows)
+    }
+}
+
+impl<R> Stream for CopyOut<R>
+where
+    R: for<'a> CopyOutRow<'a>,
+{
+    type Item = crate::Result<R>;
+
+    fn poll_next(
+        mut self: Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        self.stream.as_mut().poll_next(cx).map(|row| {
+            row.map(|row| {
+                row.and_then(|row| R::from_copy_out_row(&row))
+                    .map_err(driver_error)
+            })
+        })
+    }
+}
+
+/// Returns the quoted name of the table of `R` and its quoted, comma separated column names.
+fn table_and_columns<R>() -> crate::Result<(String, String)>
+where
+    R: CopyRow,
+{
+    type Dialect = kosame_sql::postgres::Dialect;
+
+    let table = R::TABLE.name().to_sql_string::<Dialect>()?;
+    let columns = R::TABLE
+        .columns()
+        .iter()
+        .map(|column| column.name().to_sql_string::<Dialect>())
+        .collect::<Result<Vec<_>, _>>()?
+        .join(", ");
+    Ok((table, columns))
+}
+
+/// The `COPY ... FROM STDIN` statement that [`copy_in`] writes the rows of `R` with.
+fn copy_in_sql<R>() -> crate::Result<String>
+where
+    R: CopyRow,
+{
+    let (table, columns) = table_and_columns::<R>()?;
+    Ok(format!(
+        "copy {table} ({columns}) from stdin with (format binary)"
+    ))
+}
+
+/// The `COPY ... TO STDOUT` statement that [`copy_out`] reads the rows of `R` with.
+fn copy_out_sql<R>() -> crate::Result<String>
+where
+    R: CopyRow,
+{
+    let (table, columns) = table_and_columns::<R>()?;
+    Ok(format!(
+        "copy {table} ({columns}) to stdout with (format binary)"
+    ))
+}
+
+/// Returns the database types of the columns, which the binary format is encoded with. They
+/// follow from the declared column types, except for types that only the database knows, like
+/// enums and extension types, whose OIDs are looked up by preparing a `select` of the columns.
+async fn column_types<R>(client: &impl CopyClient) -> crate::Result<Vec<Type>>
+where
+    R: CopyRow,
+{
+    let types = R::TABLE
+        .columns()
+        .iter()
+        .map(|column| builtin_type(column.data_type().as_str()))
+        .collect::<Option<Vec<_>>>();
+    if let Some(types) = types {
+        return Ok(types);
+    }
+
+    let (table, columns) = table_and_columns::<R>()?;
+    let statement = client
+        .prepare(&format!("select {columns} from {table}"))
+        .await
+        .map_err(driver_error)?;
+    Ok(statement
+        .columns()
+        .iter()
+        .map(|column| column.type_().clone())
+        .collect())
+}
+
+/// Returns the built-in database type of a column declared with the given type, e.g. `int[]`.
+fn builtin_type(data_type: &str) -> Option<Type> {
+    let (name, array) = match data_type.split_once('[') {
+        Some((name, _)) => (name, true),
+        None => (data_type, false),
+    };
+    let (scalar_type, array_type) = match name {
+        "bool" => (Type::BOOL, Type::BOOL_ARRAY),
+        "char" => (Type::CHAR, Type::CHAR_ARRAY),
+        "smallint" | "smallserial" => (Type::INT2, Type::INT2_ARRAY),
+        "int" | "serial" => (Type::INT4, Type::INT4_ARRAY),
+        "oid" => (Type::OID, Type::OID_ARRAY),
+        "bigint" | "bigserial" => (Type::INT8, Type::INT8_ARRAY),
+        "real" => (Type::FLOAT4, Type::FLOAT4_ARRAY),
+        "varchar" => (Type::VARCHAR, Type::VARCHAR_ARRAY),
+        "text" => (Type::TEXT, Type::TEXT_ARRAY),
+        "name" => (Type::NAME, Type::NAME_ARRAY),
+        "bytea" => (Type::BYTEA, Type::BYTEA_ARRAY),
+        "timestamp" => (Type::TIMESTAMP, Type::TIMESTAMP_ARRAY),
+        "timestamptz" => (Type::TIMESTAMPTZ, Type::TIMESTAMPTZ_ARRAY),
+        "date" => (Type::DATE, Type::DATE_ARRAY),
+        "time" => (Type::TIME, Type::TIME_ARRAY),
+        "interval" => (Type::INTERVAL, Type::INTERVAL_ARRAY),
+        "inet" => (Type::INET, Type::INET_ARRAY),
+        "tsvector" => (Type::TS_VECTOR, Type::TS_VECTOR_ARRAY),
+        "tsquery" => (Type::TSQUERY, Type::TSQUERY_ARRAY),
+        "uuid" => (Type::UUID, Type::UUID_ARRAY),
+        "json" => (Type::JSON, Type::JSON_ARRAY),
+        "jsonb" => (Type::JSONB, Type::JSONB_ARRAY),
+        "numeric" => (Type::NUMERIC, Type::NUMERIC_ARRAY),
+        _ => return None,
+    };
+    Some(if array { array_type } else { scalar_type })
+}
+
+fn driver_error(error: Error) -> crate::Error {
+    crate::Error::Driver(Box::new(error))
+}
+
+#[cfg(test)]
+mod tests {
+    use kosame_repr::schema::{Column, Table};
+    use postgres_types::Type;
+
+    use super::{CopyRow, builtin_type, copy_in_sql, copy_out_sql};
+
+    struct Row;
+
+    impl CopyRow for Row {
+        const TABLE: Table<'static> = Table::new(
+            "Posts",
+            &[
+                &Column::new("id", "int", true, true, None),
+                &Column::new("user", "text", false, true, None),
+                &Column::new("createdAt", "timestamptz", false, false, None),
+            ],
+            &[],
+        );
+    }
+
+    #[test]
+    fn quotes_table_and_columns() {
+        assert_eq!(
+            copy_in_sql::<Row>().unwrap(),
+            r#"copy "Posts" ("id", "user", "createdAt") from stdin with (format binary)"#
+        );
+        assert_eq!(
+            copy_out_sql::<Row>().unwrap(),
+            r#"copy "Posts" ("id", "user", "createdAt") to stdout with (format binary)"#
+        );
+    }
+
+    #[test]
+    fn column_types_follow_the_declaration() {
+        assert_eq!(builtin_type("int"), Some(Type::INT4));
+        assert_eq!(builtin_type("text[]"), Some(Type::TEXT_ARRAY));
+        assert_eq!(builtin_type("tsvector"), Some(Type::TS_VECTOR));
+        assert_eq!(builtin_type("mood"), None);
+    }
+}
@@ -1,3 +1,7 @@
+mod copy;
+
+pub use copy::*;
+
 use crate::driver::Connection;
 
 impl Connection for tokio_postgres::Client {
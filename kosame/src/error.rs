@@ -2,6 +2,8 @@
 pub enum Error {
     #[error("unexpected number of rows in result set")]
     RowCount,
+    #[error("invalid cursor")]
+    InvalidCursor,
     #[error("SQL formatting failed")]
     FmtSql(
         #[from]
@@ -0,0 +1,325 @@
+//! Type-level helpers for the type inference of the Kosame macros.
+//!
+//! Whether a column is nullable is only known to the table macro, so statements and queries
+//! combine the nullability of their operands with these traits instead.
+
+/// Marks an expression that cannot be null.
+pub struct NotNull;
+
+/// Marks an expression that may be null.
+pub struct Nullable;
+
+pub trait Nullability {
+    /// [`Nullable`] if either `Self` or `N` is [`Nullable`].
+    type Or<N: Nullability>: Nullability;
+
+    /// [`Nullable`] if both `Self` and `N` are [`Nullable`].
+    type And<N: Nullability>: Nullability;
+
+    /// `T` wrapped in an [`Option`] if `Self` is [`Nullable`].
+    type Apply<T>;
+}
+
+impl Nullability for NotNull {
+    type Or<N: Nullability> = N;
+    type And<N: Nullability> = NotNull;
+    type Apply<T> = T;
+}
+
+impl Nullability for Nullable {
+    type Or<N: Nullability> = Nullable;
+    type And<N: Nullability> = N;
+    type Apply<T> = Option<T>;
+}
+
+/// The result type of an arithmetic operator, following the numeric type promotion of
+/// PostgreSQL. Operands of the same type keep their type.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of arithmetic on values of type `{Self}` and `{Rhs}`",
+    note = "add a type override to the expression"
+)]
+pub trait Arithmetic<Rhs> {
+    type Output;
+}
+
+impl<T> Arithmetic<T> for T {
+    type Output = T;
+}
+
+/// The common type of values that are combined into one, like the arguments of `coalesce`.
+/// Unlike arithmetic operators, PostgreSQL combines integers and `real` into `real`.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the common type of values of type `{Self}` and `{Rhs}`",
+    note = "add a type override to the expression"
+)]
+pub trait Common<Rhs> {
+    type Output;
+}
+
+impl<T> Common<T> for T {
+    type Output = T;
+}
+
+macro_rules! promote {
+    ($trait:ident { $($lhs:ty, $rhs:ty => $output:ty;)* }) => {
+        $(
+            impl $trait<$rhs> for $lhs {
+                type Output = $output;
+            }
+
+            impl $trait<$lhs> for $rhs {
+                type Output = $output;
+            }
+        )*
+    };
+}
+
+promote!(Arithmetic {
+    i16, i32 => i32;
+    i16, i64 => i64;
+    i32, i64 => i64;
+    i16, f32 => f64;
+    i32, f32 => f64;
+    i64, f32 => f64;
+    i16, f64 => f64;
+    i32, f64 => f64;
+    i64, f64 => f64;
+    f32, f64 => f64;
+});
+
+// Timestamps, dates and times are shifted by intervals, and dates also by a number of days.
+promote!(Arithmetic {
+    std::time::SystemTime, crate::date_time::Interval => std::time::SystemTime;
+    crate::date_time::Date, i32 => crate::date_time::Date;
+    crate::date_time::Date, crate::date_time::Interval => std::time::SystemTime;
+    crate::date_time::Date, crate::date_time::Time => std::time::SystemTime;
+    crate::date_time::Time, crate::date_time::Interval => crate::date_time::Time;
+});
+
+promote!(Common {
+    i16, i32 => i32;
+    i16, i64 => i64;
+    i32, i64 => i64;
+    i16, f32 => f32;
+    i32, f32 => f32;
+    i64, f32 => f32;
+    i16, f64 => f64;
+    i32, f64 => f64;
+    i64, f64 => f64;
+    f32, f64 => f64;
+});
+
+// `numeric` absorbs integers, but mixing it with floats yields `double precision`, or `real` for
+// the common type, because floats are preferred over `numeric`.
+#[cfg(feature = "rust_decimal")]
+promote!(Arithmetic {
+    i16, rust_decimal::Decimal => rust_decimal::Decimal;
+    i32, rust_decimal::Decimal => rust_decimal::Decimal;
+    i64, rust_decimal::Decimal => rust_decimal::Decimal;
+    f32, rust_decimal::Decimal => f64;
+    f64, rust_decimal::Decimal => f64;
+});
+
+#[cfg(feature = "rust_decimal")]
+promote!(Common {
+    i16, rust_decimal::Decimal => rust_decimal::Decimal;
+    i32, rust_decimal::Decimal => rust_decimal::Decimal;
+    i64, rust_decimal::Decimal => rust_decimal::Decimal;
+    f32, rust_decimal::Decimal => f32;
+    f64, rust_decimal::Decimal => f64;
+});
+
+/// The result type of the `sum` aggregate function.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `sum` for values of type `{Self}`",
+    note = "add a type override to the expression"
+)]
+pub trait Sum {
+    type Output;
+}
+
+/// The result type of the `avg` aggregate function.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `avg` for values of type `{Self}`",
+    note = "add a type override to the expression"
+)]
+pub trait Avg {
+    type Output;
+}
+
+macro_rules! projection {
+    ($trait:ident { $($input:ty => $output:ty;)* }) => {
+        $(
+            impl $trait for $input {
+                type Output = $output;
+            }
+        )*
+    };
+}
+
+projection!(Sum {
+    i16 => i64;
+    i32 => i64;
+    f32 => f32;
+    f64 => f64;
+});
+
+projection!(Avg {
+    f32 => f64;
+    f64 => f64;
+});
+
+// `sum(bigint)` and `avg` of integers return `numeric`.
+#[cfg(feature = "rust_decimal")]
+projection!(Sum {
+    i64 => rust_decimal::Decimal;
+    rust_decimal::Decimal => rust_decimal::Decimal;
+});
+
+#[cfg(feature = "rust_decimal")]
+projection!(Avg {
+    i16 => rust_decimal::Decimal;
+    i32 => rust_decimal::Decimal;
+    i64 => rust_decimal::Decimal;
+    rust_decimal::Decimal => rust_decimal::Decimal;
+});
+
+/// The result type of the `^` operator, which computes integers as `double precision`.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `^` for values of type `{Self}`",
+    note = "add a type override to the expression"
+)]
+pub trait Power {
+    type Output;
+}
+
+projection!(Power {
+    i16 => f64;
+    i32 => f64;
+    i64 => f64;
+    f32 => f64;
+    f64 => f64;
+});
+
+#[cfg(feature = "rust_decimal")]
+projection!(Power {
+    rust_decimal::Decimal => rust_decimal::Decimal;
+});
+
+/// The arguments a function declared with `function!` takes, checked against each of its calls.
+/// `A` is a tuple of the types of the arguments of a call, and `M` a tuple of their
+/// [`Argument`] markers.
+#[diagnostic::on_unimplemented(
+    message = "wrong number of arguments for the declared function",
+    note = "the arguments must match the `create function` declaration"
+)]
+pub trait Arguments<A, M> {}
+
+/// Whether a value of type `Self` can be passed as a function argument of type `T`, as it is or
+/// with an implicit cast. `M` tells the impls apart, so that they do not overlap.
+#[diagnostic::on_unimplemented(
+    message = "cannot pass a value of type `{Self}` as an argument of type `{T}`",
+    note = "add a cast to the argument"
+)]
+pub trait Argument<T, M> {}
+
+/// Marks an argument that has the declared type.
+pub struct Exact;
+
+/// Marks an argument that PostgreSQL casts to the declared type implicitly.
+pub struct Implicit;
+
+/// The type of an argument that cannot be inferred, like a string literal, which PostgreSQL
+/// reads as any type. It is passed as any argument type.
+pub struct Unknown;
+
+impl<T> Argument<T, Exact> for T {}
+
+impl<T> Argument<T, Unknown> for Unknown {}
+
+// Array elements of a declared argument may be null.
+impl<T> Argument<Vec<Option<T>>, Implicit> for Vec<T> {}
+
+macro_rules! implicit_cast {
+    ($($from:ty => $($to:ty),*;)*) => {
+        $($(
+            impl Argument<$to, Implicit> for $from {}
+        )*)*
+    };
+}
+
+implicit_cast! {
+    i16 => i32, i64, f32, f64;
+    i32 => i64, f32, f64;
+    i64 => f32, f64;
+    f32 => f64;
+    crate::date_time::Date => std::time::SystemTime;
+    crate::date_time::Time => crate::date_time::Interval;
+}
+
+#[cfg(feature = "rust_decimal")]
+implicit_cast! {
+    i16 => rust_decimal::Decimal;
+    i32 => rust_decimal::Decimal;
+    i64 => rust_decimal::Decimal;
+    rust_decimal::Decimal => f32, f64;
+}
+
+/// The element type of an array, e.g. for a subscript like `tags[1]` or `unnest(tags)`.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the element type of `{Self}`",
+    note = "add a type override to the expression"
+)]
+pub trait Element {
+    type Output;
+}
+
+impl<T> Element for Vec<T> {
+    type Output = T;
+}
+
+/// The result type of the `||` operator. Operands of the same type keep their type, and arrays
+/// can be concatenated with a single element.
+#[diagnostic::on_unimplemented(
+    message = "cannot infer the type of `||` for values of type `{Self}` and `{Rhs}`",
+    note = "add a type override to the expression"
+)]
+pub trait Concat<Rhs> {
+    type Output;
+}
+
+impl Concat<String> for String {
+    type Output = String;
+}
+
+impl<T> Concat<Vec<T>> for Vec<T> {
+    type Output = Vec<T>;
+}
+
+impl<T> Concat<T> for Vec<T> {
+    type Output = Vec<T>;
+}
+
+// Text can be concatenated with any non-array value, which is converted to text.
+macro_rules! concat_text {
+    ($($other:ty),*) => {
+        $(
+            impl Concat<$other> for String {
+                type Output = String;
+            }
+
+            impl Concat<String> for $other {
+                type Output = String;
+            }
+        )*
+    };
+}
+
+concat_text!(i16, i32, i64, f32, f64, bool);
+
+#[cfg(feature = "rust_decimal")]
+concat_text!(rust_decimal::Decimal);
+
+impl Concat<crate::text_search::TsVector> for crate::text_search::TsVector {
+    type Output = crate::text_search::TsVector;
+}
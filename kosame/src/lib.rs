@@ -2,9 +2,12 @@ pub use kosame_macro::*;
 pub use kosame_repr as repr;
 pub use kosame_sql as sql;
 
+#[doc(hidden)]
+pub mod infer;
 #[doc(hidden)]
 pub mod keyword;
 
+pub mod date_time;
 pub mod driver;
 mod error;
 pub mod params;
@@ -12,5 +15,6 @@ pub mod prelude;
 pub mod query;
 pub mod relation;
 pub mod statement;
+pub mod text_search;
 
 pub use error::*;
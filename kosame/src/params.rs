@@ -1,3 +1,44 @@
 pub trait Params<T> {
     fn to_driver(&self) -> T;
+
+    /// The ordinals of the bind parameters that are left out of the SQL, e.g. optional bind
+    /// parameters that are `None`. [`Params::to_driver`] does not include their values.
+    fn omitted_bind_params(&self) -> Vec<u32> {
+        Vec::new()
+    }
+
+    /// The number of elements of each list bind parameter, e.g. `:ids` in `in :ids`, by ordinal.
+    /// Dialects without arrays expand these into one placeholder per element.
+    fn list_lens(&self) -> Vec<(u32, usize)> {
+        Vec::new()
+    }
+}
+
+/// A text bind parameter that accepts any string type, like the pattern of `like`.
+#[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+pub trait Text: std::fmt::Debug + Sync {
+    fn to_sql_ref(&self) -> &(dyn crate::driver::postgres_types::ToSql + Sync);
+}
+
+#[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+macro_rules! text {
+    ($($ty:ty),*) => {
+        $(
+            impl Text for $ty {
+                fn to_sql_ref(&self) -> &(dyn crate::driver::postgres_types::ToSql + Sync) {
+                    self
+                }
+            }
+        )*
+    };
+}
+
+#[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+text!(String, &str, Box<str>, std::borrow::Cow<'_, str>);
+
+#[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+impl<T: Text + ?Sized> Text for &T {
+    fn to_sql_ref(&self) -> &(dyn crate::driver::postgres_types::ToSql + Sync) {
+        (**self).to_sql_ref()
+    }
 }
@@ -0,0 +1,148 @@
+use std::{fmt::Write, ops::Range};
+
+/// An opaque position in the result of a query paginated with `after (...) = :cursor`.
+///
+/// A cursor holds the values of the keyset columns of the last row of a page, in the driver's
+/// wire format. Use [`Cursor::encode`] to hand it to a client and [`Cursor::decode`] to read it
+/// back.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cursor {
+    values: Vec<CursorValue>,
+}
+
+impl Cursor {
+    #[inline]
+    #[must_use]
+    pub fn new(values: Vec<CursorValue>) -> Self {
+        Self { values }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn values(&self) -> &[CursorValue] {
+        &self.values
+    }
+
+    /// Encodes the cursor as a URL-safe string.
+    #[must_use]
+    pub fn encode(&self) -> String {
+        let mut encoded = String::new();
+        for (index, value) in self.values.iter().enumerate() {
+            if index != 0 {
+                encoded.push('.');
+            }
+            match &value.0 {
+                Some(bytes) => {
+                    for byte in bytes {
+                        write!(encoded, "{byte:02x}").unwrap();
+                    }
+                }
+                None => encoded.push('n'),
+            }
+        }
+        encoded
+    }
+
+    /// Decodes a cursor previously returned by [`Cursor::encode`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidCursor`](crate::Error::InvalidCursor) if the string is not a
+    /// valid cursor with `len` values.
+    pub fn decode(encoded: &str, len: usize) -> crate::Result<Self> {
+        let values = encoded
+            .split('.')
+            .map(|value| {
+                // Keyset columns are `not null`, so a cursor never holds `null` values.
+                if value.len() % 2 != 0 {
+                    return None;
+                }
+                (0..value.len())
+                    .step_by(2)
+                    .map(|index| u8::from_str_radix(value.get(index..index + 2)?, 16).ok())
+                    .collect::<Option<Vec<_>>>()
+                    .map(|bytes| CursorValue(Some(bytes)))
+            })
+            .collect::<Option<Vec<_>>>()
+            .filter(|values| values.len() == len)
+            .ok_or(crate::Error::InvalidCursor)?;
+        Ok(Self { values })
+    }
+
+    /// Returns the value bound to the `index`th placeholder of the cursor. Without a cursor, all
+    /// placeholders are bound to `null`, which disables the keyset condition.
+    #[doc(hidden)]
+    #[must_use]
+    pub fn bind_value(cursor: &Option<Self>, index: usize) -> &CursorValue {
+        static NULL: CursorValue = CursorValue(None);
+        cursor
+            .as_ref()
+            .and_then(|cursor| cursor.values.get(index))
+            .unwrap_or(&NULL)
+    }
+}
+
+impl std::fmt::Display for Cursor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.encode())
+    }
+}
+
+/// A single keyset column value of a [`Cursor`], kept in the driver's wire format.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CursorValue(pub(crate) Option<Vec<u8>>);
+
+/// Rows of a driver that can produce the [`Cursor`] of a paginated query.
+pub trait CursorRow {
+    /// Reads the cursor from the given columns of the row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the driver fails to read the columns.
+    fn cursor(&self, columns: Range<usize>) -> crate::Result<Cursor>;
+}
+
+/// A page of rows returned by a paginated query.
+#[derive(Debug, Clone)]
+pub struct Page<T> {
+    rows: Vec<T>,
+    next_cursor: Option<Cursor>,
+}
+
+impl<T> Page<T> {
+    #[inline]
+    #[must_use]
+    pub fn new(rows: Vec<T>, next_cursor: Option<Cursor>) -> Self {
+        Self { rows, next_cursor }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn rows(&self) -> &[T] {
+        &self.rows
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_rows(self) -> Vec<T> {
+        self.rows
+    }
+
+    /// The cursor pointing after the last row of this page, or `None` if the page is empty.
+    #[inline]
+    #[must_use]
+    pub fn next_cursor(&self) -> Option<&Cursor> {
+        self.next_cursor.as_ref()
+    }
+}
+
+/// Implemented for the nullability of `not null` columns. The keyset columns of `after` must be
+/// `not null`, since comparisons with `null` never match and rows would be skipped.
+#[doc(hidden)]
+#[diagnostic::on_unimplemented(
+    message = "the keyset columns of `after` must be `not null`",
+    label = "this column is nullable"
+)]
+pub trait KeysetNullability {}
+
+impl KeysetNullability for crate::infer::NotNull {}
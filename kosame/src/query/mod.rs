@@ -1,5 +1,7 @@
+mod cursor;
 mod runner;
 
+pub use cursor::*;
 pub use kosame_repr::query::*;
 pub use runner::*;
 
@@ -91,3 +93,31 @@ pub trait Query {
         self.query_opt(connection).block_on()
     }
 }
+
+/// A query paginated with `after (...) = :cursor`. It is implemented by the `Query` struct that
+/// `query!` generates for queries with an `after` clause.
+pub trait PaginatedQuery: Query {
+    /// Runs the query and returns the page along with the cursor for the next one.
+    fn query_page<'c, C>(
+        &self,
+        connection: &mut C,
+    ) -> impl Future<Output = crate::Result<Page<Self::Row>>>
+    where
+        C: Connection,
+        C::Row: CursorRow,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        async { RecordArrayRunner {}.run_page(connection, self).await }
+    }
+
+    fn query_page_sync<'c, C>(&self, connection: &mut C) -> crate::Result<Page<Self::Row>>
+    where
+        C: Connection,
+        C::Row: CursorRow,
+        Self::Params: Params<C::Params<'c>>,
+        for<'b> Self::Row: From<&'b C::Row>,
+    {
+        self.query_page(connection).block_on()
+    }
+}
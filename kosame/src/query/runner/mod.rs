@@ -4,7 +4,7 @@ pub use record_array::*;
 
 use crate::{driver::Connection, params::Params};
 
-use super::{Field, Node, Query};
+use super::{After, CursorRow, Field, Node, Page, PaginatedQuery, Query};
 
 pub trait Runner {
     fn run<'a, C, Q>(
@@ -17,4 +17,16 @@ pub trait Runner {
         Q: Query + ?Sized,
         Q::Params: Params<C::Params<'a>>,
         for<'b> Q::Row: From<&'b C::Row>;
+
+    fn run_page<'a, C, Q>(
+        &self,
+        connection: &mut C,
+        query: &Q,
+    ) -> impl Future<Output = crate::Result<Page<Q::Row>>>
+    where
+        C: Connection,
+        C::Row: CursorRow,
+        Q: PaginatedQuery + ?Sized,
+        Q::Params: Params<C::Params<'a>>,
+        for<'b> Q::Row: From<&'b C::Row>;
 }
@@ -1,11 +1,15 @@
 use std::fmt::Write;
 
-use kosame_repr::schema::Relation;
+use kosame_repr::{
+    Ident,
+    clause::{OrderBy, OrderByDir},
+    schema::{Relation, RelationColumn},
+};
 use kosame_sql::FmtSql;
 
 use crate::driver::Connection;
 
-use super::{Field, Node, Params, Query, Runner};
+use super::{After, CursorRow, Field, Node, Page, PaginatedQuery, Params, Query, Runner};
 
 pub struct RecordArrayRunner {}
 
@@ -13,10 +17,24 @@ impl RecordArrayRunner {
     pub fn query_to_sql<D: kosame_sql::Dialect>(
         &self,
         query: &(impl Query + ?Sized),
+    ) -> Result<String, kosame_sql::Error> {
+        self.query_to_sql_with_bind_params::<D>(query, &[], &[])
+    }
+
+    /// Like [`RecordArrayRunner::query_to_sql`], but leaves out the conditions referring to the
+    /// omitted bind parameters and expands list parameters to the given number of elements for
+    /// dialects without array support.
+    pub fn query_to_sql_with_bind_params<D: kosame_sql::Dialect>(
+        &self,
+        query: &(impl Query + ?Sized),
+        omitted_bind_params: &[u32],
+        list_lens: &[(u32, usize)],
     ) -> Result<String, kosame_sql::Error> {
         let mut sql = String::new();
-        let mut formatter = kosame_sql::Formatter::<D>::new(&mut sql);
-        fmt_node_sql(&mut formatter, query.repr(), None)?;
+        let mut formatter = kosame_sql::Formatter::<D>::new(&mut sql)
+            .with_omitted_bind_params(omitted_bind_params)
+            .with_list_lens(list_lens);
+        fmt_node_sql(&mut formatter, query.repr(), None, None)?;
         Ok(sql)
     }
 }
@@ -29,26 +47,249 @@ impl Runner for RecordArrayRunner {
         Q::Params: Params<C::Params<'a>>,
         for<'b> Q::Row: From<&'b C::Row>,
     {
-        let sql = self.query_to_sql::<C::Dialect>(query)?;
+        let params = query.params();
+        let sql = self.query_to_sql_with_bind_params::<C::Dialect>(
+            query,
+            &params.omitted_bind_params(),
+            &params.list_lens(),
+        )?;
         let rows = connection
-            .query(&sql, &query.params().to_driver())
+            .query(&sql, &params.to_driver())
             .await
             .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
         Ok(rows.iter().map(Q::Row::from).collect())
     }
+
+    async fn run_page<'a, C, Q>(&self, connection: &mut C, query: &Q) -> crate::Result<Page<Q::Row>>
+    where
+        C: Connection,
+        C::Row: CursorRow,
+        Q: PaginatedQuery + ?Sized,
+        Q::Params: Params<C::Params<'a>>,
+        for<'b> Q::Row: From<&'b C::Row>,
+    {
+        let node = query.repr();
+        let after = node
+            .after()
+            .expect("paginated queries should have an `after` clause");
+
+        let params = query.params();
+        let sql = self.query_to_sql_with_bind_params::<C::Dialect>(
+            query,
+            &params.omitted_bind_params(),
+            &params.list_lens(),
+        )?;
+        let rows = connection
+            .query(&sql, &params.to_driver())
+            .await
+            .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+
+        // The cursor columns follow the columns of the row struct.
+        let start = node.fields().len()
+            + if node.star() {
+                node.table().columns().len()
+            } else {
+                0
+            };
+        let next_cursor = rows
+            .last()
+            .map(|row| row.cursor(start..start + after.params().len()))
+            .transpose()?;
+
+        Ok(Page::new(
+            rows.iter().map(Q::Row::from).collect(),
+            next_cursor,
+        ))
+    }
+}
+
+/// The alias under which the parent row's join columns are exposed to a self-referencing relation.
+const SOURCE_ALIAS: &str = "kosame_source";
+
+/// The relation a nested node is loaded through, along with the name under which the parent row
+/// is visible to the node.
+#[derive(Clone, Copy)]
+struct Parent<'a> {
+    relation: &'a Relation<'a>,
+    alias: Ident<'a>,
+}
+
+/// Returns the name under which the rows of a node are visible to expressions. Nested nodes are
+/// aliased by their relation name, matching the names column references are resolved against.
+fn node_alias<'a>(node: &'a Node<'a>, parent: Option<Parent<'a>>) -> Ident<'a> {
+    parent.map_or_else(|| node.table().name(), |parent| parent.relation.name())
+}
+
+/// Writes the record array of a relation field.
+fn fmt_relation_sql<D: kosame_sql::Dialect>(
+    formatter: &mut kosame_sql::Formatter<D>,
+    node: &Node,
+    relation: &Relation,
+    source: Ident,
+    depth: Option<u32>,
+) -> std::fmt::Result {
+    if !relation.is_self_referencing() {
+        formatter.write_str("array(")?;
+        fmt_node_sql(
+            formatter,
+            node,
+            Some(Parent {
+                relation,
+                alias: source,
+            }),
+            depth,
+        )?;
+        return formatter.write_str(")");
+    }
+
+    // The table of a self-referencing relation may shadow the parent, so the parent's join
+    // columns are first selected into a subquery in an enclosing scope.
+    formatter.write_str("(select array(")?;
+    fmt_node_sql(
+        formatter,
+        node,
+        Some(Parent {
+            relation,
+            alias: Ident::new(SOURCE_ALIAS),
+        }),
+        depth,
+    )?;
+    formatter.write_str(") from (select ")?;
+    let source_columns = relation
+        .source_columns()
+        .iter()
+        .filter_map(RelationColumn::column);
+    for (index, column) in source_columns.enumerate() {
+        if index != 0 {
+            formatter.write_str(", ")?;
+        }
+        source.fmt_sql(formatter)?;
+        formatter.write_str(".")?;
+        column.name().fmt_sql(formatter)?;
+    }
+    formatter.write_str(") as ")?;
+    Ident::new(SOURCE_ALIAS).fmt_sql(formatter)?;
+    formatter.write_str(")")
 }
 
+/// Writes the SQL for a query node.
+///
+/// `depth` is set for the nodes of recursive relations and counts the levels that remain to be
+/// loaded, including the current one.
 fn fmt_node_sql<D: kosame_sql::Dialect>(
     formatter: &mut kosame_sql::Formatter<D>,
     node: &Node,
-    relation: Option<&Relation>,
+    parent: Option<Parent>,
+    depth: Option<u32>,
 ) -> std::fmt::Result {
+    let alias = node_alias(node, parent);
+
     formatter.write_str("select ")?;
+    if let Some(distinct) = node.distinct() {
+        distinct.fmt_sql(formatter)?;
+    }
 
-    if relation.is_some() {
+    if parent.is_some() {
         formatter.write_str("row(")?;
     }
 
+    fmt_fields_sql(formatter, node, parent, depth)?;
+
+    if parent.is_some() {
+        formatter.write_str(")")?;
+    }
+
+    let after = node.after().zip(node.order_by());
+
+    if let Some((_, order_by)) = after {
+        for item in order_by.items() {
+            formatter.write_str(", ")?;
+            item.expr().fmt_sql(formatter)?;
+        }
+    }
+
+    formatter.write_str(" from ")?;
+    node.table().name().fmt_sql(formatter)?;
+    if alias.as_str() != node.table().name().as_str() {
+        formatter.write_str(" as ")?;
+        alias.fmt_sql(formatter)?;
+    }
+
+    let r#where = node
+        .r#where()
+        .filter(|r#where| !r#where.expr().is_omitted(formatter));
+    let condition_count = usize::from(parent.is_some())
+        + usize::from(r#where.is_some())
+        + usize::from(after.is_some());
+    let mut condition_index = 0;
+    let mut fmt_condition = |formatter: &mut kosame_sql::Formatter<D>,
+                             f: &dyn Fn(&mut kosame_sql::Formatter<D>) -> std::fmt::Result|
+     -> std::fmt::Result {
+        formatter.write_str(if condition_index == 0 {
+            " where "
+        } else {
+            " and "
+        })?;
+        condition_index += 1;
+        if condition_count > 1 {
+            formatter.write_str("(")?;
+        }
+        f(formatter)?;
+        if condition_count > 1 {
+            formatter.write_str(")")?;
+        }
+        Ok(())
+    };
+
+    if let Some(parent) = parent {
+        fmt_condition(formatter, &|formatter| {
+            fmt_relation_condition_sql(formatter, parent, alias)
+        })?;
+    }
+
+    if let Some(r#where) = r#where {
+        fmt_condition(formatter, &|formatter| r#where.expr().fmt_sql(formatter))?;
+    }
+
+    if let Some((after, order_by)) = after {
+        fmt_condition(formatter, &|formatter| {
+            fmt_after_sql(formatter, after, order_by)
+        })?;
+    }
+
+    if let Some(group_by) = node.group_by() {
+        group_by.fmt_sql(formatter)?;
+    }
+
+    if let Some(having) = node.having() {
+        having.fmt_sql(formatter)?;
+    }
+
+    if let Some(order_by) = &node.order_by() {
+        order_by.fmt_sql(formatter)?;
+    }
+
+    if let Some(limit) = &node.limit() {
+        limit.fmt_sql(formatter)?;
+    }
+
+    if let Some(offset) = &node.offset() {
+        offset.fmt_sql(formatter)?;
+    }
+
+    Ok(())
+}
+
+/// Writes the selected fields of a query node, followed by the nested rows of a recursive
+/// relation.
+fn fmt_fields_sql<D: kosame_sql::Dialect>(
+    formatter: &mut kosame_sql::Formatter<D>,
+    node: &Node,
+    parent: Option<Parent>,
+    depth: Option<u32>,
+) -> std::fmt::Result {
+    let alias = node_alias(node, parent);
+
     if node.star() {
         for (index, column) in node.table().columns().iter().enumerate() {
             column.name().fmt_sql(formatter)?;
@@ -56,7 +297,7 @@ fn fmt_node_sql<D: kosame_sql::Dialect>(
                 formatter.write_str(", ")?;
             }
         }
-        if !node.fields().is_empty() {
+        if !node.fields().is_empty() || depth.is_some() {
             formatter.write_str(", ")?;
         }
     }
@@ -66,73 +307,125 @@ fn fmt_node_sql<D: kosame_sql::Dialect>(
             Field::Column { column, .. } => {
                 column.name().fmt_sql(formatter)?;
             }
-            Field::Relation { node, relation, .. } => {
-                formatter.write_str("array(")?;
-                fmt_node_sql::<D>(formatter, node, Some(relation))?;
-                formatter.write_str(")")?;
+            Field::Relation {
+                node,
+                relation,
+                depth,
+                ..
+            } => {
+                fmt_relation_sql(formatter, node, relation, alias, *depth)?;
             }
             Field::Expr { expr, .. } => {
                 expr.fmt_sql(formatter)?;
             }
         }
-        if index != node.fields().len() - 1 {
+        if index != node.fields().len() - 1 || depth.is_some() {
             formatter.write_str(", ")?;
         }
     }
 
-    if relation.is_some() {
-        formatter.write_str(")")?;
+    if let Some(depth) = depth {
+        match parent {
+            Some(parent) if depth > 1 => {
+                fmt_relation_sql(formatter, node, parent.relation, alias, Some(depth - 1))?;
+            }
+            _ => formatter.write_str("null::record[]")?,
+        }
     }
 
-    formatter.write_str(" from ")?;
-    node.table().name().fmt_sql(formatter)?;
-
-    if relation.is_some() || node.r#where().is_some() {
-        formatter.write_str(" where ")?;
-    }
+    Ok(())
+}
 
-    if relation.is_some() && node.r#where().is_some() {
-        formatter.write_str("(")?;
+/// Writes the condition joining the rows of a relation to their parent row.
+fn fmt_relation_condition_sql<D: kosame_sql::Dialect>(
+    formatter: &mut kosame_sql::Formatter<D>,
+    parent: Parent,
+    alias: Ident,
+) -> std::fmt::Result {
+    let relation = parent.relation;
+    for (index, (source_column, target_column)) in relation.column_pairs().enumerate() {
+        fmt_relation_column_sql(formatter, parent.alias, source_column)?;
+        formatter.write_str(" = ")?;
+        fmt_relation_column_sql(formatter, alias, target_column)?;
+        if index != relation.source_columns().len() - 1 {
+            formatter.write_str(" and ")?;
+        }
     }
+    Ok(())
+}
 
-    if let Some(relation) = relation {
-        for (index, (source_column, target_column)) in relation.column_pairs().enumerate() {
-            relation.source_table().fmt_sql(formatter)?;
-            formatter.write_str(".")?;
-            source_column.name().fmt_sql(formatter)?;
-            formatter.write_str(" = ")?;
-            relation.target_table().fmt_sql(formatter)?;
+fn fmt_relation_column_sql<D: kosame_sql::Dialect>(
+    formatter: &mut kosame_sql::Formatter<D>,
+    table: Ident,
+    column: &RelationColumn,
+) -> std::fmt::Result {
+    match column {
+        RelationColumn::Column(column) => {
+            table.fmt_sql(formatter)?;
             formatter.write_str(".")?;
-            target_column.name().fmt_sql(formatter)?;
-            if index != relation.source_columns().len() - 1 {
-                formatter.write_str(" and ")?;
-            }
+            column.name().fmt_sql(formatter)
         }
+        RelationColumn::Lit(lit) => lit.fmt_sql(formatter),
     }
+}
 
-    if relation.is_some() && node.r#where().is_some() {
-        formatter.write_str(") and (")?;
-    }
-
-    if let Some(r#where) = &node.r#where() {
-        r#where.expr().fmt_sql(formatter)?;
-    }
+/// Writes the keyset condition of a paginated node. The comparison operator of each `order by`
+/// item follows its direction, and a `null` cursor matches all rows.
+fn fmt_after_sql<D: kosame_sql::Dialect>(
+    formatter: &mut kosame_sql::Formatter<D>,
+    after: &After,
+    order_by: &OrderBy,
+) -> std::fmt::Result {
+    let items = order_by.items();
+    let params = after.params();
+    let operator = |dir: Option<OrderByDir>| match dir {
+        Some(OrderByDir::Desc) => " < ",
+        Some(OrderByDir::Asc) | None => " > ",
+    };
 
-    if relation.is_some() && node.r#where().is_some() {
+    if items.len() == 1 {
+        items[0].expr().fmt_sql(formatter)?;
+        formatter.write_str(operator(items[0].dir()))?;
+        params[0].fmt_sql(formatter)?;
+    } else if items
+        .iter()
+        .all(|item| operator(item.dir()) == operator(items[0].dir()))
+    {
+        // Row comparisons can make use of a composite index.
+        formatter.write_str("(")?;
+        for (index, item) in items.iter().enumerate() {
+            if index != 0 {
+                formatter.write_str(", ")?;
+            }
+            item.expr().fmt_sql(formatter)?;
+        }
+        formatter.write_str(")")?;
+        formatter.write_str(operator(items[0].dir()))?;
+        formatter.write_str("(")?;
+        kosame_sql::Punctuated::new(params, ",").fmt_sql(formatter)?;
+        formatter.write_str(")")?;
+    } else {
+        formatter.write_str("(")?;
+        for (index, item) in items.iter().enumerate() {
+            if index != 0 {
+                formatter.write_str(" or ")?;
+            }
+            for (previous, param) in items[..index].iter().zip(params) {
+                previous.expr().fmt_sql(formatter)?;
+                formatter.write_str(" = ")?;
+                param.fmt_sql(formatter)?;
+                formatter.write_str(" and ")?;
+            }
+            item.expr().fmt_sql(formatter)?;
+            formatter.write_str(operator(item.dir()))?;
+            params[index].fmt_sql(formatter)?;
+        }
         formatter.write_str(")")?;
     }
 
-    if let Some(order_by) = &node.order_by() {
-        order_by.fmt_sql(formatter)?;
-    }
-
-    if let Some(limit) = &node.limit() {
-        limit.fmt_sql(formatter)?;
-    }
-
-    if let Some(offset) = &node.offset() {
-        offset.fmt_sql(formatter)?;
-    }
-
-    Ok(())
+    // Postgres infers the type of the parameter from its first occurrence, so the null check
+    // must come last.
+    formatter.write_str(" or ")?;
+    params[0].fmt_sql(formatter)?;
+    formatter.write_str(" is null")
 }
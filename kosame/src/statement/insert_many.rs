@@ -0,0 +1,207 @@
+use kosame_repr::{
+    clause::{Values, ValuesItem, ValuesRow},
+    command::{Command, CommandType, Insert, InsertSource},
+    expr::{BindParam, Expr},
+    part::{ColumnList, TargetTable},
+    schema::Table,
+};
+use pollster::FutureExt;
+
+use crate::{driver::Connection, params::Params};
+
+/// A row that can be inserted with [`InsertMany`]. It is implemented by the `Insert` struct that
+/// `table!` generates for every table.
+pub trait InsertRow {
+    /// The table that the row is inserted into.
+    const TABLE: Table<'static>;
+
+    /// Whether the column at the given index of [`InsertRow::TABLE`] takes its default value in
+    /// this row. The row does not bind a value for such columns.
+    fn is_default(&self, column: usize) -> bool;
+}
+
+/// Inserts a runtime slice of rows using multi-row `values` lists. Created by the `insert_many`
+/// function of a table module.
+///
+/// The rows are split into as many statements as needed to stay under
+/// [`InsertMany::MAX_BIND_PARAMS`]. Run it in a transaction if the rows must be inserted
+/// atomically.
+pub struct InsertMany<'a, R> {
+    rows: &'a [R],
+}
+
+impl<'a, R> InsertMany<'a, R>
+where
+    R: InsertRow,
+{
+    /// The maximum number of bind parameters of a single statement. The Postgres protocol allows
+    /// 65535, but `postgres` and `tokio-postgres` encode the count as an `i16`.
+    pub const MAX_BIND_PARAMS: usize = i16::MAX as usize;
+
+    #[inline]
+    #[must_use]
+    pub const fn new(rows: &'a [R]) -> Self {
+        Self { rows }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn rows(&self) -> &'a [R] {
+        self.rows
+    }
+
+    /// Returns the chunks of rows that are inserted with one statement each.
+    pub fn chunks(&self) -> std::slice::Chunks<'a, R> {
+        let columns = R::TABLE.columns().len().max(1);
+        self.rows.chunks((Self::MAX_BIND_PARAMS / columns).max(1))
+    }
+
+    /// Renders the statement that inserts the given chunk of rows.
+    pub fn chunk_to_sql_string<D>(rows: &[R]) -> crate::Result<String>
+    where
+        D: kosame_sql::Dialect,
+    {
+        use kosame_sql::FmtSql;
+
+        let columns = R::TABLE
+            .columns()
+            .iter()
+            .map(|column| column.name().as_str())
+            .collect::<Vec<_>>();
+
+        let mut ordinal = 0;
+        let mut items = Vec::with_capacity(rows.len() * columns.len());
+        for row in rows {
+            for (index, column) in columns.iter().enumerate() {
+                if row.is_default(index) {
+                    items.push(ValuesItem::Default);
+                } else {
+                    items.push(ValuesItem::Expr(Expr::BindParam(BindParam::new(
+                        column, ordinal,
+                    ))));
+                    ordinal += 1;
+                }
+            }
+        }
+        let values_rows = items
+            .chunks(columns.len().max(1))
+            .map(ValuesRow::new)
+            .collect::<Vec<_>>();
+
+        let command = Command::new(
+            None,
+            CommandType::Insert(Insert::new(
+                TargetTable::new(R::TABLE.name().as_str(), None),
+                Some(ColumnList::new(&columns)),
+                InsertSource::Values(Values::new(&values_rows)),
+                None,
+                None,
+            )),
+        );
+        Ok(command.to_sql_string::<D>()?)
+    }
+
+    /// Inserts all rows and returns the number of inserted rows.
+    pub async fn exec<'c, C>(&self, connection: &mut C) -> crate::Result<u64>
+    where
+        C: Connection,
+        InsertManyParams<'a, R>: Params<C::Params<'c>>,
+    {
+        let mut count = 0;
+        for rows in self.chunks() {
+            let sql = Self::chunk_to_sql_string::<C::Dialect>(rows)?;
+            let params = InsertManyParams { rows };
+            count += connection
+                .exec(&sql, &params.to_driver())
+                .await
+                .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
+        }
+        Ok(count)
+    }
+
+    pub fn exec_sync<'c, C>(&self, connection: &mut C) -> crate::Result<u64>
+    where
+        C: Connection,
+        InsertManyParams<'a, R>: Params<C::Params<'c>>,
+    {
+        self.exec(connection).block_on()
+    }
+}
+
+/// The bind parameters of a chunk of rows, which are the parameters of each row in order.
+pub struct InsertManyParams<'a, R> {
+    rows: &'a [R],
+}
+
+impl<'a, R, T> Params<Vec<T>> for InsertManyParams<'a, R>
+where
+    &'a R: Params<Vec<T>>,
+{
+    fn to_driver(&self) -> Vec<T> {
+        self.rows.iter().flat_map(|row| row.to_driver()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kosame_repr::{
+        expr::{Expr, Lit},
+        schema::{Column, Table},
+    };
+
+    use super::{InsertMany, InsertRow};
+
+    const ZERO: Expr = Expr::Lit(Lit::Int(0));
+    const COLUMNS: &[&Column] = &[
+        &Column::new("id", "int", true, true, None),
+        &Column::new("content", "text", false, true, None),
+        &Column::new("upvotes", "int", false, true, Some(&ZERO)),
+    ];
+
+    #[derive(Clone)]
+    struct Comment {
+        upvotes: Option<i32>,
+    }
+
+    impl InsertRow for Comment {
+        const TABLE: Table<'static> = Table::new("comments", COLUMNS, &[]);
+
+        fn is_default(&self, column: usize) -> bool {
+            column == 2 && self.upvotes.is_none()
+        }
+    }
+
+    #[test]
+    fn defaults_skip_ordinals() {
+        let rows = [
+            Comment { upvotes: None },
+            Comment { upvotes: Some(1) },
+            Comment { upvotes: None },
+        ];
+        assert_eq!(
+            InsertMany::chunk_to_sql_string::<kosame_sql::postgres::Dialect>(&rows).unwrap(),
+            r#"insert into "comments" ("id", "content", "upvotes") values ($1, $2, default), ($3, $4, $5), ($6, $7, default)"#
+        );
+    }
+
+    #[test]
+    fn chunks_stay_within_bind_param_limit() {
+        let per_chunk = InsertMany::<Comment>::MAX_BIND_PARAMS / COLUMNS.len();
+        assert!(i16::try_from(per_chunk * COLUMNS.len()).is_ok());
+        assert!(i16::try_from((per_chunk + 1) * COLUMNS.len()).is_err());
+
+        let rows = vec![Comment { upvotes: Some(0) }; per_chunk];
+        let chunks = InsertMany::new(&rows)
+            .chunks()
+            .map(<[_]>::len)
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, [per_chunk]);
+
+        let rows = vec![Comment { upvotes: Some(0) }; per_chunk + 1];
+        let chunks = InsertMany::new(&rows)
+            .chunks()
+            .map(<[_]>::len)
+            .collect::<Vec<_>>();
+        assert_eq!(chunks, [per_chunk, 1]);
+    }
+}
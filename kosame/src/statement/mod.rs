@@ -1,3 +1,6 @@
+mod insert_many;
+
+pub use insert_many::*;
 pub use kosame_repr::command::*;
 use pollster::FutureExt;
 
@@ -22,10 +25,14 @@ pub trait Statement {
     {
         async {
             use kosame_sql::FmtSql;
-            let sql = self.repr().to_sql_string::<C::Dialect>()?;
+            let params = self.params();
+            let sql = self.repr().to_sql_string_with_bind_params::<C::Dialect>(
+                &params.omitted_bind_params(),
+                &params.list_lens(),
+            )?;
 
             Ok(connection
-                .exec(&sql, &self.params().to_driver())
+                .exec(&sql, &params.to_driver())
                 .await
                 .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?)
         }
@@ -50,10 +57,14 @@ pub trait Statement {
     {
         async {
             use kosame_sql::FmtSql;
-            let sql = self.repr().to_sql_string::<C::Dialect>()?;
+            let params = self.params();
+            let sql = self.repr().to_sql_string_with_bind_params::<C::Dialect>(
+                &params.omitted_bind_params(),
+                &params.list_lens(),
+            )?;
 
             let rows = connection
-                .query(&sql, &self.params().to_driver())
+                .query(&sql, &params.to_driver())
                 .await
                 .map_err(|e| Box::new(e) as Box<dyn std::error::Error>)?;
             Ok(rows.iter().map(Self::Row::from).collect())
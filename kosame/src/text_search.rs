@@ -0,0 +1,70 @@
+//! Types of the PostgreSQL full-text search.
+//!
+//! Both types hold the text representation PostgreSQL would print for them, e.g. `'fat':2 'rat':3`
+//! for a `tsvector` or `'fat' & 'rat'` for a `tsquery`.
+
+use std::fmt::{self, Display, Formatter};
+
+/// A PostgreSQL `tsvector`, a sorted list of lexemes with their positions.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct TsVector(String);
+
+impl TsVector {
+    #[inline]
+    #[must_use]
+    pub fn new(text: String) -> Self {
+        Self(text)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Display for TsVector {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A PostgreSQL `tsquery`, lexemes combined with the operators `&`, `|`, `!`, and `<->`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde-full", derive(serde::Deserialize))]
+pub struct TsQuery(String);
+
+impl TsQuery {
+    #[inline]
+    #[must_use]
+    pub fn new(text: String) -> Self {
+        Self(text)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl Display for TsQuery {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
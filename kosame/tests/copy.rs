@@ -0,0 +1,31 @@
+#![cfg(feature = "tokio-postgres")]
+
+use kosame::driver::tokio_postgres::{CopyInRow, CopyOutRow};
+
+mod schema {
+    use kosame::pg_table;
+
+    pg_table! {
+        create table posts (
+            id int primary key,
+            title text not null,
+            upvotes int not null default 0,
+            tags text[],
+            search tsvector,
+        );
+    }
+}
+
+fn assert_copy_in<R: for<'a> CopyInRow<'a>>() {}
+
+fn assert_copy_out<R: for<'a> CopyOutRow<'a>>() {}
+
+#[test]
+fn select_copies_in_and_out() {
+    assert_copy_in::<schema::posts::Select>();
+    assert_copy_out::<schema::posts::Select>();
+
+    // The associated functions are only callable if the traits are implemented.
+    let _ = schema::posts::Select::copy_in::<tokio_postgres::Client, Vec<schema::posts::Select>>;
+    let _ = schema::posts::Select::copy_out::<tokio_postgres::Client>;
+}
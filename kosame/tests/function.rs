@@ -0,0 +1,103 @@
+#![cfg(feature = "tokio-postgres")]
+
+use kosame::statement::Statement;
+use kosame_sql::FmtSql;
+
+mod schema {
+    use kosame::pg_table;
+
+    pg_table! {
+        create table posts (
+            id int primary key,
+            title text not null,
+            subtitle text,
+            upvotes smallint not null,
+        );
+    }
+
+    kosame::function! {
+        create function slugify(text) returns text strict;
+    }
+
+    kosame::function! {
+        create function boost(bigint, real, anyelement) returns bigint
+    }
+}
+
+kosame::pg_statement! {
+    (
+        select
+            schema::slugify(:text) as slug,
+            schema::boost(:votes, :factor, :any) as boosted,
+    ) as declared_params
+}
+
+kosame::pg_statement! {
+    (
+        select
+            coalesce(posts.subtitle, :fallback) as subtitle,
+            coalesce(:fallback, posts.title) as title,
+        from schema::posts
+    ) as coalesce_param
+}
+
+#[test]
+fn declared_function_arguments() {
+    // Integers are cast to wider arguments, string literals fit any argument, and arguments
+    // without a Rust type are not checked.
+    let statement = kosame::pg_statement! {
+        select
+            schema::slugify(posts.title) as slug,
+            schema::slugify("Hello") as greeting,
+            schema::boost(posts.upvotes, posts.id, posts.title) as boosted,
+        from schema::posts
+    };
+    assert_eq!(
+        statement
+            .repr()
+            .to_sql_string::<kosame_sql::postgres::Dialect>()
+            .unwrap(),
+        r#"select "slugify"("posts"."title") as "slug", "slugify"('Hello') as "greeting", "boost"("posts"."upvotes", "posts"."id", "posts"."title") as "boosted" from "posts""#
+    );
+}
+
+#[test]
+fn bind_parameters_take_the_declared_argument_type() {
+    let text = String::from("Hello World");
+    let statement = declared_params::Statement::new(declared_params::Params {
+        text: &text,
+        votes: &3,
+        factor: &2.0,
+        any: &true,
+    });
+    assert_eq!(
+        statement
+            .repr()
+            .to_sql_string::<kosame_sql::postgres::Dialect>()
+            .unwrap(),
+        r#"select "slugify"($1) as "slug", "boost"($2, $3, $4) as "boosted""#
+    );
+    // Arguments without a Rust type accept any value.
+    let _: for<'a> fn(&declared_params::Params<'a>) -> (&'a String, &'a i64, &'a f32) =
+        |params| (params.text, params.votes, params.factor);
+    // `slugify` is strict and the bind parameter is not null.
+    let _: fn(declared_params::Row) -> (String, Option<i64>) = |row| (row.slug, row.boosted);
+}
+
+#[test]
+fn bind_parameters_take_the_type_of_the_other_coalesce_arguments() {
+    let fallback = String::from("Untitled");
+    let statement = coalesce_param::Statement::new(coalesce_param::Params {
+        fallback: &fallback,
+    });
+    assert_eq!(
+        statement
+            .repr()
+            .to_sql_string::<kosame_sql::postgres::Dialect>()
+            .unwrap(),
+        r#"select coalesce("posts"."subtitle", $1) as "subtitle", coalesce($1, "posts"."title") as "title" from "posts""#
+    );
+    let _: for<'a> fn(&coalesce_param::Params<'a>) -> &'a String = |params| params.fallback;
+    // The bind parameter is not null, so neither is the result.
+    let _: fn(coalesce_param::Row) -> (String, String) = |row| (row.subtitle, row.title);
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "tokio-postgres")]
+
+use kosame::{params::Params, statement::Statement};
+
+mod schema {
+    use kosame::pg_table;
+
+    pg_table! {
+        create table posts (
+            id int primary key,
+            title text not null,
+        );
+    }
+}
+
+type DriverParams<'a> = Vec<&'a (dyn kosame::driver::postgres_types::ToSql + Sync + 'a)>;
+
+#[test]
+fn like_patterns_accept_any_string_type() {
+    let pattern: &str = "%a%";
+    let statement = kosame::pg_statement! {
+        select posts.id from schema::posts where title like :pattern
+    };
+    assert_eq!(
+        Params::<DriverParams>::to_driver(statement.params()).len(),
+        1
+    );
+
+    let pattern = String::from("%a%");
+    let statement = kosame::pg_statement! {
+        select posts.id from schema::posts where title ilike :pattern
+    };
+    assert_eq!(
+        Params::<DriverParams>::to_driver(statement.params()).len(),
+        1
+    );
+}
+
+#[test]
+fn optional_like_patterns_accept_any_string_type() {
+    for pattern in [Some("%a%"), None] {
+        let statement = kosame::pg_statement! {
+            select posts.id from schema::posts where title like :pattern?
+        };
+        let params = statement.params();
+        assert_eq!(
+            Params::<DriverParams>::to_driver(params).len(),
+            usize::from(pattern.is_some())
+        );
+        assert_eq!(
+            Params::<DriverParams>::omitted_bind_params(params),
+            if pattern.is_some() { vec![] } else { vec![0] }
+        );
+    }
+
+    let pattern: Option<String> = None;
+    let statement = kosame::pg_statement! {
+        select posts.id from schema::posts where title not ilike :pattern?
+    };
+    assert!(Params::<DriverParams>::to_driver(statement.params()).is_empty());
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "tokio-postgres")]
+
+use kosame::{
+    query::RecordArrayRunner,
+    relation::{Many, ZeroOrOne},
+};
+
+mod schema {
+    use kosame::pg_table;
+
+    pg_table! {
+        create table categories (
+            id int primary key,
+            parent_id int,
+            name text not null,
+        );
+
+        children: (id) <= categories (parent_id),
+        parent: (parent_id) => categories (id),
+    }
+}
+
+kosame::pg_query! {
+    schema::categories {
+        id,
+        children recursive(depth = 2) { name },
+        parent recursive(depth = 2) { name },
+    }
+    as category_tree
+}
+
+#[test]
+fn recursive_relations_repeat_until_the_depth() {
+    let query = category_tree::Query::new(category_tree::Params {});
+    assert_eq!(
+        RecordArrayRunner {}
+            .query_to_sql::<kosame_sql::postgres::Dialect>(&query)
+            .unwrap(),
+        r#"select "id", (select array(select row("name", (select array(select row("name", null::record[]) from "categories" as "children" where "kosame_source"."id" = "children"."parent_id") from (select "children"."id") as "kosame_source")) from "categories" as "children" where "kosame_source"."id" = "children"."parent_id") from (select "categories"."id") as "kosame_source"), (select array(select row("name", (select array(select row("name", null::record[]) from "categories" as "parent" where "kosame_source"."parent_id" = "parent"."id") from (select "parent"."parent_id") as "kosame_source")) from "categories" as "parent" where "kosame_source"."parent_id" = "parent"."id") from (select "categories"."parent_id") as "kosame_source") from "categories""#
+    );
+
+    // Every level repeats the relation, and the to-one levels below the first are boxed. The
+    // relations on the deepest level are selected as `null::record[]` and read as empty.
+    let _: fn(category_tree::Row) -> Many<category_tree::RowChildren> = |row| row.children;
+    let _: fn(category_tree::Row) -> ZeroOrOne<category_tree::RowParent> = |row| row.parent;
+    let _: fn(category_tree::RowChildren) -> (String, Many<category_tree::RowChildren>) =
+        |row| (row.name, row.children);
+    let _: fn(category_tree::RowParent) -> (String, ZeroOrOne<Box<category_tree::RowParent>>) =
+        |row| (row.name, row.parent);
+}
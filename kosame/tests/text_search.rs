@@ -0,0 +1,42 @@
+#![cfg(feature = "tokio-postgres")]
+
+use kosame::statement::Statement;
+use kosame_sql::FmtSql;
+
+mod schema {
+    use kosame::pg_table;
+
+    pg_table! {
+        create table posts (
+            id int primary key,
+            search tsvector not null,
+        );
+    }
+}
+
+kosame::pg_statement! {
+    (
+        select
+            posts.id,
+            ts_rank(posts.search, websearch_to_tsquery("english", :query)) as rank,
+        from
+            schema::posts
+        where
+            posts.search @@ websearch_to_tsquery("english", :query)
+    ) as ranked_posts
+}
+
+#[test]
+fn ranking_infers_the_query_text() {
+    let query = String::from("fat rat");
+    let statement = ranked_posts::Statement::new(ranked_posts::Params { query: &query });
+    assert_eq!(
+        statement
+            .repr()
+            .to_sql_string::<kosame_sql::postgres::Dialect>()
+            .unwrap(),
+        r#"select "posts"."id", "ts_rank"("posts"."search", "websearch_to_tsquery"('english', $1)) as "rank" from "posts" where "posts"."search" @@ "websearch_to_tsquery"('english', $1)"#
+    );
+    let _: for<'a> fn(&ranked_posts::Params<'a>) -> &'a String = |params| params.query;
+    let _: fn(ranked_posts::Row) -> (i32, f32) = |row| (row.id, row.rank);
+}
@@ -54,6 +54,11 @@ impl<'ast> Visit<'ast> for Visitor {
                 initial_space,
                 initial_indent,
             )),
+            "function" => Some(pretty_print_str::<Macro<kosame_dsl::schema::Function>>(
+                &source_text,
+                initial_space,
+                initial_indent,
+            )),
             "query" | "pg_query" => Some(pretty_print_str::<Macro<kosame_dsl::query::Query>>(
                 &source_text,
                 initial_space,
@@ -13,6 +13,7 @@ pub struct CustomMeta {
     pub driver: Option<MetaDriver>,
     pub rename: Option<MetaRename>,
     pub type_override: Option<MetaTypeOverride>,
+    pub elements_not_null: Option<MetaElementsNotNull>,
 
     pub pass: u32,
     pub tables: HashMap<Path, Table>,
@@ -23,6 +24,7 @@ pub enum MetaLocation {
     TableInner,
     TableOuter,
     Column,
+    Function,
     QueryInner,
     QueryOuter,
     StatementInner,
@@ -71,10 +73,27 @@ impl CustomMeta {
                             );
                         }
                         MetaItem::Rename(rename) => {
-                            fill_or_error!(rename, "rename", location == MetaLocation::Column);
+                            fill_or_error!(
+                                rename,
+                                "rename",
+                                location == MetaLocation::Column
+                                    || location == MetaLocation::Function
+                            );
                         }
                         MetaItem::TypeOverride(type_override) => {
-                            fill_or_error!(type_override, "ty", location == MetaLocation::Column);
+                            fill_or_error!(
+                                type_override,
+                                "ty",
+                                location == MetaLocation::Column
+                                    || location == MetaLocation::Function
+                            );
+                        }
+                        MetaItem::ElementsNotNull(elements_not_null) => {
+                            fill_or_error!(
+                                elements_not_null,
+                                "elements_not_null",
+                                location == MetaLocation::Column
+                            );
                         }
                         MetaItem::Pass(pass) => {
                             result.pass = pass.value.base10_parse()?;
@@ -106,6 +125,7 @@ enum MetaItem {
     Driver(MetaDriver),
     Rename(MetaRename),
     TypeOverride(MetaTypeOverride),
+    ElementsNotNull(MetaElementsNotNull),
     Pass(MetaPass),
     Table(MetaTable),
 }
@@ -126,6 +146,8 @@ impl Parse for MetaItem {
             Ok(Self::Rename(input.parse()?))
         } else if lookahead.peek(keyword::ty) {
             Ok(Self::TypeOverride(input.parse()?))
+        } else if lookahead.peek(keyword::elements_not_null) {
+            Ok(Self::ElementsNotNull(input.parse()?))
         } else {
             keyword::group_attribute::error(input);
         }
@@ -186,6 +208,20 @@ impl Parse for MetaTypeOverride {
     }
 }
 
+/// Declares that the elements of an array column are never `null`, e.g.
+/// `#[kosame(elements_not_null)]`.
+pub struct MetaElementsNotNull {
+    pub path: keyword::elements_not_null,
+}
+
+impl Parse for MetaElementsNotNull {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            path: input.parse()?,
+        })
+    }
+}
+
 pub struct MetaPass {
     pub pass_keyword: keyword::__pass,
     pub eq_token: Token![=],
@@ -1,20 +1,143 @@
 use crate::{
-    expr::{self, visit_bind_param},
-    query::Query,
+    clause::{Fields, Having, Limit, Offset, SelectCore, SetItem, ValuesItem, Where},
+    command::{Command, Delete, Insert, InsertSource, Update},
+    correlations::Correlations,
+    expr::{
+        self, Between, BinOp, Binary, Call, Cast, Expr, In, InList, Subscript, UnOp, Unary,
+        visit_bind_param,
+    },
+    inferred_type::{InferredType, resolve_not_null_type},
+    part::TargetTable,
+    query::{After, Node, Query},
+    scopes::{ScopeId, Scopes},
     statement::Statement,
-    visit::Visit,
+    visit::{self, Visit},
 };
+use proc_macro_error::{abort, emit_error};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, format_ident, quote};
-use syn::Ident;
+use syn::{Ident, Type, parse_quote};
 
 struct BindParamsBuilder<'a> {
     params: Vec<&'a Ident>,
+    cursor: Option<&'a After>,
+    /// The types of bind parameters as inferred from where they are used, e.g. the type of
+    /// `posts.id` in `posts.id = :id`. The first hint of a bind parameter wins.
+    hints: Vec<(&'a Ident, Hint<'a>)>,
+    scope_ids: Vec<ScopeId>,
+    target_table: Option<&'a TargetTable>,
+    /// The optional bind parameters of the `where` and `having` conditions enclosing the
+    /// current expression. The expression is left out of the SQL if any of them is `None`.
+    guards: Vec<&'a Ident>,
+    /// Every use of a bind parameter, together with the optional bind parameters guarding it.
+    uses: Vec<(&'a expr::BindParam, Vec<&'a Ident>)>,
+    /// The `where` clause of the current `update` or `delete`. Its conditions must not be left
+    /// out, since that would make the command affect every row of the table.
+    mutation_where: Option<&'a Where>,
+    /// The bind parameters that hold the list of an `in`, e.g. `:ids` in `in :ids`.
+    lists: Vec<&'a Ident>,
 }
 
-impl BindParamsBuilder<'_> {
+struct Hint<'a> {
+    inferred_type: InferredType<'a>,
+    /// Whether the bind parameter holds an array of the inferred type, e.g. in `in :ids`.
+    array: bool,
+    /// Whether the type was given explicitly with `:name: Type`.
+    explicit: bool,
+    /// Whether the bind parameter is text that may be passed as any string type, e.g. the
+    /// pattern of `like`.
+    text: bool,
+}
+
+impl<'a> BindParamsBuilder<'a> {
     fn new() -> Self {
-        Self { params: Vec::new() }
+        Self {
+            params: Vec::new(),
+            cursor: None,
+            hints: Vec::new(),
+            scope_ids: Vec::new(),
+            target_table: None,
+            guards: Vec::new(),
+            uses: Vec::new(),
+            mutation_where: None,
+            lists: Vec::new(),
+        }
+    }
+
+    /// Visits the condition of a `where` or `having` clause. Each operand of its top-level `and`
+    /// chain is guarded by the optional bind parameters it refers to, unless the condition must
+    /// not be left out.
+    fn visit_condition(&mut self, expr: &'a Expr, guarded: bool) {
+        for conjunct in expr.conjuncts() {
+            self.hint_rust_type(conjunct, parse_quote!(bool));
+            let len = self.guards.len();
+            if guarded {
+                self.guards.extend(conjunct.optional_bind_params());
+            }
+            self.visit_expr(conjunct);
+            self.guards.truncate(len);
+        }
+    }
+
+    fn scoped(&mut self, scope_id: ScopeId, f: impl FnOnce(&mut Self)) {
+        self.scope_ids.push(scope_id);
+        f(self);
+        self.scope_ids.pop();
+    }
+
+    /// Infers the type of `expr` if it is a bind parameter.
+    fn hint(&mut self, expr: &'a Expr, array: bool, inferred_type: Option<InferredType<'a>>) {
+        let expr = match expr {
+            Expr::Paren(paren) => &paren.expr,
+            expr => expr,
+        };
+        if let Expr::BindParam(bind_param) = expr {
+            self.hint_bind_param(bind_param, array, inferred_type);
+        }
+    }
+
+    fn hint_bind_param(
+        &mut self,
+        bind_param: &'a expr::BindParam,
+        array: bool,
+        inferred_type: Option<InferredType<'a>>,
+    ) {
+        let Some(inferred_type) = inferred_type else {
+            return;
+        };
+        if self.hints.iter().any(|(name, _)| *name == &bind_param.name) {
+            return;
+        }
+        self.hints.push((
+            &bind_param.name,
+            Hint {
+                inferred_type,
+                array,
+                explicit: false,
+                text: false,
+            },
+        ));
+    }
+
+    /// Infers the type of `expr` from the type of `other`, if `expr` is a bind parameter.
+    fn hint_from(&mut self, expr: &'a Expr, array: bool, other: &'a Expr) {
+        if let Some(scope_id) = self.scope_ids.last().copied() {
+            self.hint(expr, array, other.infer_type(scope_id));
+        }
+    }
+
+    fn hint_rust_type(&mut self, expr: &'a Expr, rust_type: Type) {
+        self.hint(expr, false, InferredType::derived(rust_type, []));
+    }
+
+    /// Infers `expr` to be text that may be passed as any string type, if it is a bind
+    /// parameter.
+    fn hint_text(&mut self, expr: &'a Expr) {
+        let len = self.hints.len();
+        self.hint_rust_type(expr, parse_quote!(::std::string::String));
+        if let Some((_, hint)) = self.hints.get_mut(len) {
+            hint.text = true;
+        }
     }
 }
 
@@ -23,45 +146,423 @@ impl<'a> Visit<'a> for BindParamsBuilder<'a> {
         if !self.params.contains(&&bind_param.name) {
             self.params.push(&bind_param.name);
         }
+
+        if bind_param.is_optional() && self.guards.is_empty() {
+            emit_error!(
+                bind_param.span(),
+                "optional bind parameters can only be used in `where` and `having` conditions of \
+                selects and queries"
+            );
+        }
+        self.uses.push((bind_param, self.guards.clone()));
+
+        if let Some(type_override) = &bind_param.type_override {
+            let position = self
+                .hints
+                .iter()
+                .position(|(name, _)| *name == &bind_param.name);
+            if let Some(position) = position {
+                let (_, hint) = &self.hints[position];
+                if hint.explicit {
+                    if let InferredType::RustType(previous) = hint.inferred_type
+                        && *previous != type_override.type_path
+                    {
+                        emit_error!(
+                            type_override.type_path,
+                            "conflicting types for bind parameter `{}`",
+                            bind_param.name
+                        );
+                    }
+                    return;
+                }
+                self.hints.remove(position);
+            }
+            self.hints.push((
+                &bind_param.name,
+                Hint {
+                    inferred_type: InferredType::RustType(&type_override.type_path),
+                    array: false,
+                    explicit: true,
+                    text: false,
+                },
+            ));
+        }
+
         visit_bind_param(self, bind_param);
     }
+
+    fn visit_after(&mut self, after: &'a After) {
+        // The cursor is bound as one parameter per keyset column, so it does not go through
+        // `visit_bind_param`.
+        self.cursor = Some(after);
+    }
+
+    fn visit_command(&mut self, command: &'a Command) {
+        self.scoped(command.scope_id, |this| visit::visit_command(this, command));
+    }
+
+    fn visit_select_core(&mut self, select_core: &'a SelectCore) {
+        self.scoped(select_core.scope_id, |this| {
+            visit::visit_select_core(this, select_core);
+        });
+    }
+
+    fn visit_node(&mut self, node: &'a Node) {
+        self.scoped(node.scope_id, |this| visit::visit_node(this, node));
+    }
+
+    fn visit_insert(&mut self, insert: &'a Insert) {
+        self.target_table = Some(&insert.target_table);
+        // Values are assigned to the listed columns, or to the columns of the table in order.
+        let table_path = &insert.target_table.table;
+        let column_type = |index: usize| match &insert.columns {
+            Some(columns) => columns
+                .columns
+                .iter()
+                .nth(index)
+                .map(|column| InferredType::TableColumn { table_path, column }),
+            None => Some(InferredType::TableColumnAt { table_path, index }),
+        };
+        match &insert.source {
+            InsertSource::Values(values) => {
+                for row in &values.rows {
+                    for (index, item) in row.items.iter().enumerate() {
+                        if let ValuesItem::Expr(expr) = item {
+                            self.hint(expr, false, column_type(index));
+                        }
+                    }
+                }
+            }
+            InsertSource::Select(command) => {
+                for (index, field) in command
+                    .fields()
+                    .into_iter()
+                    .flat_map(Fields::iter)
+                    .enumerate()
+                {
+                    self.hint(&field.expr, false, column_type(index));
+                }
+            }
+            InsertSource::DefaultValues { .. } => {}
+        }
+        visit::visit_insert(self, insert);
+    }
+
+    fn visit_update(&mut self, update: &'a Update) {
+        self.target_table = Some(&update.target_table);
+        self.mutation_where = update.r#where.as_ref();
+        visit::visit_update(self, update);
+    }
+
+    fn visit_delete(&mut self, delete: &'a Delete) {
+        self.mutation_where = delete.r#where.as_ref();
+        visit::visit_delete(self, delete);
+    }
+
+    fn visit_set_item(&mut self, set_item: &'a SetItem) {
+        if let SetItem::Expr { column, expr, .. } = set_item
+            && let Some(target_table) = self.target_table
+        {
+            self.hint(
+                expr,
+                false,
+                Some(InferredType::TableColumn {
+                    table_path: &target_table.table,
+                    column,
+                }),
+            );
+        }
+        visit::visit_set_item(self, set_item);
+    }
+
+    fn visit_binary(&mut self, binary: &'a Binary) {
+        match binary.op {
+            BinOp::Like(_) | BinOp::NotLike(..) | BinOp::Ilike(_) | BinOp::NotIlike(..) => {
+                self.hint_text(&binary.lhs);
+                self.hint_text(&binary.rhs);
+            }
+            BinOp::And(_) | BinOp::Or(_) => {
+                self.hint_rust_type(&binary.lhs, parse_quote!(bool));
+                self.hint_rust_type(&binary.rhs, parse_quote!(bool));
+            }
+            // The operands of `@@` are of different types, a document and a query.
+            BinOp::Is(_) | BinOp::IsNot(..) | BinOp::Match(..) => {}
+            // The key of `->` may be either a field name or an array index.
+            BinOp::JsonGet(_) | BinOp::JsonGetText(..) => {
+                self.hint_rust_type(&binary.lhs, parse_quote!(::serde_json::Value));
+            }
+            BinOp::HasKey(_) => {
+                self.hint_rust_type(&binary.lhs, parse_quote!(::serde_json::Value));
+                self.hint_rust_type(&binary.rhs, parse_quote!(::std::string::String));
+            }
+            BinOp::JsonPath(..)
+            | BinOp::JsonPathText(..)
+            | BinOp::HasAnyKey(..)
+            | BinOp::HasAllKeys(..) => {
+                self.hint_rust_type(&binary.lhs, parse_quote!(::serde_json::Value));
+                self.hint(
+                    &binary.rhs,
+                    true,
+                    InferredType::derived(parse_quote!(::std::string::String), []),
+                );
+            }
+            _ => {
+                if let Expr::Quantified(quantified) = &*binary.rhs {
+                    self.hint_from(&quantified.expr, true, &binary.lhs);
+                } else {
+                    self.hint_from(&binary.lhs, false, &binary.rhs);
+                    self.hint_from(&binary.rhs, false, &binary.lhs);
+                }
+            }
+        }
+        visit::visit_binary(self, binary);
+    }
+
+    fn visit_call(&mut self, call: &'a Call) {
+        if let Some(scope_id) = self.scope_ids.last().copied() {
+            for (index, param) in call.params.iter().enumerate() {
+                self.hint(param, false, call.infer_arg_type(index, scope_id));
+            }
+        }
+        expr::visit_call(self, call);
+    }
+
+    fn visit_subscript(&mut self, subscript: &'a Subscript) {
+        for bound in [&subscript.lower, &subscript.upper].into_iter().flatten() {
+            self.hint_rust_type(bound, parse_quote!(i32));
+        }
+        visit::visit_subscript(self, subscript);
+    }
+
+    fn visit_between(&mut self, between: &'a Between) {
+        self.hint_from(&between.low, false, &between.lhs);
+        self.hint_from(&between.high, false, &between.lhs);
+        self.hint_from(&between.lhs, false, &between.low);
+        visit::visit_between(self, between);
+    }
+
+    fn visit_in(&mut self, r#in: &'a In) {
+        match &r#in.list {
+            InList::Exprs { list, .. } => {
+                for item in list {
+                    self.hint_from(item, false, &r#in.lhs);
+                }
+            }
+            InList::BindParam(bind_param) => {
+                if !self.lists.contains(&&bind_param.name) {
+                    self.lists.push(&bind_param.name);
+                }
+                if let Some(scope_id) = self.scope_ids.last().copied() {
+                    self.hint_bind_param(bind_param, true, r#in.lhs.infer_type(scope_id));
+                }
+            }
+            InList::Subquery { .. } => {}
+        }
+        visit::visit_in(self, r#in);
+    }
+
+    fn visit_cast(&mut self, cast: &'a Cast) {
+        if let Some(rust_type) = cast.data_type.rust_type() {
+            self.hint_rust_type(&cast.value, parse_quote!(#rust_type));
+        }
+        visit::visit_cast(self, cast);
+    }
+
+    fn visit_unary(&mut self, unary: &'a Unary) {
+        match unary.op {
+            UnOp::Not(_) => self.hint_rust_type(&unary.operand, parse_quote!(bool)),
+            UnOp::Plus(_) | UnOp::Minus(_) => {}
+        }
+        visit::visit_unary(self, unary);
+    }
+
+    fn visit_where(&mut self, r#where: &'a Where) {
+        let guarded = !self
+            .mutation_where
+            .is_some_and(|mutation_where| std::ptr::eq(mutation_where, r#where));
+        self.visit_condition(&r#where.expr, guarded);
+    }
+
+    fn visit_having(&mut self, having: &'a Having) {
+        self.visit_condition(&having.expr, true);
+    }
+
+    fn visit_limit(&mut self, limit: &'a Limit) {
+        self.hint_rust_type(&limit.expr, parse_quote!(i64));
+        visit::visit_limit(self, limit);
+    }
+
+    fn visit_offset(&mut self, offset: &'a Offset) {
+        self.hint_rust_type(&offset.expr, parse_quote!(i64));
+        visit::visit_offset(self, offset);
+    }
 }
 
 pub struct BindParams<'a> {
-    params: Vec<&'a Ident>,
+    params: Vec<BindParamField<'a>>,
+    cursor: Option<&'a After>,
 }
 
-impl BindParams<'_> {
+struct BindParamField<'a> {
+    name: &'a Ident,
+    /// The Rust type of the bind parameter, if it could be inferred.
+    rust_type: Option<Type>,
+    /// Whether the bind parameter is optional, e.g. `:name?`.
+    optional: bool,
+    /// The optional bind parameters guarding each use of the bind parameter. It is omitted if
+    /// one of them is `None` for every use, or never if any use is unguarded.
+    #[cfg_attr(
+        not(any(feature = "postgres", feature = "tokio-postgres")),
+        allow(dead_code)
+    )]
+    guards: Option<Vec<Vec<&'a Ident>>>,
+    /// Whether the bind parameter holds the list of an `in`.
+    #[cfg_attr(
+        not(any(feature = "postgres", feature = "tokio-postgres")),
+        allow(dead_code)
+    )]
+    list: bool,
+    /// Whether the bind parameter is text that is passed as a `kosame::params::Text`.
+    text: bool,
+}
+
+impl<'a> BindParams<'a> {
     #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.params.is_empty()
+    pub fn from_statement(
+        statement: &'a Statement,
+        correlations: &Correlations<'_>,
+        scopes: &Scopes<'_>,
+    ) -> Self {
+        let mut builder = BindParamsBuilder::new();
+        builder.visit_statement(statement);
+        Self::new(builder, correlations, scopes)
     }
-}
 
-impl<'a> From<&'a Statement> for BindParams<'a> {
-    fn from(value: &'a Statement) -> Self {
+    #[must_use]
+    pub fn from_query(
+        query: &'a Query,
+        correlations: &Correlations<'_>,
+        scopes: &Scopes<'_>,
+    ) -> Self {
         let mut builder = BindParamsBuilder::new();
-        builder.visit_statement(value);
-        Self {
-            params: builder.params,
+        builder.visit_node(&query.body);
+
+        if let Some(after) = builder.cursor {
+            let name = &after.bind_param.name;
+            if builder.params.contains(&name) {
+                emit_error!(
+                    after.bind_param.span(),
+                    "the cursor bind parameter `{}` cannot be used in other expressions",
+                    name
+                );
+            }
         }
+
+        Self::new(builder, correlations, scopes)
     }
-}
 
-impl<'a> From<&'a Query> for BindParams<'a> {
-    fn from(value: &'a Query) -> Self {
-        let mut builder = BindParamsBuilder::new();
-        builder.visit_node(&value.body);
+    fn new(
+        builder: BindParamsBuilder<'a>,
+        correlations: &Correlations<'_>,
+        scopes: &Scopes<'_>,
+    ) -> Self {
+        let mut hints = builder.hints;
+        let uses = builder.uses;
+        let lists = builder.lists;
+        let params = builder
+            .params
+            .into_iter()
+            .map(|name| {
+                let hint = hints
+                    .iter()
+                    .position(|(hint_name, _)| *hint_name == name)
+                    .map(|position| hints.remove(position).1);
+                let text = hint.as_ref().is_some_and(|hint| hint.text) && !lists.contains(&name);
+                let rust_type = hint.and_then(|hint| {
+                        // Explicit types are taken as written, including an `Option`.
+                        if hint.explicit
+                            && let InferredType::RustType(rust_type) = hint.inferred_type
+                        {
+                            return Some(parse_quote!(#rust_type));
+                        }
+                        let rust_type =
+                            resolve_not_null_type(correlations, scopes, hint.inferred_type)?;
+                        Some(if hint.array {
+                            parse_quote!(::std::vec::Vec<#rust_type>)
+                        } else {
+                            rust_type
+                        })
+                    });
+
+                let uses = uses.iter().filter(|(bind_param, _)| bind_param.name == *name);
+                let optional = uses.clone().any(|(bind_param, _)| bind_param.is_optional());
+                if optional {
+                    for (bind_param, _) in uses.clone().filter(|(bind_param, _)| !bind_param.is_optional()) {
+                        emit_error!(
+                            bind_param.span(),
+                            "bind parameter `{}` is optional, mark it with `:{}?` here too",
+                            name,
+                            name
+                        );
+                    }
+                    if rust_type.is_none() {
+                        abort!(
+                            name,
+                            "cannot infer the type of optional bind parameter `{}`, specify it like `:{}?: String`",
+                            name,
+                            name
+                        );
+                    }
+                }
+
+                let mut guards = Some(Vec::new());
+                for (_, use_guards) in uses {
+                    if use_guards.is_empty() {
+                        guards = None;
+                        break;
+                    }
+                    if let Some(guards) = &mut guards
+                        && !guards.contains(use_guards)
+                    {
+                        guards.push(use_guards.clone());
+                    }
+                }
+
+                BindParamField {
+                    name,
+                    rust_type,
+                    optional,
+                    guards,
+                    list: lists.contains(&name),
+                    text,
+                }
+            })
+            .collect();
+
         Self {
-            params: builder.params,
+            params,
+            cursor: builder.cursor,
         }
     }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty() && self.cursor.is_none()
+    }
+
+    fn names(&self) -> impl Iterator<Item = &Ident> {
+        self.params
+            .iter()
+            .map(|param| param.name)
+            .chain(self.cursor.map(|after| &after.bind_param.name))
+    }
 }
 
 impl ToTokens for BindParams<'_> {
+    #[allow(clippy::too_many_lines)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let mut modules = vec![];
-        for (ordinal, name) in self.params.iter().enumerate() {
+        for (ordinal, BindParamField { name, .. }) in self.params.iter().enumerate() {
             let ordinal = u32::try_from(ordinal).unwrap();
             let name_string = name.to_string();
             modules.push(quote! {
@@ -71,10 +572,55 @@ impl ToTokens for BindParams<'_> {
             });
         }
 
+        if let Some(after) = self.cursor {
+            let name = &after.bind_param.name;
+            let bind_params = (0..after.len()).map(|index| {
+                let ordinal = u32::try_from(self.params.len() + index).unwrap();
+                let name_string = format!("{name}_{index}");
+                quote! { ::kosame::repr::expr::BindParam::new(#name_string, #ordinal) }
+            });
+            modules.push(quote! {
+                pub(super) mod #name {
+                    pub const BIND_PARAMS: &[::kosame::repr::expr::BindParam<'_>] = &[#(#bind_params),*];
+                }
+            });
+        }
+
         let mut fields = vec![];
-        for name in &self.params {
+        for BindParamField {
+            name,
+            rust_type,
+            optional,
+            text,
+            ..
+        } in &self.params
+        {
+            if *text {
+                if *optional {
+                    fields.push(
+                        quote! { #name: ::core::option::Option<&'a dyn ::kosame::params::Text> },
+                    );
+                } else {
+                    fields.push(quote! { #name: &'a dyn ::kosame::params::Text });
+                }
+                continue;
+            }
+            // Bind parameters whose type cannot be inferred accept any value.
+            let rust_type = if let Some(rust_type) = rust_type {
+                rust_type.to_token_stream()
+            } else {
+                quote! { (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync) }
+            };
+            if *optional {
+                fields.push(quote! { #name: &'a ::core::option::Option<#rust_type> });
+            } else {
+                fields.push(quote! { #name: &'a #rust_type });
+            }
+        }
+        if let Some(after) = self.cursor {
+            let name = &after.bind_param.name;
             fields.push(quote! {
-                #name: &'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync)
+                #name: &'a ::core::option::Option<::kosame::query::Cursor>
             });
         }
         let fields_len = fields.len();
@@ -95,12 +641,129 @@ impl ToTokens for BindParams<'_> {
 
         #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
         {
-            let field_names = &self.params;
+            let omitted = self
+                .params
+                .iter()
+                .enumerate()
+                .filter_map(|(ordinal, param)| {
+                    let ordinal = u32::try_from(ordinal).unwrap();
+                    let guards = param.guards.as_ref()?.iter().map(|guards| {
+                        quote! { (#(self.#guards.is_none())||*) }
+                    });
+                    Some(quote! {
+                        if #(#guards)&&* {
+                            omitted.push(#ordinal);
+                        }
+                    })
+                });
+            let omitted_bind_params =
+                self.params
+                    .iter()
+                    .any(|param| param.guards.is_some())
+                    .then(|| {
+                        quote! {
+                            fn omitted_bind_params(&self) -> ::std::vec::Vec<u32> {
+                                let mut omitted = ::std::vec::Vec::new();
+                                #(#omitted)*
+                                omitted
+                            }
+                        }
+                    });
+            // The length of a list whose type is unknown cannot be told, in which case the list
+            // can only be bound as an array.
+            let list_lens = self
+                .params
+                .iter()
+                .enumerate()
+                .filter(|(_, param)| param.list && param.rust_type.is_some())
+                .map(|(ordinal, param)| {
+                    let ordinal = u32::try_from(ordinal).unwrap();
+                    let name = param.name;
+                    if param.optional {
+                        quote! { (#ordinal, ::core::option::Option::as_ref(self.#name).map_or(0, |list| list.len())) }
+                    } else {
+                        quote! { (#ordinal, self.#name.len()) }
+                    }
+                })
+                .collect::<Vec<_>>();
+            let list_lens = (!list_lens.is_empty()).then(|| {
+                quote! {
+                    fn list_lens(&self) -> ::std::vec::Vec<(u32, usize)> {
+                        ::std::vec![#(#list_lens),*]
+                    }
+                }
+            });
+            let values = self.params.iter().enumerate().map(|(ordinal, param)| {
+                let ordinal = u32::try_from(ordinal).unwrap();
+                let name = param.name;
+                if param.text {
+                    let value = quote! { ::kosame::params::Text::to_sql_ref(value) };
+                    if param.optional {
+                        quote! {
+                            if let ::core::option::Option::Some(value) = self.#name.filter(|_| !omitted.contains(&#ordinal)) {
+                                params.push(#value);
+                            }
+                        }
+                    } else if param.guards.is_some() {
+                        quote! {
+                            if !omitted.contains(&#ordinal) {
+                                let value = self.#name;
+                                params.push(#value);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            let value = self.#name;
+                            params.push(#value);
+                        }
+                    }
+                } else if param.optional {
+                    quote! {
+                        if let ::core::option::Option::Some(value) = ::core::option::Option::as_ref(self.#name).filter(|_| !omitted.contains(&#ordinal)) {
+                            params.push(value as &(dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync));
+                        }
+                    }
+                } else if param.guards.is_some() {
+                    quote! {
+                        if !omitted.contains(&#ordinal) {
+                            params.push(self.#name as &(dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync));
+                        }
+                    }
+                } else {
+                    quote! {
+                        params.push(self.#name as &(dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync));
+                    }
+                }
+            });
+            let omitted = omitted_bind_params.is_some().then(|| {
+                quote! { let omitted = ::kosame::params::Params::<::std::vec::Vec<_>>::omitted_bind_params(self); }
+            });
+            let cursor_values = self.cursor.into_iter().flat_map(|after| {
+                let name = &after.bind_param.name;
+                (0..after.len()).map(move |index| {
+                    quote! { params.push(::kosame::query::Cursor::bind_value(self.#name, #index)); }
+                })
+            });
+            let body = if self.is_empty() {
+                quote! { ::std::vec::Vec::new() }
+            } else {
+                quote! {
+                    #omitted
+                    let mut params: Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> = ::std::vec::Vec::new();
+                    #(#values)*
+                    #(#cursor_values)*
+                    params
+                }
+            };
             quote! {
             impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for Params #lifetime {
                 fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
-                    vec![#(self.#field_names),*]
+                    #body
                 }
+
+                #omitted_bind_params
+
+                #list_lens
             }
         }.to_tokens(tokens);
         }
@@ -128,10 +791,21 @@ impl ToTokens for BindParamsClosure<'_> {
 
         let mut rename_vars = vec![];
         let mut struct_fields = vec![];
-        for (ordinal, name) in self.bind_params.params.iter().enumerate() {
+        for (ordinal, name) in self.bind_params.names().enumerate() {
             let renamed = format_ident!("bind_param_{}", ordinal);
             rename_vars.push(quote! { let #renamed = &#name; });
-            struct_fields.push(quote! { #name: #renamed });
+            let optional_text = self
+                .bind_params
+                .params
+                .iter()
+                .any(|param| param.name == name && param.text && param.optional);
+            if optional_text {
+                struct_fields.push(quote! {
+                    #name: #renamed.as_ref().map(|value| value as &dyn ::kosame::params::Text)
+                });
+            } else {
+                struct_fields.push(quote! { #name: #renamed });
+            }
         }
 
         quote! {
@@ -10,7 +10,7 @@ use syn::{
 use crate::{
     clause::peek_clause,
     correlations::{CorrelationId, Correlations},
-    expr::ExprRoot,
+    expr::{ColumnRef, ExprRoot},
     inferred_type::{InferredType, resolve_type},
     parse_option::ParseOption,
     part::{Alias, TypeOverride},
@@ -45,12 +45,20 @@ impl Field {
             return None;
         };
         let Some(resolved_type) = resolve_type(correlations, scopes, correlation_id, name) else {
-            emit_error!(
-                self.expr.span(),
-                "field type cannot be inferred";
-                help = "consider adding a type override using `: RustType`";
-                note = "Kosame can only infer the type of a column when it is qualified with its table, e.g. `posts.id` instead of `id`"
-            );
+            if has_unqualified_column(&self.expr) {
+                emit_error!(
+                    self.expr.span(),
+                    "field type cannot be inferred";
+                    help = "consider adding a type override using `: RustType`";
+                    note = "Kosame can only infer the type of an unqualified column like `id` if there is a single table in scope, otherwise it must be qualified, e.g. `posts.id`"
+                );
+            } else {
+                emit_error!(
+                    self.expr.span(),
+                    "field type cannot be inferred";
+                    help = "consider adding a type override using `: RustType`"
+                );
+            }
             return None;
         };
         Some(RowField::new(
@@ -77,6 +85,22 @@ impl Field {
     }
 }
 
+/// Whether the expression refers to a column without qualifying it, whose type can only be
+/// inferred if there is a single table in scope.
+fn has_unqualified_column(expr: &ExprRoot) -> bool {
+    struct Finder(bool);
+
+    impl<'a> Visit<'a> for Finder {
+        fn visit_column_ref(&mut self, column_ref: &'a ColumnRef) {
+            self.0 |= column_ref.correlation.is_none();
+        }
+    }
+
+    let mut finder = Finder(false);
+    finder.visit_expr_root(expr);
+    finder.0
+}
+
 pub fn visit_field<'a>(visit: &mut (impl Visit<'a> + ?Sized), field: &'a Field) {
     visit.visit_expr_root(&field.expr);
 }
@@ -9,13 +9,12 @@ use crate::{
     clause::{Clause, WithItem},
     command::Command,
     correlations::CorrelationId,
-    expr::ExprRoot,
+    expr::{Call, ExprRoot},
     keyword,
     parse_option::ParseOption,
     part::{TableAlias, TablePath},
     pretty::{BreakMode, Delim, PrettyPrint, Printer},
     quote_option::QuoteOption,
-    scopes::ScopeId,
     visit::Visit,
 };
 
@@ -154,6 +153,13 @@ pub enum FromItem {
         alias: Option<TableAlias>,
         correlation_id: CorrelationId,
     },
+    /// A set-returning function like `unnest(:ids)`. Its single column is named like its alias
+    /// or, without an alias, like the function.
+    Function {
+        call: Call,
+        alias: Option<TableAlias>,
+        correlation_id: CorrelationId,
+    },
 }
 
 impl FromItem {
@@ -174,6 +180,10 @@ impl FromItem {
                 |alias| &alias.name,
             )),
             Self::Subquery { alias, .. } => alias.as_ref().map(|alias| &alias.name),
+            Self::Function { call, alias, .. } => alias
+                .as_ref()
+                .map(|alias| &alias.name)
+                .or_else(|| call.infer_name()),
         }
     }
 
@@ -182,6 +192,7 @@ impl FromItem {
         match self {
             Self::Table { correlation_id, .. } => *correlation_id,
             Self::Subquery { correlation_id, .. } => *correlation_id,
+            Self::Function { correlation_id, .. } => *correlation_id,
         }
     }
 
@@ -206,6 +217,7 @@ impl FromItem {
                 .into_iter()
                 .flat_map(|fields| fields.columns())
                 .collect(),
+            Self::Function { .. } => self.name().into_iter().collect(),
         }
     }
 }
@@ -218,6 +230,9 @@ pub fn visit_from_item<'a>(visit: &mut (impl Visit<'a> + ?Sized), from_item: &'a
         FromItem::Subquery { command, .. } => {
             visit.visit_command(command);
         }
+        FromItem::Function { call, .. } => {
+            visit.visit_call(call);
+        }
     }
 }
 
@@ -237,6 +252,12 @@ impl Parse for FromItem {
                 alias: input.call(TableAlias::parse_option)?,
                 correlation_id: CorrelationId::new(),
             })
+        } else if Call::peek(input) {
+            Ok(Self::Function {
+                call: input.parse()?,
+                alias: input.call(TableAlias::parse_option)?,
+                correlation_id: CorrelationId::new(),
+            })
         } else if lookahead.peek(Ident) {
             Ok(Self::Table {
                 table_path: input.parse()?,
@@ -255,20 +276,13 @@ impl ToTokens for FromItem {
             Self::Table {
                 table_path, alias, ..
             } => {
-                let table = alias.as_ref().map_or(
-                    &table_path
-                        .as_path()
-                        .segments
-                        .last()
-                        .expect("paths cannot be empty")
-                        .ident,
-                    |alias| &alias.name,
-                );
+                // The correlation of the table path itself, since an alias would replace the
+                // table name.
+                let table = table_path.correlation_id;
                 let alias = QuoteOption::from(alias);
-                let scope_id = ScopeId::of_scope();
                 quote! {
                     ::kosame::repr::clause::FromItem::Table {
-                        table: scopes::#scope_id::tables::#table::TABLE_NAME,
+                        table: correlations::#table::TABLE_NAME,
                         alias: #alias,
                     }
                 }
@@ -289,6 +303,15 @@ impl ToTokens for FromItem {
                     }
                 }
             }
+            Self::Function { call, alias, .. } => {
+                let alias = QuoteOption::from(alias);
+                quote! {
+                    ::kosame::repr::clause::FromItem::Function {
+                        call: #call,
+                        alias: #alias,
+                    }
+                }
+            }
         }
         .to_tokens(tokens);
     }
@@ -319,6 +342,10 @@ impl PrettyPrint for FromItem {
                 });
                 alias.pretty_print(printer);
             }
+            Self::Function { call, alias, .. } => {
+                call.pretty_print(printer);
+                alias.pretty_print(printer);
+            }
         }
     }
 }
@@ -37,7 +37,7 @@ impl Parse for Having {
 
 impl ToTokens for Having {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let expr = &self.expr;
+        let expr = self.expr.to_condition_tokens();
         quote! { ::kosame::repr::clause::Having::new(#expr) }.to_tokens(tokens);
     }
 }
@@ -4,12 +4,14 @@ mod group_by;
 mod having;
 mod limit;
 mod offset;
+mod on_conflict;
 mod order_by;
 mod returning;
 mod select;
 mod set;
 mod values;
 mod r#where;
+mod window;
 mod with;
 
 pub use field::*;
@@ -18,12 +20,14 @@ pub use group_by::*;
 pub use having::*;
 pub use limit::*;
 pub use offset::*;
+pub use on_conflict::*;
 pub use order_by::*;
 pub use returning::*;
 pub use select::*;
 pub use set::*;
 pub use values::*;
 pub use r#where::*;
+pub use window::*;
 pub use with::*;
 
 use crate::{
@@ -38,9 +42,11 @@ pub fn peek_clause(input: syn::parse::ParseStream) -> bool {
         || Where::peek(input)
         || GroupBy::peek(input)
         || Having::peek(input)
+        || Window::peek(input)
         || OrderBy::peek(input)
         || Limit::peek(input)
         || Offset::peek(input)
+        || OnConflict::peek(input)
         || Returning::peek(input)
         || Set::peek(input)
         || Values::peek(input)
@@ -0,0 +1,271 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    clause::{Clause, Set, Where},
+    correlations::CorrelationId,
+    keyword,
+    parse_option::ParseOption,
+    part::ColumnList,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    quote_option::QuoteOption,
+    scopes::ScopeId,
+    visit::Visit,
+};
+
+pub struct OnConflict {
+    pub on_keyword: keyword::on,
+    pub conflict_keyword: keyword::conflict,
+    pub target: Option<ConflictTarget>,
+    pub do_token: Token![do],
+    pub action: ConflictAction,
+}
+
+impl OnConflict {
+    /// Returns the table of the rows proposed for insertion, if the action is `do update`.
+    #[must_use]
+    pub fn excluded(&self) -> Option<&Excluded> {
+        match &self.action {
+            ConflictAction::Nothing { .. } => None,
+            ConflictAction::Update { excluded, .. } => Some(excluded),
+        }
+    }
+}
+
+impl ParseOption for OnConflict {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::on) && input.peek2(keyword::conflict)
+    }
+}
+
+pub fn visit_on_conflict<'a>(visit: &mut (impl Visit<'a> + ?Sized), on_conflict: &'a OnConflict) {
+    if let Some(ConflictTarget::Columns {
+        r#where: Some(r#where),
+        ..
+    }) = &on_conflict.target
+    {
+        visit.visit_where(r#where);
+    }
+    if let ConflictAction::Update { set, r#where, .. } = &on_conflict.action {
+        visit.visit_set(set);
+        if let Some(inner) = r#where {
+            visit.visit_where(inner);
+        }
+    }
+}
+
+impl Parse for OnConflict {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let on_keyword = input.parse()?;
+        let conflict_keyword = input.parse()?;
+        let target = if input.peek(syn::token::Paren) {
+            Some(ConflictTarget::Columns {
+                columns: input.parse()?,
+                r#where: input.call(Where::parse_option)?,
+            })
+        } else if input.peek(keyword::on) {
+            Some(ConflictTarget::Constraint {
+                on_keyword: input.parse()?,
+                constraint_keyword: input.call(keyword::constraint::parse_autocomplete)?,
+                name: input.parse()?,
+            })
+        } else {
+            None
+        };
+        let do_token = input.parse()?;
+
+        let action = if input.peek(keyword::nothing) {
+            ConflictAction::Nothing {
+                nothing_keyword: input.parse()?,
+            }
+        } else if input.peek(keyword::update) {
+            let update_keyword: keyword::update = input.parse()?;
+            if target.is_none() {
+                return Err(syn::Error::new(
+                    update_keyword.span,
+                    "`do update` requires a conflict target, e.g. `on conflict (id)`",
+                ));
+            }
+            ConflictAction::Update {
+                update_keyword,
+                set: input.parse()?,
+                r#where: input.call(Where::parse_option)?,
+                excluded: Excluded {
+                    name: Ident::new("excluded", update_keyword.span),
+                    correlation_id: CorrelationId::new(),
+                },
+            }
+        } else {
+            keyword::group_conflict_action::error(input);
+        };
+
+        Ok(Self {
+            on_keyword,
+            conflict_keyword,
+            target,
+            do_token,
+            action,
+        })
+    }
+}
+
+impl ToTokens for OnConflict {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let target = QuoteOption::from(&self.target);
+        let action = &self.action;
+        quote! {
+            ::kosame::repr::clause::OnConflict::new(#target, #action)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for OnConflict {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        let action = DoAction(self);
+        match &self.target {
+            Some(target) => {
+                Clause::new(&[&self.on_keyword, &self.conflict_keyword, target], &action)
+                    .pretty_print(printer);
+            }
+            None => {
+                Clause::new(&[&self.on_keyword, &self.conflict_keyword], &action)
+                    .pretty_print(printer);
+            }
+        }
+
+        if let ConflictAction::Update { set, r#where, .. } = &self.action {
+            set.pretty_print(printer);
+            r#where.pretty_print(printer);
+        }
+    }
+}
+
+/// The `do nothing` or `do update` that makes up the body of the `on conflict` clause.
+struct DoAction<'a>(&'a OnConflict);
+
+impl PrettyPrint for DoAction<'_> {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.0.do_token.pretty_print(printer);
+        " ".pretty_print(printer);
+        match &self.0.action {
+            ConflictAction::Nothing { nothing_keyword } => nothing_keyword.pretty_print(printer),
+            ConflictAction::Update { update_keyword, .. } => update_keyword.pretty_print(printer),
+        }
+    }
+}
+
+pub enum ConflictTarget {
+    Columns {
+        columns: ColumnList,
+        r#where: Option<Where>,
+    },
+    Constraint {
+        on_keyword: keyword::on,
+        constraint_keyword: keyword::constraint,
+        name: Ident,
+    },
+}
+
+impl ToTokens for ConflictTarget {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Columns { columns, r#where } => {
+                // Resolving the columns through the scope checks that the target table has them.
+                let scope_id = ScopeId::of_scope();
+                let columns = columns.columns.iter();
+                let r#where = QuoteOption::from(r#where);
+                quote! {
+                    ::kosame::repr::clause::ConflictTarget::Columns {
+                        columns: ::kosame::repr::part::ColumnList::new(
+                            &[#(scopes::#scope_id::columns::#columns::COLUMN_NAME),*]
+                        ),
+                        r#where: #r#where,
+                    }
+                }
+            }
+            Self::Constraint { name, .. } => {
+                let name = name.to_string();
+                quote! {
+                    ::kosame::repr::clause::ConflictTarget::Constraint(#name)
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for ConflictTarget {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        match self {
+            Self::Columns { columns, r#where } => {
+                columns
+                    .paren_token
+                    .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                        columns.columns.pretty_print(printer);
+                    });
+                if let Some(r#where) = r#where {
+                    " ".pretty_print(printer);
+                    r#where.where_token.pretty_print(printer);
+                    " ".pretty_print(printer);
+                    r#where.expr.pretty_print(printer);
+                }
+            }
+            Self::Constraint {
+                on_keyword,
+                constraint_keyword,
+                name,
+            } => {
+                on_keyword.pretty_print(printer);
+                " ".pretty_print(printer);
+                constraint_keyword.pretty_print(printer);
+                " ".pretty_print(printer);
+                name.pretty_print(printer);
+            }
+        }
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum ConflictAction {
+    Nothing {
+        nothing_keyword: keyword::nothing,
+    },
+    Update {
+        update_keyword: keyword::update,
+        set: Set,
+        r#where: Option<Where>,
+        excluded: Excluded,
+    },
+}
+
+/// The `excluded` table of a `do update`, which has the columns of the target table but is not
+/// spelled out in the statement itself.
+pub struct Excluded {
+    pub name: Ident,
+    pub correlation_id: CorrelationId,
+}
+
+impl ToTokens for ConflictAction {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Nothing { .. } => quote! {
+                ::kosame::repr::clause::ConflictAction::Nothing
+            },
+            Self::Update { set, r#where, .. } => {
+                let r#where = QuoteOption::from(r#where);
+                quote! {
+                    ::kosame::repr::clause::ConflictAction::Update {
+                        set: #set,
+                        r#where: #r#where,
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
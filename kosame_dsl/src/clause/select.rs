@@ -3,7 +3,7 @@ use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
 use crate::{
-    clause::{Clause, Fields, From, FromChain, GroupBy, Having, Where},
+    clause::{Clause, Fields, From, FromChain, GroupBy, Having, Where, Window},
     keyword,
     parse_option::ParseOption,
     pretty::{PrettyPrint, Printer},
@@ -59,6 +59,7 @@ pub struct SelectCore {
     pub r#where: Option<Where>,
     pub group_by: Option<GroupBy>,
     pub having: Option<Having>,
+    pub window: Option<Window>,
 }
 
 pub fn visit_select_core<'a>(visit: &mut (impl Visit<'a> + ?Sized), select_core: &'a SelectCore) {
@@ -75,6 +76,9 @@ pub fn visit_select_core<'a>(visit: &mut (impl Visit<'a> + ?Sized), select_core:
     if let Some(inner) = select_core.having.as_ref() {
         visit.visit_having(inner);
     }
+    if let Some(inner) = select_core.window.as_ref() {
+        visit.visit_window(inner);
+    }
 }
 
 impl Scoped for SelectCore {
@@ -91,14 +95,19 @@ impl Scoped for SelectCore {
 
 impl Parse for SelectCore {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
+        let select_core = Self {
             scope_id: ScopeId::new(),
             select: input.parse()?,
             from: input.call(From::parse_option)?,
             r#where: input.call(Where::parse_option)?,
             group_by: input.call(GroupBy::parse_option)?,
             having: input.call(Having::parse_option)?,
-        })
+            window: input.call(Window::parse_option)?,
+        };
+        Window::check_references(select_core.window.as_ref(), |references| {
+            visit_select_core(references, &select_core);
+        })?;
+        Ok(select_core)
     }
 }
 
@@ -116,6 +125,7 @@ impl ToTokens for SelectCore {
             let r#where = QuoteOption::from(&self.r#where);
             let group_by = QuoteOption::from(&self.group_by);
             let having = QuoteOption::from(&self.having);
+            let window = QuoteOption::from(&self.window);
 
             quote! {
                 ::kosame::repr::clause::SelectCore::new(
@@ -124,6 +134,7 @@ impl ToTokens for SelectCore {
                     #r#where,
                     #group_by,
                     #having,
+                    #window,
                 )
             }
             .to_tokens(tokens);
@@ -138,5 +149,6 @@ impl PrettyPrint for SelectCore {
         self.r#where.pretty_print(printer);
         self.group_by.pretty_print(printer);
         self.having.pretty_print(printer);
+        self.window.pretty_print(printer);
     }
 }
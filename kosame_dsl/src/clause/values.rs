@@ -67,8 +67,8 @@ impl PrettyPrint for Values {
 }
 
 pub struct ValuesRow {
-    paren_token: syn::token::Paren,
-    items: Punctuated<ValuesItem, Token![,]>,
+    pub paren_token: syn::token::Paren,
+    pub items: Punctuated<ValuesItem, Token![,]>,
 }
 
 pub fn visit_values_row<'a>(visit: &mut (impl Visit<'a> + ?Sized), values_row: &'a ValuesRow) {
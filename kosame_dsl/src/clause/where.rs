@@ -39,7 +39,7 @@ impl Parse for Where {
 
 impl ToTokens for Where {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let expr = &self.expr;
+        let expr = self.expr.to_condition_tokens();
         quote! { ::kosame::repr::clause::Where::new(#expr) }.to_tokens(tokens);
     }
 }
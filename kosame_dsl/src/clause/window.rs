@@ -0,0 +1,260 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    clause::{Clause, SelectCore, peek_clause},
+    command::Command,
+    expr::Call,
+    keyword,
+    parse_option::ParseOption,
+    part::{OverWindow, WindowSpec},
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    visit::{self, Visit},
+};
+
+/// Named window definitions, e.g. `window w as (partition by post_id)`, for use in
+/// `over w` or as the base of another window definition.
+pub struct Window {
+    pub window_keyword: keyword::window,
+    pub items: Punctuated<WindowItem, Token![,]>,
+}
+
+impl ParseOption for Window {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::window)
+    }
+}
+
+pub fn visit_window<'a>(visit: &mut (impl Visit<'a> + ?Sized), window: &'a Window) {
+    for item in &window.items {
+        visit.visit_window_spec(&item.spec);
+    }
+}
+
+impl Window {
+    /// Checks that the windows referenced by `over name` or `over (name ...)` in the visited
+    /// expressions are defined in the given `window` clause.
+    pub fn check_references<'a>(
+        window: Option<&'a Self>,
+        visit: impl FnOnce(&mut WindowReferences<'a>),
+    ) -> syn::Result<()> {
+        let mut references = WindowReferences::default();
+        visit(&mut references);
+        for name in references.0 {
+            if !window.is_some_and(|window| window.items.iter().any(|item| item.name == *name)) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("window `{name}` is not defined"),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Collects the window names referenced by window function calls. Nested commands and select
+/// cores are skipped, since they are checked against their own `window` clause.
+#[derive(Default)]
+pub struct WindowReferences<'a>(Vec<&'a Ident>);
+
+impl<'a> Visit<'a> for WindowReferences<'a> {
+    fn visit_call(&mut self, call: &'a Call) {
+        if let Some(over) = &call.over
+            && let OverWindow::Name(name) = &over.window
+        {
+            self.0.push(name);
+        }
+        visit::visit_call(self, call);
+    }
+
+    fn visit_window_spec(&mut self, window_spec: &'a WindowSpec) {
+        if let Some(existing) = &window_spec.existing {
+            self.0.push(existing);
+        }
+        visit::visit_window_spec(self, window_spec);
+    }
+
+    fn visit_command(&mut self, _command: &'a Command) {}
+
+    fn visit_select_core(&mut self, _select_core: &'a SelectCore) {}
+}
+
+impl Parse for Window {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let window = Self {
+            window_keyword: input.call(keyword::window::parse_autocomplete)?,
+            items: {
+                let mut punctuated = Punctuated::new();
+                while !input.is_empty() {
+                    if peek_clause(input) {
+                        break;
+                    }
+                    punctuated.push(input.parse()?);
+                    if !input.peek(Token![,]) {
+                        break;
+                    }
+                    punctuated.push_punct(input.parse()?);
+                }
+                if punctuated.is_empty() {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "window clause cannot be empty",
+                    ));
+                }
+                punctuated
+            },
+        };
+
+        // A window definition can only build on windows defined before it.
+        for (index, item) in window.items.iter().enumerate() {
+            let earlier = || window.items.iter().take(index);
+            if earlier().any(|earlier| earlier.name == item.name) {
+                return Err(syn::Error::new(
+                    item.name.span(),
+                    format!("window `{}` is already defined", item.name),
+                ));
+            }
+            if let Some(existing) = &item.spec.existing
+                && !earlier().any(|earlier| earlier.name == *existing)
+            {
+                return Err(syn::Error::new(
+                    existing.span(),
+                    format!("window `{existing}` is not defined before `{}`", item.name),
+                ));
+            }
+        }
+        Ok(window)
+    }
+}
+
+impl ToTokens for Window {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let items = self.items.iter();
+        quote! { ::kosame::repr::clause::Window::new(&[#(#items),*]) }.to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Window {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        Clause::new(&[&self.window_keyword], &self.items).pretty_print(printer);
+    }
+}
+
+pub struct WindowItem {
+    pub name: Ident,
+    pub as_token: Token![as],
+    pub paren_token: syn::token::Paren,
+    pub spec: WindowSpec,
+}
+
+impl Parse for WindowItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            name: input.parse()?,
+            as_token: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            spec: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for WindowItem {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = self.name.to_string();
+        let spec = &self.spec;
+        quote! { ::kosame::repr::clause::WindowItem::new(#name, #spec) }.to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for WindowItem {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.name.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.as_token.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.paren_token
+            .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                self.spec.pretty_print(printer);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::command::Command;
+
+    fn error(command: &str) -> Option<String> {
+        syn::parse_str::<Command>(command)
+            .err()
+            .map(|error| error.to_string())
+    }
+
+    #[test]
+    fn test_defined_windows() {
+        assert_eq!(
+            error(
+                "select rank() over w, sum(upvotes) over (w2 rows unbounded preceding) \
+                 from schema::posts \
+                 window w as (partition by author_id), w2 as (w order by upvotes) \
+                 order by rank() over w"
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_undefined_window() {
+        assert_eq!(
+            error("select rank() over w from schema::posts").as_deref(),
+            Some("window `w` is not defined")
+        );
+        assert_eq!(
+            error("select rank() over (w order by id) from schema::posts window v as ()")
+                .as_deref(),
+            Some("window `w` is not defined")
+        );
+        assert_eq!(
+            error("update schema::posts set upvotes = 1 returning rank() over w").as_deref(),
+            Some("window `w` is not defined")
+        );
+    }
+
+    #[test]
+    fn test_windows_of_nested_select() {
+        assert_eq!(
+            error(
+                "select (select rank() over w from schema::posts) \
+                 from schema::posts window w as ()"
+            )
+            .as_deref(),
+            Some("window `w` is not defined")
+        );
+        assert_eq!(
+            error(
+                "select id from schema::posts window w as () \
+                 union select id from schema::posts window w as () \
+                 order by rank() over w"
+            )
+            .as_deref(),
+            Some("window `w` is not defined")
+        );
+    }
+
+    #[test]
+    fn test_window_definition_order() {
+        assert_eq!(
+            error("select id from schema::posts window w2 as (w), w as ()").as_deref(),
+            Some("window `w` is not defined before `w2`")
+        );
+        assert_eq!(
+            error("select id from schema::posts window w as (), w as ()").as_deref(),
+            Some("window `w` is already defined")
+        );
+    }
+}
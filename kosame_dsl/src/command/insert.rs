@@ -1,12 +1,14 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::parse::{Parse, ParseStream};
 
 use crate::{
-    clause::{Returning, Values},
+    clause::{OnConflict, Returning, Values},
+    command::{Command, CommandType},
     keyword,
     parse_option::ParseOption,
-    part::TargetTable,
+    part::{ColumnList, TargetTable},
+    path_ext::PathExt,
     pretty::{PrettyPrint, Printer},
     quote_option::QuoteOption,
     visit::Visit,
@@ -16,7 +18,9 @@ pub struct Insert {
     pub insert_keyword: keyword::insert,
     pub into_keyword: keyword::into,
     pub target_table: TargetTable,
-    pub values: Values,
+    pub columns: Option<ColumnList>,
+    pub source: InsertSource,
+    pub on_conflict: Option<OnConflict>,
     pub returning: Option<Returning>,
 }
 
@@ -24,11 +28,85 @@ impl Insert {
     pub fn peek(input: ParseStream) -> bool {
         input.peek(keyword::insert)
     }
+
+    /// Checks that the number of values matches the column list, or that all rows of `values`
+    /// have the same length if there is none.
+    fn validate(&self, source_span: Span) -> syn::Result<()> {
+        let expected = match &self.columns {
+            Some(columns) => columns.columns.len(),
+            None => match &self.source {
+                InsertSource::Values(values) => values.rows[0].items.len(),
+                InsertSource::Select(_) | InsertSource::DefaultValues { .. } => return Ok(()),
+            },
+        };
+        match &self.source {
+            InsertSource::Values(values) => {
+                for row in &values.rows {
+                    if row.items.len() != expected {
+                        return Err(syn::Error::new(
+                            row.paren_token.span.join(),
+                            format!("expected {expected} values, found {}", row.items.len()),
+                        ));
+                    }
+                }
+            }
+            InsertSource::Select(command) => {
+                let found = command.fields().map_or(0, |fields| fields.0.len());
+                if found != expected {
+                    return Err(syn::Error::new(
+                        source_span,
+                        format!("expected {expected} fields, found {found}"),
+                    ));
+                }
+            }
+            InsertSource::DefaultValues {
+                default_keyword, ..
+            } => {
+                return Err(syn::Error::new(
+                    default_keyword.span,
+                    "`default values` cannot be combined with a column list",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Asserts at compile time that the insert provides a value for every column of the target
+    /// table that is not null and has no default.
+    fn required_columns_assertion(&self) -> TokenStream {
+        let table = self.target_table.table.as_path().to_call_site(1);
+        if let Some(columns) = &self.columns {
+            let columns = columns.columns.iter();
+            quote! {
+                const _: () = #table::TABLE.assert_insert_columns(
+                    &[#(#table::columns::#columns::COLUMN_NAME),*]
+                );
+            }
+        } else {
+            let count = match &self.source {
+                InsertSource::Values(values) => values.rows[0].items.len(),
+                InsertSource::Select(command) => {
+                    command.fields().map_or(0, |fields| fields.0.len())
+                }
+                InsertSource::DefaultValues { .. } => 0,
+            };
+            quote! {
+                const _: () = #table::TABLE.assert_insert_column_count(#count);
+            }
+        }
+    }
 }
 
 pub fn visit_insert<'a>(visit: &mut (impl Visit<'a> + ?Sized), insert: &'a Insert) {
     visit.visit_target_table(&insert.target_table);
-    visit.visit_values(&insert.values);
+    match &insert.source {
+        InsertSource::Values(values) => visit.visit_values(values),
+        InsertSource::Select(command) => visit.visit_command(command),
+        InsertSource::DefaultValues { .. } => {}
+    }
+    if let Some(inner) = &insert.on_conflict {
+        visit.visit_on_conflict(inner);
+    }
     if let Some(inner) = &insert.returning {
         visit.visit_returning(inner);
     }
@@ -36,31 +114,58 @@ pub fn visit_insert<'a>(visit: &mut (impl Visit<'a> + ?Sized), insert: &'a Inser
 
 impl Parse for Insert {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
-            insert_keyword: input.parse()?,
-            into_keyword: input.parse()?,
-            target_table: input.parse()?,
-            values: input.parse()?,
+        let insert_keyword = input.parse()?;
+        let into_keyword = input.parse()?;
+        let target_table = input.parse()?;
+        let columns = input
+            .peek(syn::token::Paren)
+            .then(|| input.parse())
+            .transpose()?;
+        let source_span = input.span();
+        let insert = Self {
+            insert_keyword,
+            into_keyword,
+            target_table,
+            columns,
+            source: input.parse()?,
+            on_conflict: input.call(OnConflict::parse_option)?,
             returning: input.call(Returning::parse_option)?,
-        })
+        };
+        insert.validate(source_span)?;
+        Ok(insert)
     }
 }
 
 impl ToTokens for Insert {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let target_table = &self.target_table;
-        let values = &self.values;
+        let columns = if let Some(columns) = &self.columns {
+            let table = self.target_table.table.as_path().to_call_site(1);
+            let columns = columns.columns.iter();
+            quote! {
+                ::core::option::Option::Some(::kosame::repr::part::ColumnList::new(
+                    &[#(#table::columns::#columns::COLUMN_NAME),*]
+                ))
+            }
+        } else {
+            quote! { ::core::option::Option::None }
+        };
+        let required_columns_assertion = self.required_columns_assertion();
+        let source = &self.source;
+        let on_conflict = QuoteOption::from(&self.on_conflict);
         let returning = QuoteOption::from(&self.returning);
 
         quote! {
-            ::kosame::repr::command::Insert::new(
-                #target_table,
-                {
-                    mod scope {}
-                    #values
-                },
-                #returning,
-            )
+            {
+                #required_columns_assertion
+                ::kosame::repr::command::Insert::new(
+                    #target_table,
+                    #columns,
+                    #source,
+                    #on_conflict,
+                    #returning,
+                )
+            }
         }
         .to_tokens(tokens);
     }
@@ -75,8 +180,84 @@ impl PrettyPrint for Insert {
         printer.scan_break();
         " ".pretty_print(printer);
         self.target_table.pretty_print(printer);
+        self.columns.pretty_print(printer);
         printer.scan_indent(-1);
-        self.values.pretty_print(printer);
+        self.source.pretty_print(printer);
+        self.on_conflict.pretty_print(printer);
         self.returning.pretty_print(printer);
     }
 }
+
+pub enum InsertSource {
+    Values(Values),
+    Select(Box<Command>),
+    DefaultValues {
+        default_keyword: keyword::default,
+        values_keyword: keyword::values,
+    },
+}
+
+impl Parse for InsertSource {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if Values::peek(input) {
+            Ok(Self::Values(input.parse()?))
+        } else if input.peek(keyword::default) {
+            Ok(Self::DefaultValues {
+                default_keyword: input.parse()?,
+                values_keyword: input.call(keyword::values::parse_autocomplete)?,
+            })
+        } else if input.peek(keyword::select) || input.peek(keyword::with) {
+            let span = input.span();
+            let command: Command = input.parse()?;
+            if !matches!(command.command_type, CommandType::Select(_)) {
+                return Err(syn::Error::new(span, "expected a `select` command"));
+            }
+            Ok(Self::Select(Box::new(command)))
+        } else {
+            keyword::group_insert_source::error(input);
+        }
+    }
+}
+
+impl ToTokens for InsertSource {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Values(values) => quote! {
+                ::kosame::repr::command::InsertSource::Values({
+                    mod scope {}
+                    #values
+                })
+            },
+            Self::Select(command) => quote! {
+                ::kosame::repr::command::InsertSource::Select(&#command)
+            },
+            Self::DefaultValues { .. } => quote! {
+                ::kosame::repr::command::InsertSource::DefaultValues
+            },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for InsertSource {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        match self {
+            Self::Values(values) => values.pretty_print(printer),
+            Self::Select(command) => {
+                printer.scan_break();
+                " ".pretty_print(printer);
+                command.pretty_print(printer);
+            }
+            Self::DefaultValues {
+                default_keyword,
+                values_keyword,
+            } => {
+                printer.scan_break();
+                " ".pretty_print(printer);
+                default_keyword.pretty_print(printer);
+                " ".pretty_print(printer);
+                values_keyword.pretty_print(printer);
+            }
+        }
+    }
+}
@@ -24,7 +24,7 @@ pub use select::{
 pub use update::visit_update;
 
 use crate::{
-    clause::{Fields, FromChain, With},
+    clause::{Excluded, Fields, FromChain, Window, With},
     correlations::CorrelationId,
     keyword,
     parse_option::ParseOption,
@@ -32,7 +32,7 @@ use crate::{
     pretty::{PrettyPrint, Printer},
     quote_option::QuoteOption,
     scopes::{ScopeId, Scoped},
-    visit::Visit,
+    visit::{self, Visit},
 };
 
 pub struct Command {
@@ -48,6 +48,16 @@ impl Command {
     pub fn fields(&self) -> Option<&Fields> {
         self.command_type.fields()
     }
+
+    /// Returns the scope that the fields of the command are resolved in. For selects, this is
+    /// the scope of the first select core.
+    #[must_use]
+    pub fn fields_scope_id(&self) -> ScopeId {
+        match self.select_chain() {
+            Some(select_chain) => select_chain.start.scope_id(),
+            None => self.scope_id,
+        }
+    }
 }
 
 pub fn visit_command<'a>(visit: &mut (impl Visit<'a> + ?Sized), command: &'a Command) {
@@ -81,6 +91,14 @@ impl Scoped for Command {
         }
     }
 
+    #[inline]
+    fn excluded(&self) -> Option<&Excluded> {
+        match &self.command_type {
+            CommandType::Insert(insert) => insert.on_conflict.as_ref()?.excluded(),
+            _ => None,
+        }
+    }
+
     #[inline]
     #[allow(clippy::wrong_self_convention)]
     fn from_chain(&self) -> Option<&FromChain> {
@@ -90,13 +108,28 @@ impl Scoped for Command {
 
 impl Parse for Command {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        Ok(Self {
+        let command = Self {
             scope_id: ScopeId::new(),
             attrs: input.call(Attribute::parse_outer)?,
             with: input.call(With::parse_option)?,
             command_type: input.parse()?,
             correlation_id: CorrelationId::new(),
-        })
+        };
+        // The `order by` of a select can use the windows of its select core, unless it is
+        // combined with others.
+        let window = match &command.command_type {
+            CommandType::Select(select) if select.chain.combinators.is_empty() => {
+                match &select.chain.start {
+                    SelectItem::Core(select_core) => select_core.window.as_ref(),
+                    SelectItem::Paren { .. } => None,
+                }
+            }
+            _ => None,
+        };
+        Window::check_references(window, |references| {
+            visit::visit_command_type(references, &command.command_type);
+        })?;
+        Ok(command)
     }
 }
 
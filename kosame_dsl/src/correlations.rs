@@ -5,13 +5,13 @@ use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
 
 use crate::{
-    clause::{FromItem, WithItem},
-    command::Command,
+    clause::{Excluded, FromItem, OnConflict, SelectCore, WithItem},
+    command::{Command, Insert},
     inferred_type::InferredType,
-    part::TablePath,
+    part::{TablePath, TargetTable},
     path_ext::PathExt,
     query::{self, Query, QueryNodePath},
-    scopes::Scoped,
+    scopes::ScopeId,
     visit::Visit,
 };
 
@@ -95,9 +95,13 @@ impl ToTokens for Correlations<'_> {
 
 enum Correlation<'a> {
     Table(&'a TablePath, Option<&'a WithItem>),
+    TargetTable(&'a TargetTable),
+    /// The `excluded` table of an `on conflict do update` and the target table of the insert.
+    Excluded(&'a Excluded, &'a TargetTable),
     Command(&'a Command),
     WithItem(&'a WithItem),
-    FromItem(&'a FromItem),
+    /// A from item and the scope it is in, which the arguments of a function are evaluated in.
+    FromItem(&'a FromItem, ScopeId),
     QueryNodePath {
         node: &'a query::Node,
         table_path: &'a TablePath,
@@ -109,9 +113,11 @@ impl<'a> Correlation<'a> {
     fn id(&self) -> CorrelationId {
         match self {
             Self::Table(inner, _) => inner.correlation_id,
+            Self::TargetTable(inner) => inner.correlation_id,
+            Self::Excluded(inner, _) => inner.correlation_id,
             Self::Command(inner) => inner.correlation_id,
             Self::WithItem(inner) => inner.correlation_id,
-            Self::FromItem(inner) => inner.correlation_id(),
+            Self::FromItem(inner, _) => inner.correlation_id(),
             Self::QueryNodePath { node, .. } => node.correlation_id,
         }
     }
@@ -121,11 +127,13 @@ impl<'a> Correlation<'a> {
             Self::Table(_, with_item) => {
                 with_item.as_ref().map(|with_item| with_item.correlation_id)
             }
+            Self::TargetTable(inner) | Self::Excluded(_, inner) => Some(inner.table.correlation_id),
             Self::Command(_) => None,
             Self::WithItem(inner) => Some(inner.command.correlation_id),
-            Self::FromItem(inner) => match inner {
+            Self::FromItem(inner, _) => match inner {
                 FromItem::Table { table_path, .. } => Some(table_path.correlation_id),
                 FromItem::Subquery { command, .. } => Some(command.correlation_id),
+                FromItem::Function { .. } => None,
             },
             Self::QueryNodePath { .. } => None,
         }
@@ -141,22 +149,25 @@ impl<'a> Correlation<'a> {
                 }),
                 None => Some(InferredType::TableColumn { table_path, column }),
             },
+            Self::TargetTable(target_table) | Self::Excluded(_, target_table) => {
+                Some(InferredType::TableColumn {
+                    table_path: &target_table.table,
+                    column,
+                })
+            }
             Self::Command(command) => {
                 let field = command
                     .fields()?
                     .iter()
                     .find(|field| field.infer_name() == Some(column))?;
-                match command.select_chain() {
-                    Some(select_chain) => field.infer_type(select_chain.start.scope_id()),
-                    None => field.infer_type(command.scope_id),
-                }
+                field.infer_type(command.fields_scope_id())
             }
             Self::WithItem(with_item) => Some(InferredType::Correlation {
                 correlation_id: with_item.command.correlation_id,
                 column,
                 nullable: false,
             }),
-            Self::FromItem(from_item) => match from_item {
+            Self::FromItem(from_item, scope_id) => match from_item {
                 FromItem::Table { table_path, .. } => Some(InferredType::Correlation {
                     correlation_id: table_path.correlation_id,
                     column,
@@ -167,6 +178,7 @@ impl<'a> Correlation<'a> {
                     column,
                     nullable: false,
                 }),
+                FromItem::Function { call, .. } => call.infer_type(*scope_id),
             },
             Self::QueryNodePath { table_path, .. } => {
                 Some(InferredType::TableColumn { table_path, column })
@@ -176,6 +188,7 @@ impl<'a> Correlation<'a> {
 }
 
 impl ToTokens for Correlation<'_> {
+    #[allow(clippy::too_many_lines)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let id = &self.id();
 
@@ -192,6 +205,30 @@ impl ToTokens for Correlation<'_> {
                     pub use #table_path as #id;
                 }
             }
+            Self::TargetTable(target_table) => {
+                let source_id = target_table.table.correlation_id;
+                if let Some(alias) = &target_table.alias {
+                    let alias = alias.ident.to_string();
+                    quote! {
+                        pub mod #id {
+                            pub const TABLE_NAME: &str = #alias;
+                            pub use super::#source_id::columns;
+                        }
+                    }
+                } else {
+                    quote! { pub use #source_id as #id; }
+                }
+            }
+            Self::Excluded(excluded, target_table) => {
+                let source_id = target_table.table.correlation_id;
+                let name = excluded.name.to_string();
+                quote! {
+                    pub mod #id {
+                        pub const TABLE_NAME: &str = #name;
+                        pub use super::#source_id::columns;
+                    }
+                }
+            }
             Self::Command(command) => {
                 if let Some(fields) = command.fields() {
                     let fields = fields.columns();
@@ -214,18 +251,17 @@ impl ToTokens for Correlation<'_> {
             Self::WithItem(with_item) => {
                 if with_item.alias.columns.is_some() {
                     unimplemented!();
-                } else {
-                    let source_id = with_item.command.correlation_id;
-                    let alias = with_item.alias.name.to_string();
-                    quote! {
-                        pub mod #id {
-                            pub const TABLE_NAME: &str = #alias;
-                            pub use super::#source_id::columns;
-                        }
+                }
+                let source_id = with_item.command.correlation_id;
+                let alias = with_item.alias.name.to_string();
+                quote! {
+                    pub mod #id {
+                        pub const TABLE_NAME: &str = #alias;
+                        pub use super::#source_id::columns;
                     }
                 }
             }
-            Self::FromItem(from_item) => match from_item {
+            Self::FromItem(from_item, _) => match from_item {
                 FromItem::Table {
                     table_path, alias, ..
                 } => {
@@ -256,15 +292,39 @@ impl ToTokens for Correlation<'_> {
                         quote! { pub use #source_id as #id; }
                     }
                 }
+                FromItem::Function { .. } => {
+                    let name = from_item.name().expect("function from items have a name");
+                    let name_string = name.to_string();
+                    quote! {
+                        pub mod #id {
+                            pub const TABLE_NAME: &str = #name_string;
+                            pub mod columns {
+                                pub mod #name {
+                                    pub const COLUMN_NAME: &str = #name_string;
+                                }
+                            }
+                        }
+                    }
+                }
             },
             Self::QueryNodePath {
                 table_path,
                 node_path,
                 ..
             } => {
-                let table_path = node_path.resolve(&table_path.as_path().to_call_site(2));
-                quote! {
-                    pub use #table_path as #id;
+                // Nested nodes are aliased by their relation name in the generated SQL.
+                if let Some(name) = node_path.segments.last() {
+                    let table_path = node_path.resolve(&table_path.as_path().to_call_site(3));
+                    let alias = name.to_string();
+                    quote! {
+                        pub mod #id {
+                            pub const TABLE_NAME: &str = #alias;
+                            pub use #table_path::columns;
+                        }
+                    }
+                } else {
+                    let table_path = table_path.as_path().to_call_site(2);
+                    quote! { pub use #table_path as #id; }
                 }
             }
         }
@@ -272,76 +332,96 @@ impl ToTokens for Correlation<'_> {
     }
 }
 
-impl<'a> From<&'a Command> for Correlations<'a> {
-    fn from(value: &'a Command) -> Self {
-        #[derive(Default)]
-        struct Visitor<'a> {
-            correlations: Vec<Correlation<'a>>,
-            inherited_with_items: Vec<&'a WithItem>,
-        }
+/// Collects the correlations of a command and of all commands nested in it, be it through
+/// `with`, subqueries in `from` or subquery expressions.
+#[derive(Default)]
+struct CommandVisitor<'a> {
+    correlations: Vec<Correlation<'a>>,
+    inherited_with_items: Vec<&'a WithItem>,
+    scope_ids: Vec<ScopeId>,
+}
 
-        impl<'a> Visit<'a> for Visitor<'a> {
-            fn visit_with_item(&mut self, with_item: &'a WithItem) {
-                self.correlations.push(Correlation::WithItem(with_item));
-                self.visit_command(&with_item.command);
-                self.inherited_with_items.push(with_item);
-            }
+impl<'a> Visit<'a> for CommandVisitor<'a> {
+    fn visit_with_item(&mut self, with_item: &'a WithItem) {
+        self.correlations.push(Correlation::WithItem(with_item));
+        self.visit_command(&with_item.command);
+        self.inherited_with_items.push(with_item);
+    }
+
+    fn visit_target_table(&mut self, target_table: &'a TargetTable) {
+        self.correlations
+            .push(Correlation::Table(&target_table.table, None));
+        self.correlations
+            .push(Correlation::TargetTable(target_table));
+    }
+
+    fn visit_insert(&mut self, insert: &'a Insert) {
+        if let Some(excluded) = insert.on_conflict.as_ref().and_then(OnConflict::excluded) {
+            self.correlations
+                .push(Correlation::Excluded(excluded, &insert.target_table));
+        }
+        crate::visit::visit_insert(self, insert);
+    }
 
-            fn visit_target_table(&mut self, target_table: &'a crate::part::TargetTable) {
+    fn visit_from_item(&mut self, from_item: &'a FromItem) {
+        let scope_id = *self
+            .scope_ids
+            .last()
+            .expect("from items must be in a command or select core");
+        self.correlations
+            .push(Correlation::FromItem(from_item, scope_id));
+
+        match from_item {
+            FromItem::Table { table_path, .. } => {
+                let with_item = match from_item {
+                    FromItem::Table { table_path, .. } => match table_path.get_ident() {
+                        Some(table) => self
+                            .inherited_with_items
+                            .iter()
+                            .rev()
+                            .find(|with_item| with_item.alias.name == *table),
+                        None => None,
+                    },
+                    FromItem::Subquery { .. } | FromItem::Function { .. } => None,
+                };
                 self.correlations
-                    .push(Correlation::Table(&target_table.table, None));
+                    .push(Correlation::Table(table_path, with_item.copied()));
             }
-
-            fn visit_from_item(&mut self, from_item: &'a FromItem) {
-                self.correlations.push(Correlation::FromItem(from_item));
-
-                match from_item {
-                    FromItem::Table { table_path, .. } => {
-                        let with_item = match from_item {
-                            FromItem::Table { table_path, .. } => match table_path.get_ident() {
-                                Some(table) => self
-                                    .inherited_with_items
-                                    .iter()
-                                    .rev()
-                                    .find(|with_item| with_item.alias.name == *table),
-                                None => None,
-                            },
-                            FromItem::Subquery { .. } => None,
-                        };
-                        self.correlations
-                            .push(Correlation::Table(table_path, with_item.copied()));
-                    }
-                    FromItem::Subquery { command, .. } => {
-                        self.visit_command(command);
-                    }
-                }
+            FromItem::Subquery { command, .. } => {
+                self.visit_command(command);
+            }
+            FromItem::Function { call, .. } => {
+                self.visit_call(call);
             }
+        }
+    }
 
-            fn visit_command(&mut self, command: &'a Command) {
-                self.correlations.push(Correlation::Command(command));
-                let with_items_truncate = self.inherited_with_items.len();
+    fn visit_select_core(&mut self, select_core: &'a SelectCore) {
+        self.scope_ids.push(select_core.scope_id);
+        crate::visit::visit_select_core(self, select_core);
+        self.scope_ids.pop();
+    }
 
-                if let Some(with) = &command.with {
-                    self.visit_with(with);
-                }
+    fn visit_command(&mut self, command: &'a Command) {
+        self.correlations.push(Correlation::Command(command));
+        let with_items_truncate = self.inherited_with_items.len();
+        self.scope_ids.push(command.scope_id);
 
-                if let Some(target_table) = command.target_table() {
-                    self.visit_target_table(target_table);
-                }
+        // Walks the with clause, target table, from items and all expressions, including the
+        // subqueries in them.
+        crate::visit::visit_command(self, command);
 
-                if let Some(select_chain) = command.select_chain() {
-                    self.visit_select_chain(select_chain);
-                } else if let Some(from_chain) = command.from_chain() {
-                    // We would risk processing the from chain twice if we collected both the
-                    // select chain and the from chain of a select.
-                    self.visit_from_chain(from_chain);
-                }
+        self.scope_ids.pop();
+        self.inherited_with_items.truncate(with_items_truncate);
+    }
 
-                self.inherited_with_items.truncate(with_items_truncate);
-            }
-        }
+    // Query nodes get their correlations from the query visitor.
+    fn visit_node(&mut self, _node: &'a query::Node) {}
+}
 
-        let mut visitor = Visitor::default();
+impl<'a> From<&'a Command> for Correlations<'a> {
+    fn from(value: &'a Command) -> Self {
+        let mut visitor = CommandVisitor::default();
         visitor.visit_command(value);
         Correlations {
             correlations: visitor.correlations,
@@ -372,6 +452,10 @@ impl<'a> From<&'a Query> for Correlations<'a> {
                     table_path: &self.query.table,
                     node_path: self.node_path.clone(),
                 });
+
+                let mut commands = CommandVisitor::default();
+                query::visit_node(&mut commands, node);
+                self.correlations.append(&mut commands.correlations);
             }
         }
 
@@ -1,7 +1,8 @@
 use proc_macro_error::abort;
-use quote::{ToTokens, quote};
+use proc_macro2::TokenStream;
+use quote::quote;
 use syn::{
-    Ident,
+    Ident, bracketed,
     parse::{Parse, ParseStream},
 };
 
@@ -10,19 +11,103 @@ use crate::pretty::{PrettyPrint, Printer};
 #[derive(Clone)]
 pub struct DataType {
     pub name: Ident,
+    /// The `[]` of an array type like `text[]`, once for each dimension.
+    pub array_brackets: Vec<syn::token::Bracket>,
 }
 
 impl Parse for DataType {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name = input.parse()?;
+        let mut array_brackets = Vec::new();
+        while input.peek(syn::token::Bracket) {
+            let content;
+            array_brackets.push(bracketed!(content in input));
+            if !content.is_empty() {
+                return Err(content.error("array sizes are not supported, use `[]` instead"));
+            }
+        }
         Ok(Self {
-            name: input.parse()?,
+            name,
+            array_brackets,
         })
     }
 }
 
-impl ToTokens for DataType {
-    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        match self.name.to_string().as_str() {
+impl DataType {
+    /// The name of the type in SQL, e.g. `text[]`.
+    #[must_use]
+    pub fn sql_name(&self) -> String {
+        let mut sql_name = self.name.to_string();
+        for _ in &self.array_brackets {
+            sql_name.push_str("[]");
+        }
+        sql_name
+    }
+
+    /// Returns the Rust type that values of this database type are read into, if it is known.
+    /// Arrays become a `Vec` of nullable elements, since the database does not enforce `not null`
+    /// on them. Multi-dimensional arrays cannot be read into nested `Vec`s and have no known type.
+    #[must_use]
+    pub fn rust_type(&self) -> Option<TokenStream> {
+        self.rust_type_with_elements(false)
+    }
+
+    /// Like [`DataType::rust_type`], but the elements of an array are not wrapped in an `Option`
+    /// if they are declared `not null`.
+    #[must_use]
+    pub fn rust_type_with_elements(&self, elements_not_null: bool) -> Option<TokenStream> {
+        if !self.is_array() {
+            return self.scalar_rust_type();
+        }
+        let element = self.element_rust_type()?;
+        Some(if elements_not_null {
+            quote! { ::std::vec::Vec<#element> }
+        } else {
+            quote! { ::std::vec::Vec<::std::option::Option<#element>> }
+        })
+    }
+
+    /// Returns the Rust type of the elements of a one-dimensional array type, without their
+    /// nullability, e.g. `i32` for `int[]`.
+    #[must_use]
+    pub fn element_rust_type(&self) -> Option<TokenStream> {
+        if self.is_multi_dimensional() {
+            return None;
+        }
+        self.scalar_rust_type()
+    }
+
+    /// Returns the Rust type of a column of this type, or aborts if it is not known.
+    #[must_use]
+    pub fn column_rust_type(&self, elements_not_null: bool) -> TokenStream {
+        if self.is_multi_dimensional() {
+            abort!(
+                self.name.span(),
+                "multi-dimensional arrays cannot be read into a `Vec`, requires type override"
+            );
+        }
+        let Some(rust_type) = self.rust_type_with_elements(elements_not_null) else {
+            abort!(
+                self.name.span(),
+                "cannot determine rust type for unrecognized database type {}, requires type override",
+                self.sql_name(),
+            );
+        };
+        rust_type
+    }
+
+    #[must_use]
+    pub fn is_array(&self) -> bool {
+        !self.array_brackets.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_multi_dimensional(&self) -> bool {
+        self.array_brackets.len() > 1
+    }
+
+    fn scalar_rust_type(&self) -> Option<TokenStream> {
+        Some(match self.name.to_string().as_str() {
             // Built-in / Standard library types
             "bool" => quote! { bool },
             "char" => quote! { i8 },
@@ -34,28 +119,33 @@ impl ToTokens for DataType {
             "double precision" => quote! { f64 },
             "varchar" | "text" | "citext" | "name" | "unknown" => quote! { ::std::string::String },
             "bytea" => quote! { ::std::vec::Vec<u8> },
-            "hstore" => quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> },
-            "timestamp" | "timestamptz" | "timestamp with time zone" => quote! { ::std::time::SystemTime },
+            "hstore" => {
+                quote! { ::std::collections::HashMap<::std::string::String, ::std::option::Option<::std::string::String>> }
+            }
+            "timestamp" | "timestamptz" | "timestamp with time zone" => {
+                quote! { ::std::time::SystemTime }
+            }
+            "date" => quote! { ::kosame::date_time::Date },
+            "time" => quote! { ::kosame::date_time::Time },
+            "interval" => quote! { ::kosame::date_time::Interval },
             "inet" => quote! { ::std::net::IpAddr },
+            "tsvector" => quote! { ::kosame::text_search::TsVector },
+            "tsquery" => quote! { ::kosame::text_search::TsQuery },
 
             // Crates
             "uuid" => quote! { ::uuid::Uuid },
             "json" | "jsonb" => quote! { ::serde_json::Value },
             "numeric" => quote! { ::rust_decimal::Decimal },
-            _ => {
-                abort!(
-                    self.name.span(),
-                    "cannot determine rust type for unrecognized database type {}, requires type override",
-                    self.name,
-                );
-            }
-        }
-        .to_tokens(tokens);
+            _ => return None,
+        })
     }
 }
 
 impl PrettyPrint for DataType {
     fn pretty_print(&self, printer: &mut Printer<'_>) {
         self.name.pretty_print(printer);
+        for _ in &self.array_brackets {
+            "[]".pretty_print(printer);
+        }
     }
 }
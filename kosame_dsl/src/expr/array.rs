@@ -0,0 +1,165 @@
+use crate::{
+    command::Command,
+    inferred_type::InferredType,
+    keyword,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Expr, Subquery, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, bracketed, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// An array constructor like `array[1, 2, 3]` or `array(select id from posts)`.
+pub struct Array {
+    pub array_keyword: keyword::array,
+    pub elements: ArrayElements,
+}
+
+pub enum ArrayElements {
+    List {
+        bracket_token: syn::token::Bracket,
+        elements: Punctuated<Expr, Token![,]>,
+    },
+    Subquery {
+        paren_token: syn::token::Paren,
+        command: Box<Command>,
+    },
+}
+
+impl Array {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::array)
+            && (input.peek2(syn::token::Bracket) || input.peek2(syn::token::Paren))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        match &self.elements {
+            ArrayElements::List { elements, .. } => {
+                if elements.is_empty() {
+                    return None;
+                }
+                Some(InferredType::Array(
+                    elements
+                        .iter()
+                        .map(|element| element.infer_type(scope_id))
+                        .collect::<Option<_>>()?,
+                ))
+            }
+            ArrayElements::Subquery { command, .. } => {
+                let mut fields = command.fields()?.iter();
+                let field = fields.next()?;
+                if fields.next().is_some() {
+                    return None;
+                }
+                Some(InferredType::Array(vec![
+                    field.infer_type(command.fields_scope_id())?,
+                ]))
+            }
+        }
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        let delim_span = match &self.elements {
+            ArrayElements::List { bracket_token, .. } => bracket_token.span.span(),
+            ArrayElements::Subquery { paren_token, .. } => paren_token.span.span(),
+        };
+        self.array_keyword
+            .span
+            .join(delim_span)
+            .unwrap_or(self.array_keyword.span)
+    }
+}
+
+pub fn visit_array<'a>(visit: &mut (impl Visit<'a> + ?Sized), array: &'a Array) {
+    match &array.elements {
+        ArrayElements::List { elements, .. } => {
+            for element in elements {
+                visit.visit_expr(element);
+            }
+        }
+        ArrayElements::Subquery { command, .. } => visit.visit_command(command),
+    }
+}
+
+impl Parse for Array {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let array_keyword = input.parse()?;
+        let content;
+        let elements = if input.peek(syn::token::Bracket) {
+            ArrayElements::List {
+                bracket_token: bracketed!(content in input),
+                elements: content.parse_terminated(Expr::parse, Token![,])?,
+            }
+        } else if Subquery::peek(input) {
+            ArrayElements::Subquery {
+                paren_token: parenthesized!(content in input),
+                command: content.parse()?,
+            }
+        } else {
+            return Err(syn::Error::new(
+                input.span(),
+                "expected `[...]` or a parenthesized subquery",
+            ));
+        };
+        Ok(Self {
+            array_keyword,
+            elements,
+        })
+    }
+}
+
+impl ToTokens for Array {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.elements {
+            ArrayElements::List { elements, .. } => {
+                let elements = elements.iter();
+                quote! {
+                    ::kosame::repr::expr::Array::List(&[#(#elements),*])
+                }
+            }
+            ArrayElements::Subquery { command, .. } => quote! {
+                ::kosame::repr::expr::Array::Subquery(&#command)
+            },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Array {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.array_keyword.pretty_print(printer);
+        match &self.elements {
+            ArrayElements::List {
+                bracket_token,
+                elements,
+            } => {
+                bracket_token.pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                    elements.pretty_print(printer);
+                });
+            }
+            ArrayElements::Subquery {
+                paren_token,
+                command,
+            } => {
+                paren_token.pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                    command.pretty_print(printer);
+                });
+            }
+        }
+    }
+}
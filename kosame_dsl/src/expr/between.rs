@@ -0,0 +1,108 @@
+use crate::{
+    inferred_type::InferredType,
+    keyword,
+    pretty::{PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, parse::ParseStream, parse_quote};
+
+/// `lhs [not] between low and high`
+pub struct Between {
+    pub lhs: Box<Expr>,
+    pub not_keyword: Option<keyword::not>,
+    pub between_keyword: keyword::between,
+    pub low: Box<Expr>,
+    pub and_keyword: keyword::and,
+    pub high: Box<Expr>,
+}
+
+impl Between {
+    // Taken from https://www.postgresql.org/docs/18/sql-syntax-lexical.html#SQL-PRECEDENCE
+    pub const PRECEDENCE: u32 = 6;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::between) || (input.peek(keyword::not) && input.peek2(keyword::between))
+    }
+
+    /// Parses the remainder of the expression after its left-hand side. The bounds bind tighter
+    /// than `between` itself, so that the `and` separating them is not taken for a logical `and`.
+    pub fn parse_postfix(lhs: Expr, input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            lhs: Box::new(lhs),
+            not_keyword: input.parse()?,
+            between_keyword: input.call(keyword::between::parse_autocomplete)?,
+            low: Box::new(Expr::parse_expr(input, Self::PRECEDENCE + 1)?),
+            and_keyword: input.parse()?,
+            high: Box::new(Expr::parse_expr(input, Self::PRECEDENCE + 1)?),
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        InferredType::derived(
+            parse_quote!(bool),
+            [
+                self.lhs.infer_type(scope_id),
+                self.low.infer_type(scope_id),
+                self.high.infer_type(scope_id),
+            ],
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.lhs
+            .span()
+            .join(self.high.span())
+            .unwrap_or(self.lhs.span())
+    }
+}
+
+pub fn visit_between<'a>(visit: &mut (impl Visit<'a> + ?Sized), between: &'a Between) {
+    visit.visit_expr(&between.lhs);
+    visit.visit_expr(&between.low);
+    visit.visit_expr(&between.high);
+}
+
+impl ToTokens for Between {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lhs = &self.lhs;
+        let not = self.not_keyword.is_some();
+        let low = &self.low;
+        let high = &self.high;
+        quote! {
+            ::kosame::repr::expr::Between::new(&#lhs, #not, &#low, &#high)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Between {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.lhs.pretty_print(printer);
+        printer.scan_break();
+        " ".pretty_print(printer);
+        if let Some(not_keyword) = &self.not_keyword {
+            not_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+        }
+        self.between_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.low.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.and_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.high.pretty_print(printer);
+    }
+}
@@ -11,6 +11,7 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident, Token,
     parse::{Parse, ParseStream},
+    parse_quote,
 };
 
 pub struct Binary {
@@ -36,10 +37,71 @@ impl Binary {
         None
     }
 
-    #[inline]
     #[must_use]
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        match self.op {
+            BinOp::Multiply(_)
+            | BinOp::Divide(_)
+            | BinOp::Modulo(_)
+            | BinOp::Add(_)
+            | BinOp::Subtract(_)
+            | BinOp::BitAnd(_)
+            | BinOp::BitOr(_)
+            | BinOp::BitXor(_)
+            | BinOp::ShiftLeft(_)
+            | BinOp::ShiftRight(_) => Some(InferredType::Arithmetic {
+                lhs: Box::new(self.lhs.infer_type(scope_id)?),
+                rhs: Box::new(self.rhs.infer_type(scope_id)?),
+            }),
+            // `^` is only defined for `double precision` and `numeric`.
+            BinOp::Power(_) => Some(InferredType::Projection {
+                trait_name: "Power",
+                operand: Box::new(InferredType::Arithmetic {
+                    lhs: Box::new(self.lhs.infer_type(scope_id)?),
+                    rhs: Box::new(self.rhs.infer_type(scope_id)?),
+                }),
+            }),
+            // Missing keys and paths yield null.
+            BinOp::JsonGet(_) | BinOp::JsonPath(..) => Some(InferredType::Nullable(Box::new(
+                InferredType::derived(parse_quote!(::serde_json::Value), [])?,
+            ))),
+            BinOp::JsonGetText(..) | BinOp::JsonPathText(..) => {
+                Some(InferredType::Nullable(Box::new(InferredType::derived(
+                    parse_quote!(::std::string::String),
+                    [],
+                )?)))
+            }
+            // `is` never yields null, even for null operands.
+            BinOp::Is(_) | BinOp::IsNot(..) | BinOp::IsDistinctFrom(..) => {
+                InferredType::derived(parse_quote!(bool), [])
+            }
+            BinOp::Concat(_) => Some(InferredType::Concat {
+                lhs: Box::new(self.lhs.infer_type(scope_id)?),
+                rhs: Box::new(self.rhs.infer_type(scope_id)?),
+            }),
+            BinOp::Contains(..)
+            | BinOp::ContainedBy(..)
+            | BinOp::Match(..)
+            | BinOp::Overlaps(_)
+            | BinOp::HasKey(_)
+            | BinOp::HasAnyKey(..)
+            | BinOp::HasAllKeys(..)
+            | BinOp::Like(_)
+            | BinOp::NotLike(..)
+            | BinOp::Ilike(_)
+            | BinOp::NotIlike(..)
+            | BinOp::Eq(_)
+            | BinOp::Uneq(..)
+            | BinOp::LessThan(_)
+            | BinOp::GreaterThan(_)
+            | BinOp::LessThanOrEq(..)
+            | BinOp::GreaterThanOrEq(..)
+            | BinOp::And(_)
+            | BinOp::Or(_) => InferredType::derived(
+                parse_quote!(bool),
+                [self.lhs.infer_type(scope_id), self.rhs.infer_type(scope_id)],
+            ),
+        }
     }
 
     #[inline]
@@ -87,6 +149,8 @@ pub enum Associativity {
 
 #[allow(unused)]
 pub enum BinOp {
+    // exponentiation
+    Power(Token![^]),
     // multiplication, division, modulo
     Multiply(Token![*]),
     Divide(Token![/]),
@@ -94,6 +158,34 @@ pub enum BinOp {
     // addition, subtraction
     Add(Token![+]),
     Subtract(Token![-]),
+    // json access
+    JsonGet(Token![->]),
+    JsonGetText(Token![->], Token![>]),
+    JsonPath(Token![#], Token![>]),
+    JsonPathText(Token![#], Token![>], Token![>]),
+    // containment
+    Contains(Token![@], Token![>]),
+    ContainedBy(Token![<], Token![@]),
+    Overlaps(Token![&&]),
+    // text search
+    Match(Token![@], Token![@]),
+    // json key existence
+    HasKey(Token![?]),
+    HasAnyKey(Token![?], Token![|]),
+    HasAllKeys(Token![?], Token![&]),
+    // concatenation
+    Concat(Token![||]),
+    // bitwise operators
+    BitAnd(Token![&]),
+    BitOr(Token![|]),
+    BitXor(Token![#]),
+    ShiftLeft(Token![<<]),
+    ShiftRight(Token![>>]),
+    // pattern matching
+    Like(keyword::like),
+    NotLike(keyword::not, keyword::like),
+    Ilike(keyword::ilike),
+    NotIlike(keyword::not, keyword::ilike),
     // comparison operators
     Eq(Token![=]),
     Uneq(Token![<], Token![>]),
@@ -120,16 +212,57 @@ impl BinOp {
         Associativity::Left
     }
 
+    /// Whether the operator compares its operands, in which case the right-hand side may be an
+    /// array comparison like `any(:ids)`.
+    #[must_use]
+    pub fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Like(_)
+                | Self::NotLike(..)
+                | Self::Ilike(_)
+                | Self::NotIlike(..)
+                | Self::Eq(_)
+                | Self::Uneq(..)
+                | Self::LessThan(_)
+                | Self::GreaterThan(_)
+                | Self::LessThanOrEq(..)
+                | Self::GreaterThanOrEq(..)
+        )
+    }
+
     #[must_use]
     pub fn precedence(&self) -> u32 {
         // Taken from https://www.postgresql.org/docs/18/sql-syntax-lexical.html#SQL-PRECEDENCE
         #[allow(clippy::match_same_arms)]
         match self {
+            Self::Power(_) => 10,
             Self::Multiply(_) => 9,
             Self::Divide(_) => 9,
             Self::Modulo(_) => 9,
             Self::Add(_) => 8,
             Self::Subtract(_) => 8,
+            Self::JsonGet(_) => 7,
+            Self::JsonGetText(..) => 7,
+            Self::JsonPath(..) => 7,
+            Self::JsonPathText(..) => 7,
+            Self::Contains(..) => 7,
+            Self::ContainedBy(..) => 7,
+            Self::HasKey(_) => 7,
+            Self::HasAnyKey(..) => 7,
+            Self::HasAllKeys(..) => 7,
+            Self::Overlaps(_) => 7,
+            Self::Match(..) => 7,
+            Self::Concat(_) => 7,
+            Self::BitAnd(_) => 7,
+            Self::BitOr(_) => 7,
+            Self::BitXor(_) => 7,
+            Self::ShiftLeft(_) => 7,
+            Self::ShiftRight(_) => 7,
+            Self::Like(_) => 6,
+            Self::NotLike(..) => 6,
+            Self::Ilike(_) => 6,
+            Self::NotIlike(..) => 6,
             Self::Eq(_) => 5,
             Self::Uneq(..) => 5,
             Self::LessThan(_) => 5,
@@ -146,8 +279,59 @@ impl BinOp {
 }
 
 impl Parse for BinOp {
+    #[allow(clippy::too_many_lines)]
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let lookahead = input.lookahead1();
+        // `->` must be checked before `-`.
+        if lookahead.peek(Token![->]) {
+            // `->` spans two punctuation tokens.
+            if input.peek3(Token![>]) {
+                return Ok(Self::JsonGetText(input.parse()?, input.parse()?));
+            }
+            return Ok(Self::JsonGet(input.parse()?));
+        } else if input.peek(Token![#]) && input.peek2(Token![>]) {
+            if input.peek3(Token![>]) {
+                return Ok(Self::JsonPathText(
+                    input.parse()?,
+                    input.parse()?,
+                    input.parse()?,
+                ));
+            }
+            return Ok(Self::JsonPath(input.parse()?, input.parse()?));
+        } else if input.peek(Token![@]) && input.peek2(Token![@]) {
+            return Ok(Self::Match(input.parse()?, input.parse()?));
+        } else if input.peek(Token![@]) && input.peek2(Token![>]) {
+            return Ok(Self::Contains(input.parse()?, input.parse()?));
+        } else if input.peek(Token![<]) && input.peek2(Token![@]) {
+            return Ok(Self::ContainedBy(input.parse()?, input.parse()?));
+        } else if lookahead.peek(Token![?]) {
+            if input.peek2(Token![|]) && !input.peek2(Token![||]) {
+                return Ok(Self::HasAnyKey(input.parse()?, input.parse()?));
+            } else if input.peek2(Token![&]) && !input.peek2(Token![&&]) {
+                return Ok(Self::HasAllKeys(input.parse()?, input.parse()?));
+            }
+            return Ok(Self::HasKey(input.parse()?));
+        } else if lookahead.peek(Token![&&]) {
+            return Ok(Self::Overlaps(input.parse()?));
+        } else if lookahead.peek(Token![||]) {
+            return Ok(Self::Concat(input.parse()?));
+        }
+
+        // The bitwise operators must be checked after the operators they are a prefix of.
+        if lookahead.peek(Token![&]) {
+            return Ok(Self::BitAnd(input.parse()?));
+        } else if lookahead.peek(Token![|]) {
+            return Ok(Self::BitOr(input.parse()?));
+        } else if lookahead.peek(Token![#]) {
+            return Ok(Self::BitXor(input.parse()?));
+        } else if lookahead.peek(Token![<<]) {
+            return Ok(Self::ShiftLeft(input.parse()?));
+        } else if lookahead.peek(Token![>>]) {
+            return Ok(Self::ShiftRight(input.parse()?));
+        } else if lookahead.peek(Token![^]) {
+            return Ok(Self::Power(input.parse()?));
+        }
+
         if lookahead.peek(Token![+]) {
             return Ok(Self::Add(input.parse()?));
         } else if lookahead.peek(Token![-]) {
@@ -164,6 +348,18 @@ impl Parse for BinOp {
             return Ok(Self::Or(input.parse()?));
         }
 
+        if lookahead.peek(keyword::like) {
+            return Ok(Self::Like(input.parse()?));
+        } else if lookahead.peek(keyword::ilike) {
+            return Ok(Self::Ilike(input.parse()?));
+        } else if input.peek(keyword::not) {
+            if input.peek2(keyword::like) {
+                return Ok(Self::NotLike(input.parse()?, input.parse()?));
+            } else if input.peek2(keyword::ilike) {
+                return Ok(Self::NotIlike(input.parse()?, input.parse()?));
+            }
+        }
+
         if lookahead.peek(keyword::is) {
             if input.peek2(keyword::not) {
                 return Ok(Self::IsNot(input.parse()?, input.parse()?));
@@ -209,11 +405,33 @@ impl ToTokens for BinOp {
         }
 
         branches!(
+            Power
             Multiply
             Divide
             Modulo
             Add
             Subtract
+            JsonGet
+            JsonGetText
+            JsonPath
+            JsonPathText
+            Contains
+            ContainedBy
+            HasKey
+            HasAnyKey
+            HasAllKeys
+            Overlaps
+            Match
+            Concat
+            BitAnd
+            BitOr
+            BitXor
+            ShiftLeft
+            ShiftRight
+            Like
+            NotLike
+            Ilike
+            NotIlike
             Eq
             Uneq
             LessThan
@@ -232,11 +450,66 @@ impl ToTokens for BinOp {
 impl PrettyPrint for BinOp {
     fn pretty_print(&self, printer: &mut Printer) {
         match self {
+            Self::Power(inner) => inner.pretty_print(printer),
             Self::Multiply(inner) => inner.pretty_print(printer),
             Self::Divide(inner) => inner.pretty_print(printer),
             Self::Modulo(inner) => inner.pretty_print(printer),
             Self::Add(inner) => inner.pretty_print(printer),
             Self::Subtract(inner) => inner.pretty_print(printer),
+            Self::JsonGet(inner) => inner.pretty_print(printer),
+            Self::JsonGetText(arrow, gt) => {
+                arrow.pretty_print(printer);
+                gt.pretty_print(printer);
+            }
+            Self::JsonPath(pound, gt) => {
+                pound.pretty_print(printer);
+                gt.pretty_print(printer);
+            }
+            Self::JsonPathText(pound, gt1, gt2) => {
+                pound.pretty_print(printer);
+                gt1.pretty_print(printer);
+                gt2.pretty_print(printer);
+            }
+            Self::Contains(at, gt) => {
+                at.pretty_print(printer);
+                gt.pretty_print(printer);
+            }
+            Self::ContainedBy(lt, at) => {
+                lt.pretty_print(printer);
+                at.pretty_print(printer);
+            }
+            Self::HasKey(inner) => inner.pretty_print(printer),
+            Self::HasAnyKey(question, pipe) => {
+                question.pretty_print(printer);
+                pipe.pretty_print(printer);
+            }
+            Self::HasAllKeys(question, and) => {
+                question.pretty_print(printer);
+                and.pretty_print(printer);
+            }
+            Self::Overlaps(inner) => inner.pretty_print(printer),
+            Self::Match(at1, at2) => {
+                at1.pretty_print(printer);
+                at2.pretty_print(printer);
+            }
+            Self::Concat(inner) => inner.pretty_print(printer),
+            Self::BitAnd(inner) => inner.pretty_print(printer),
+            Self::BitOr(inner) => inner.pretty_print(printer),
+            Self::BitXor(inner) => inner.pretty_print(printer),
+            Self::ShiftLeft(inner) => inner.pretty_print(printer),
+            Self::ShiftRight(inner) => inner.pretty_print(printer),
+            Self::Like(inner) => inner.pretty_print(printer),
+            Self::NotLike(not, like) => {
+                not.pretty_print(printer);
+                " ".pretty_print(printer);
+                like.pretty_print(printer);
+            }
+            Self::Ilike(inner) => inner.pretty_print(printer),
+            Self::NotIlike(not, ilike) => {
+                not.pretty_print(printer);
+                " ".pretty_print(printer);
+                ilike.pretty_print(printer);
+            }
             Self::Eq(inner) => inner.pretty_print(printer),
             Self::Uneq(lt, gt) => {
                 lt.pretty_print(printer);
@@ -1,5 +1,8 @@
 use crate::{
     inferred_type::InferredType,
+    parse_option::ParseOption,
+    part::TypeOverride,
+    path_ext::PathExt,
     pretty::{PrettyPrint, Printer},
     scopes::ScopeId,
 };
@@ -8,13 +11,19 @@ use super::Visit;
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Ident, Token,
+    Ident, Path, Token,
     parse::{Parse, ParseStream},
 };
 
 pub struct BindParam {
     pub colon_token: Token![:],
     pub name: Ident,
+    /// Marks an optional bind parameter, e.g. `:name?`. The condition of a `where` or `having`
+    /// clause that refers to it is left out if it is `None`.
+    pub question_token: Option<Token![?]>,
+    /// An explicit Rust type, e.g. `:name: String`, for when it cannot be inferred from the
+    /// context of the bind parameter.
+    pub type_override: Option<TypeOverride>,
 }
 
 impl BindParam {
@@ -25,7 +34,14 @@ impl BindParam {
 
     #[must_use]
     pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+        self.type_override
+            .as_ref()
+            .map(|type_override| InferredType::RustType(&type_override.type_path))
+    }
+
+    #[must_use]
+    pub fn is_optional(&self) -> bool {
+        self.question_token.is_some()
     }
 
     pub fn peek(input: ParseStream) -> bool {
@@ -48,10 +64,51 @@ impl Parse for BindParam {
         Ok(Self {
             colon_token: input.parse()?,
             name: input.parse()?,
+            // A `?` followed by a literal, `|` or `&` is a JSON key existence operator.
+            question_token: if input.peek(Token![?])
+                && !input.peek2(syn::Lit)
+                && (!input.peek2(Token![|]) || input.peek2(Token![||]))
+                && (!input.peek2(Token![&]) || input.peek2(Token![&&]))
+            {
+                if peek_operand_after_question(input) {
+                    return Err(syn::Error::new(
+                        input.span(),
+                        "`?` after a bind parameter marks it as optional, wrap the bind \
+                        parameter in parentheses to use the `?` operator, e.g. `(:doc) ? :key`",
+                    ));
+                }
+                Some(input.parse()?)
+            } else {
+                None
+            },
+            type_override: input.call(TypeOverride::parse_option)?,
         })
     }
 }
 
+/// Whether the `?` after a bind parameter is followed by a column or another bind parameter, in
+/// which case it was most likely meant as the `?` operator, e.g. `:doc ? posts.key`. A `:`
+/// followed by a type, like `String`, `i64` or `crate::Id`, is the type override of an optional
+/// bind parameter instead.
+fn peek_operand_after_question(input: ParseStream) -> bool {
+    let fork = input.fork();
+    if fork.parse::<Token![?]>().is_err() {
+        return false;
+    }
+    if fork.peek(Token![:]) && !fork.peek(Token![::]) {
+        let _ = fork.parse::<Token![:]>();
+        return fork.parse::<Ident>().is_ok_and(|ident| {
+            let path = Path::from(ident.clone());
+            !fork.peek(Token![::])
+                && !fork.peek(Token![<])
+                && !path.is_primitive_type()
+                && !ident.to_string().starts_with(char::is_uppercase)
+        });
+    }
+    fork.peek(syn::token::Paren)
+        || (fork.peek(Ident) && (fork.peek2(Token![.]) || fork.peek2(Token![::])))
+}
+
 impl ToTokens for BindParam {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let name = &self.name;
@@ -63,5 +120,44 @@ impl PrettyPrint for BindParam {
     fn pretty_print(&self, printer: &mut Printer) {
         self.colon_token.pretty_print(printer);
         self.name.pretty_print(printer);
+        self.question_token.pretty_print(printer);
+        self.type_override.pretty_print(printer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::Expr;
+
+    #[test]
+    fn test_optional() {
+        let bind_param = syn::parse_str::<BindParam>(":title?").unwrap();
+        assert!(bind_param.is_optional());
+        for source in [
+            ":title? : String",
+            ":id?: i64",
+            ":id?: crate::Id",
+            ":id?: ::std::string::String",
+        ] {
+            let bind_param = syn::parse_str::<BindParam>(source).unwrap();
+            assert!(bind_param.is_optional());
+            assert!(bind_param.type_override.is_some());
+        }
+    }
+
+    #[test]
+    fn test_has_key_with_column() {
+        let error = syn::parse_str::<Expr>(":doc ? posts.key").err().unwrap();
+        assert!(error.to_string().contains("e.g. `(:doc) ? :key`"));
+        assert!(syn::parse_str::<Expr>("(:doc) ? posts.key").is_ok());
+    }
+
+    #[test]
+    fn test_has_key_with_bind_param() {
+        let error = syn::parse_str::<Expr>(":a ? :b").err().unwrap();
+        assert!(error.to_string().contains("e.g. `(:doc) ? :key`"));
+        assert!(syn::parse_str::<Expr>("(:a) ? :b").is_ok());
+        assert!(syn::parse_str::<Expr>(":a ? \"key\"").is_ok());
     }
 }
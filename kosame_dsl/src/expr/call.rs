@@ -1,38 +1,247 @@
 use crate::{
     inferred_type::InferredType,
+    keyword,
+    parse_option::ParseOption,
+    part::{Over, OverWindow},
+    path_ext::PathExt,
     pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    quote_option::QuoteOption,
     scopes::ScopeId,
 };
 
-use super::{Expr, Visit};
+use super::{Expr, ExprRoot, Visit};
 use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Ident, Token, parenthesized,
+    Ident, Path, Token, parenthesized,
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
     spanned::Spanned,
 };
 
+/// A function call. A single identifier calls a built-in function, whereas a path like
+/// `schema::slugify(...)` calls a function declared with the `function!` macro.
 pub struct Call {
-    pub function: Ident,
+    pub function: Path,
     pub paren: syn::token::Paren,
+    /// The `*` in `count(*)`.
+    pub star: Option<Token![*]>,
     pub params: Punctuated<Expr, Token![,]>,
+    /// The `filter (where ...)` of an aggregate function call.
+    pub filter: Option<Box<CallFilter>>,
+    /// The window of a window function call, e.g. `over (partition by post_id)`.
+    pub over: Option<Box<Over>>,
 }
 
 impl Call {
     pub fn peek(input: ParseStream) -> bool {
-        input.peek(Ident) && input.peek2(syn::token::Paren)
+        let fork = input.fork();
+        fork.call(Path::parse_mod_style).is_ok() && fork.peek(syn::token::Paren)
     }
 
     #[must_use]
     pub fn infer_name(&self) -> Option<&Ident> {
-        Some(&self.function)
+        self.function.segments.last().map(|segment| &segment.ident)
     }
 
     #[must_use]
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    #[allow(clippy::too_many_lines)]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        // Bind parameters take the type of the argument they are passed to.
+        let param = |index: usize| {
+            let param = self.params.get(index)?;
+            param.infer_type(scope_id).or_else(|| {
+                param
+                    .is_bind_param()
+                    .then(|| self.infer_arg_type(index, scope_id))
+                    .flatten()
+            })
+        };
+        let params = || (0..self.params.len()).map(param);
+        let first_param = || param(0);
+
+        let Some(function) = self.function.get_ident() else {
+            return Some(InferredType::Function {
+                path: &self.function,
+                args: params().collect::<Option<_>>()?,
+            });
+        };
+
+        // The return types of common built-in functions. Strict functions return null if any of
+        // their arguments is null, aggregate functions return null for an empty set of rows.
+        match function.to_string().as_str() {
+            "count" | "row_number" | "rank" | "dense_rank" => {
+                InferredType::derived(parse_quote!(i64), [])
+            }
+            "ntile" => InferredType::derived(parse_quote!(i32), []),
+            "percent_rank" | "cume_dist" => InferredType::derived(parse_quote!(f64), []),
+            "now" | "clock_timestamp" | "statement_timestamp" | "transaction_timestamp" => {
+                InferredType::derived(parse_quote!(::std::time::SystemTime), [])
+            }
+            "random" => InferredType::derived(parse_quote!(f64), []),
+            "gen_random_uuid" => InferredType::derived(parse_quote!(::uuid::Uuid), []),
+            "coalesce" | "greatest" | "least" => {
+                Some(InferredType::Coalesce(params().collect::<Option<_>>()?))
+            }
+            "nullif" | "min" | "max" | "lag" | "lead" | "first_value" | "last_value"
+            | "nth_value" => Some(InferredType::Nullable(Box::new(first_param()?))),
+            "sum" => Some(InferredType::Nullable(Box::new(InferredType::Projection {
+                trait_name: "Sum",
+                operand: Box::new(first_param()?),
+            }))),
+            "avg" => Some(InferredType::Nullable(Box::new(InferredType::Projection {
+                trait_name: "Avg",
+                operand: Box::new(first_param()?),
+            }))),
+            "abs" => first_param(),
+            "lower" | "upper" | "initcap" | "trim" | "btrim" | "ltrim" | "rtrim" | "left"
+            | "right" | "lpad" | "rpad" | "repeat" | "replace" | "reverse" | "substr" | "md5"
+            | "to_char" => InferredType::derived(parse_quote!(::std::string::String), params()),
+            "length" | "char_length" | "character_length" | "octet_length" => {
+                InferredType::derived(parse_quote!(i32), params())
+            }
+            "date_trunc" => InferredType::derived(parse_quote!(::std::time::SystemTime), params()),
+            // `concat` ignores null arguments.
+            "concat" => InferredType::derived(parse_quote!(::std::string::String), []),
+            "string_agg" => Some(InferredType::Nullable(Box::new(InferredType::derived(
+                parse_quote!(::std::string::String),
+                [],
+            )?))),
+            "bool_and" | "bool_or" | "every" => Some(InferredType::Nullable(Box::new(
+                InferredType::derived(parse_quote!(bool), [])?,
+            ))),
+            "to_json" | "to_jsonb" | "jsonb_set" | "jsonb_insert" | "jsonb_strip_nulls" => {
+                InferredType::derived(parse_quote!(::serde_json::Value), params())
+            }
+            // The `build` functions are not strict, a null argument becomes a JSON null.
+            "json_build_object" | "jsonb_build_object" | "json_build_array"
+            | "jsonb_build_array" => InferredType::derived(parse_quote!(::serde_json::Value), []),
+            "json_agg"
+            | "jsonb_agg"
+            | "json_object_agg"
+            | "jsonb_object_agg"
+            | "jsonb_path_query_first" => Some(InferredType::Nullable(Box::new(
+                InferredType::derived(parse_quote!(::serde_json::Value), [])?,
+            ))),
+            "json_typeof" | "jsonb_typeof" | "jsonb_pretty" => {
+                InferredType::derived(parse_quote!(::std::string::String), params())
+            }
+            "json_array_length" | "jsonb_array_length" => {
+                InferredType::derived(parse_quote!(i32), params())
+            }
+            "jsonb_path_exists" => InferredType::derived(parse_quote!(bool), params()),
+            "array_agg" => Some(InferredType::Nullable(Box::new(InferredType::Array(vec![
+                first_param()?,
+            ])))),
+            "cardinality" => InferredType::derived(parse_quote!(i32), params()),
+            // `array_length` and `array_position` return null for empty arrays and missing
+            // elements.
+            "array_length" | "array_position" => Some(InferredType::Nullable(Box::new(
+                InferredType::derived(parse_quote!(i32), [])?,
+            ))),
+            "array_to_string" => {
+                InferredType::derived(parse_quote!(::std::string::String), params())
+            }
+            "to_tsvector" | "setweight" => {
+                InferredType::derived(parse_quote!(::kosame::text_search::TsVector), params())
+            }
+            "to_tsquery" | "plainto_tsquery" | "phraseto_tsquery" | "websearch_to_tsquery" => {
+                InferredType::derived(parse_quote!(::kosame::text_search::TsQuery), params())
+            }
+            "ts_rank" | "ts_rank_cd" => InferredType::derived(parse_quote!(f32), params()),
+            "ts_headline" => InferredType::derived(parse_quote!(::std::string::String), params()),
+            // Set-returning functions, which may also be used in `from`.
+            "unnest" if self.params.len() == 1 => {
+                Some(InferredType::Element(Box::new(first_param()?)))
+            }
+            "generate_series" => first_param(),
+            "json_array_elements" | "jsonb_array_elements" => {
+                InferredType::derived(parse_quote!(::serde_json::Value), [])
+            }
+            "json_array_elements_text" | "jsonb_array_elements_text" => {
+                Some(InferredType::Nullable(Box::new(InferredType::derived(
+                    parse_quote!(::std::string::String),
+                    [],
+                )?)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Infers the type of the argument at `index` from the signature of the function, for
+    /// arguments without a type of their own like bind parameters.
+    #[must_use]
+    pub fn infer_arg_type(&self, index: usize, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let Some(function) = self.function.get_ident() else {
+            return Some(InferredType::FunctionArg {
+                path: &self.function,
+                index,
+            });
+        };
+
+        let text = || InferredType::derived(parse_quote!(::std::string::String), []);
+        let int = || InferredType::derived(parse_quote!(i32), []);
+
+        // The argument types of common built-in functions. Arguments of a type without a Rust
+        // equivalent, like the `regconfig` of `to_tsvector`, are left out.
+        match function.to_string().as_str() {
+            "coalesce" | "greatest" | "least" | "nullif" => {
+                let others: Vec<_> = self
+                    .params
+                    .iter()
+                    .enumerate()
+                    .filter(|(other, _)| *other != index)
+                    .filter_map(|(_, param)| param.infer_type(scope_id))
+                    .collect();
+                if others.is_empty() {
+                    return None;
+                }
+                Some(InferredType::NotNull(Box::new(InferredType::Coalesce(
+                    others,
+                ))))
+            }
+            "lower" | "upper" | "initcap" | "trim" | "btrim" | "ltrim" | "rtrim" | "replace"
+            | "reverse" | "md5" | "length" | "char_length" | "character_length"
+            | "octet_length" => text(),
+            "left" | "right" | "repeat" | "lpad" | "rpad" => match index {
+                0 | 2 => text(),
+                _ => int(),
+            },
+            "substr" => {
+                if index == 0 {
+                    text()
+                } else {
+                    int()
+                }
+            }
+            "to_char" | "array_to_string" if index > 0 => text(),
+            "array_length" if index == 1 => int(),
+            "date_trunc" => match index {
+                0 => text(),
+                1 => InferredType::derived(parse_quote!(::std::time::SystemTime), []),
+                _ => None,
+            },
+            // The text is the last argument, after an optional `regconfig`.
+            "to_tsvector"
+            | "to_tsquery"
+            | "plainto_tsquery"
+            | "phraseto_tsquery"
+            | "websearch_to_tsquery"
+                if index + 1 == self.params.len() =>
+            {
+                text()
+            }
+            "setweight" if index == 0 => {
+                InferredType::derived(parse_quote!(::kosame::text_search::TsVector), [])
+            }
+            // Unlike the variant with weights, this one takes the document first.
+            "ts_rank" | "ts_rank_cd" if self.params.len() == 2 => match index {
+                0 => InferredType::derived(parse_quote!(::kosame::text_search::TsVector), []),
+                _ => InferredType::derived(parse_quote!(::kosame::text_search::TsQuery), []),
+            },
+            _ => None,
+        }
     }
 
     #[must_use]
@@ -48,36 +257,64 @@ pub fn visit_call<'a>(visit: &mut (impl Visit<'a> + ?Sized), call: &'a Call) {
     for param in &call.params {
         visit.visit_expr(param);
     }
+    if let Some(filter) = &call.filter {
+        visit.visit_expr_root(&filter.expr);
+    }
+    if let Some(over) = &call.over
+        && let OverWindow::Spec { spec, .. } = &over.window
+    {
+        visit.visit_window_spec(spec);
+    }
 }
 
 impl Parse for Call {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let content;
         Ok(Self {
-            function: input.parse()?,
+            function: input.call(Path::parse_mod_style)?,
             paren: parenthesized!(content in input),
+            star: content.parse()?,
             params: content.parse_terminated(Expr::parse, Token![,])?,
+            filter: input.call(CallFilter::parse_option)?.map(Box::new),
+            over: input.call(Over::parse_option)?.map(Box::new),
         })
     }
 }
 
 impl ToTokens for Call {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let function_name = &self.function.to_string();
         let params = self.params.iter();
+        let star = self.star.is_some();
 
-        // Some functions like `coalesce` must not be quoted like an identifier, whereas others,
-        // like `sum`, can be. User defined functions should be treated as identifiers.
-        let keyword = matches!(
-            function_name.as_ref(),
-            "coalesce" | "greatest" | "least" | "nullif"
-        );
+        let (function_name, keyword) = if let Some(function) = self.function.get_ident() {
+            let function_name = function.to_string();
+            // Some functions like `coalesce` must not be quoted like an identifier, whereas
+            // others, like `sum`, can be. User defined functions should be treated as
+            // identifiers.
+            let keyword = matches!(
+                function_name.as_ref(),
+                "coalesce" | "greatest" | "least" | "nullif"
+            );
+            (function_name.to_token_stream(), keyword)
+        } else {
+            let function = self.function.to_call_site(1);
+            (quote! { #function::FUNCTION_NAME }, false)
+        };
+
+        let filter = QuoteOption(self.filter.as_ref().map(|filter| {
+            let expr = &filter.expr;
+            quote! { &#expr }
+        }));
+        let over = QuoteOption(self.over.as_ref().map(|over| quote! { &#over }));
 
         quote! {
             ::kosame::repr::expr::Call::new(
                 #function_name,
                 &[#(#params),*],
+                #star,
                 #keyword,
+                #filter,
+                #over,
             )
         }
         .to_tokens(tokens);
@@ -89,7 +326,56 @@ impl PrettyPrint for Call {
         self.function.pretty_print(printer);
         self.paren
             .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                self.star.pretty_print(printer);
                 self.params.pretty_print(printer);
             });
+        if let Some(filter) = &self.filter {
+            " ".pretty_print(printer);
+            filter.pretty_print(printer);
+        }
+        if let Some(over) = &self.over {
+            " ".pretty_print(printer);
+            over.pretty_print(printer);
+        }
+    }
+}
+
+/// The `filter (where ...)` of an aggregate function call, e.g.
+/// `count(*) filter (where upvotes > 0)`.
+pub struct CallFilter {
+    pub filter_keyword: keyword::filter,
+    pub paren_token: syn::token::Paren,
+    pub where_token: Token![where],
+    pub expr: ExprRoot,
+}
+
+impl ParseOption for CallFilter {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::filter) && input.peek2(syn::token::Paren)
+    }
+}
+
+impl Parse for CallFilter {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            filter_keyword: input.call(keyword::filter::parse_autocomplete)?,
+            paren_token: parenthesized!(content in input),
+            where_token: content.parse()?,
+            expr: content.parse()?,
+        })
+    }
+}
+
+impl PrettyPrint for CallFilter {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.filter_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.paren_token
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                self.where_token.pretty_print(printer);
+                " ".pretty_print(printer);
+                self.expr.pretty_print(printer);
+            });
     }
 }
@@ -0,0 +1,226 @@
+use crate::{
+    inferred_type::InferredType,
+    keyword,
+    pretty::{BreakMode, PrettyPrint, Printer},
+    quote_option::QuoteOption,
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token,
+    parse::{Parse, ParseStream},
+};
+
+/// A searched (`case when cond then ... end`) or simple (`case operand when value then ... end`)
+/// conditional expression.
+pub struct Case {
+    pub case_keyword: keyword::case,
+    pub operand: Option<Box<Expr>>,
+    pub whens: Vec<When>,
+    pub r#else: Option<Else>,
+    pub end_keyword: keyword::end,
+}
+
+impl Case {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::case)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    /// Infers the common type of all branches, which is nullable if any of them is nullable.
+    /// Without an `else` branch, the result is nullable as well.
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let branches = self
+            .whens
+            .iter()
+            .map(|when| &when.result)
+            .chain(self.r#else.as_ref().map(|r#else| &*r#else.result))
+            .map(|result| result.infer_type(scope_id))
+            .collect::<Option<_>>()?;
+        let inferred_type = InferredType::Case(branches);
+
+        if self.r#else.is_some() {
+            Some(inferred_type)
+        } else {
+            Some(InferredType::Nullable(Box::new(inferred_type)))
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.case_keyword
+            .span
+            .join(self.end_keyword.span)
+            .unwrap_or(self.case_keyword.span)
+    }
+}
+
+pub fn visit_case<'a>(visit: &mut (impl Visit<'a> + ?Sized), case: &'a Case) {
+    if let Some(operand) = &case.operand {
+        visit.visit_expr(operand);
+    }
+    for when in &case.whens {
+        visit.visit_expr(&when.condition);
+        visit.visit_expr(&when.result);
+    }
+    if let Some(r#else) = &case.r#else {
+        visit.visit_expr(&r#else.result);
+    }
+}
+
+impl Parse for Case {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let case_keyword = input.call(keyword::case::parse_autocomplete)?;
+        let operand = if input.peek(keyword::when) {
+            None
+        } else {
+            Some(Box::new(input.parse()?))
+        };
+
+        let mut whens = Vec::new();
+        while input.peek(keyword::when) {
+            whens.push(input.parse()?);
+        }
+        if whens.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "`case` requires at least one `when` branch",
+            ));
+        }
+
+        let r#else = if input.peek(Token![else]) {
+            Some(input.parse()?)
+        } else {
+            None
+        };
+
+        Ok(Self {
+            case_keyword,
+            operand,
+            whens,
+            r#else,
+            end_keyword: input.call(keyword::end::parse_autocomplete)?,
+        })
+    }
+}
+
+impl ToTokens for Case {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let operand = QuoteOption(self.operand.as_ref().map(|operand| quote! { &#operand }));
+        let whens = &self.whens;
+        let r#else = QuoteOption(self.r#else.as_ref().map(|r#else| {
+            let result = &r#else.result;
+            quote! { &#result }
+        }));
+        quote! {
+            ::kosame::repr::expr::Case::new(#operand, &[#(#whens),*], #r#else)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Case {
+    fn pretty_print(&self, printer: &mut Printer) {
+        // The enclosing expression is already indented, so the branches break onto its level
+        // and `end` is dedented to line up with `case`.
+        printer.scan_begin(BreakMode::Consistent);
+        self.case_keyword.pretty_print(printer);
+        if let Some(operand) = &self.operand {
+            " ".pretty_print(printer);
+            operand.pretty_print(printer);
+        }
+        for when in &self.whens {
+            printer.scan_break();
+            " ".pretty_print(printer);
+            printer.scan_begin(BreakMode::Inconsistent);
+            when.pretty_print(printer);
+            printer.scan_end();
+        }
+        if let Some(r#else) = &self.r#else {
+            printer.scan_break();
+            " ".pretty_print(printer);
+            printer.scan_begin(BreakMode::Inconsistent);
+            r#else.pretty_print(printer);
+            printer.scan_end();
+        }
+        printer.scan_indent(-1);
+        printer.scan_break();
+        printer.scan_indent(1);
+        " ".pretty_print(printer);
+        self.end_keyword.pretty_print(printer);
+        printer.scan_end();
+    }
+}
+
+pub struct When {
+    pub when_keyword: keyword::when,
+    pub condition: Expr,
+    pub then_keyword: keyword::then,
+    pub result: Expr,
+}
+
+impl Parse for When {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            when_keyword: input.call(keyword::when::parse_autocomplete)?,
+            condition: input.parse()?,
+            then_keyword: input.call(keyword::then::parse_autocomplete)?,
+            result: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for When {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let condition = &self.condition;
+        let result = &self.result;
+        quote! {
+            ::kosame::repr::expr::When::new(&#condition, &#result)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for When {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.when_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.condition.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.then_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.result.pretty_print(printer);
+    }
+}
+
+pub struct Else {
+    pub else_token: Token![else],
+    pub result: Box<Expr>,
+}
+
+impl Parse for Else {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            else_token: input.parse()?,
+            result: input.parse()?,
+        })
+    }
+}
+
+impl PrettyPrint for Else {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.else_token.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.result.pretty_print(printer);
+    }
+}
@@ -35,12 +35,11 @@ impl Cast {
         self.value.infer_name()
     }
 
+    /// Infers the Rust type of the target data type, which is nullable if the value is.
     #[must_use]
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        // Difficulty detecting nullability
-        // Some(InferredType::DataType(self.data_type.clone()))
-
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let rust_type = syn::parse2(self.data_type.rust_type()?).ok()?;
+        InferredType::derived(rust_type, [self.value.infer_type(scope_id)])
     }
 
     #[must_use]
@@ -72,7 +71,7 @@ impl Parse for Cast {
 impl ToTokens for Cast {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         let value = &self.value;
-        let data_type = &self.data_type.name.to_string();
+        let data_type = &self.data_type.sql_name();
         quote! {
             ::kosame::repr::expr::Cast::new(&#value, #data_type)
         }
@@ -0,0 +1,81 @@
+use crate::{
+    inferred_type::InferredType,
+    keyword,
+    pretty::{PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, LitStr, parse::ParseStream};
+
+/// `expr collate "C"`
+pub struct Collate {
+    pub expr: Box<Expr>,
+    pub collate_keyword: keyword::collate,
+    pub collation: LitStr,
+}
+
+impl Collate {
+    // Taken from https://www.postgresql.org/docs/18/sql-syntax-lexical.html#SQL-PRECEDENCE
+    pub const PRECEDENCE: u32 = 11;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::collate)
+    }
+
+    /// Parses the remainder of the expression after the expression being collated.
+    pub fn parse_postfix(expr: Expr, input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            expr: Box::new(expr),
+            collate_keyword: input.call(keyword::collate::parse_autocomplete)?,
+            collation: input.parse()?,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        self.expr.infer_name()
+    }
+
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        self.expr.infer_type(scope_id)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.expr
+            .span()
+            .join(self.collation.span())
+            .unwrap_or(self.expr.span())
+    }
+}
+
+pub fn visit_collate<'a>(visit: &mut (impl Visit<'a> + ?Sized), collate: &'a Collate) {
+    visit.visit_expr(&collate.expr);
+}
+
+impl ToTokens for Collate {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let expr = &self.expr;
+        let collation = &self.collation;
+        quote! {
+            ::kosame::repr::expr::Collate::new(&#expr, #collation)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Collate {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.expr.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.collate_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.collation.pretty_print(printer);
+    }
+}
@@ -0,0 +1,84 @@
+use crate::{
+    command::Command,
+    inferred_type::InferredType,
+    keyword,
+    pretty::{PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Visit, subquery::pretty_print_command};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    spanned::Spanned,
+};
+
+/// `exists (select ...)`. Negation is handled by the regular `not` operator.
+pub struct Exists {
+    pub exists_keyword: keyword::exists,
+    pub paren_token: syn::token::Paren,
+    pub command: Box<Command>,
+}
+
+impl Exists {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::exists) && input.peek2(syn::token::Paren)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
+        InferredType::derived(parse_quote!(bool), [])
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.exists_keyword
+            .span
+            .join(self.paren_token.span.span())
+            .unwrap_or(self.exists_keyword.span)
+    }
+}
+
+pub fn visit_exists<'a>(visit: &mut (impl Visit<'a> + ?Sized), exists: &'a Exists) {
+    visit.visit_command(&exists.command);
+}
+
+impl Parse for Exists {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            exists_keyword: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            command: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Exists {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let command = &self.command;
+        quote! {
+            ::kosame::repr::expr::Exists::new(&#command)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Exists {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.exists_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        pretty_print_command(printer, &self.paren_token, &self.command);
+    }
+}
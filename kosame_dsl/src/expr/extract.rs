@@ -0,0 +1,157 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+    token::Paren,
+};
+
+use crate::{
+    data_type::DataType,
+    inferred_type::InferredType,
+    keyword,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+
+/// A field of a date/time value, e.g. `extract(year from created_at)`.
+pub struct Extract {
+    pub extract_kw: keyword::extract,
+    pub paren: Paren,
+    pub field: Ident,
+    pub from_kw: keyword::from,
+    pub source: Box<Expr>,
+}
+
+/// The fields PostgreSQL can extract from a date/time value.
+const EXTRACT_FIELDS: &[&str] = &[
+    "century",
+    "day",
+    "decade",
+    "dow",
+    "doy",
+    "epoch",
+    "hour",
+    "isodow",
+    "isoyear",
+    "julian",
+    "microseconds",
+    "millennium",
+    "milliseconds",
+    "minute",
+    "month",
+    "quarter",
+    "second",
+    "timezone",
+    "timezone_hour",
+    "timezone_minute",
+    "week",
+    "year",
+];
+
+impl Extract {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::extract) && input.peek2(Paren)
+    }
+
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    /// `extract` returns `numeric`, which is nullable if the source is.
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let data_type = DataType {
+            name: Ident::new("numeric", self.extract_kw.span),
+            array_brackets: Vec::new(),
+        };
+        let rust_type = syn::parse2(data_type.rust_type()?).ok()?;
+        InferredType::derived(rust_type, [self.source.infer_type(scope_id)])
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.extract_kw
+            .span
+            .join(self.paren.span.span())
+            .unwrap_or(self.extract_kw.span)
+    }
+}
+
+pub fn visit_extract<'a>(visit: &mut (impl Visit<'a> + ?Sized), extract: &'a Extract) {
+    visit.visit_expr(&extract.source);
+}
+
+impl Parse for Extract {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let extract_kw = input.call(keyword::extract::parse_autocomplete)?;
+        let paren = parenthesized!(content in input);
+        let field: Ident = content.parse()?;
+        if !EXTRACT_FIELDS.iter().any(|name| field == name) {
+            return Err(syn::Error::new(
+                field.span(),
+                format!(
+                    "unknown extract field, expected one of: {}",
+                    EXTRACT_FIELDS.join(", ")
+                ),
+            ));
+        }
+        Ok(Self {
+            extract_kw,
+            paren,
+            field,
+            from_kw: content.parse()?,
+            source: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Extract {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let field = self.field.to_string();
+        let source = &self.source;
+        quote! {
+            ::kosame::repr::expr::Extract::new(#field, &#source)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Extract {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.extract_kw.pretty_print(printer);
+        self.paren
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                self.field.pretty_print(printer);
+                " ".pretty_print(printer);
+                self.from_kw.pretty_print(printer);
+                printer.scan_break();
+                " ".pretty_print(printer);
+                self.source.pretty_print(printer);
+            });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field() {
+        let extract = syn::parse_str::<Extract>("extract(isoyear from created_at)").unwrap();
+        assert_eq!(extract.field, "isoyear");
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let error = syn::parse_str::<Extract>("extract(years from created_at)")
+            .err()
+            .unwrap();
+        assert!(error.to_string().starts_with("unknown extract field"));
+    }
+}
@@ -0,0 +1,202 @@
+use crate::{
+    command::Command,
+    inferred_type::InferredType,
+    keyword,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{BindParam, Expr, Subquery, Visit, subquery::pretty_print_command};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    parse_quote,
+    punctuated::Punctuated,
+    spanned::Spanned,
+};
+
+/// `lhs [not] in (a, b, ...)`, `lhs [not] in (select ...)` or `lhs [not] in :list`
+pub struct In {
+    pub lhs: Box<Expr>,
+    pub not_keyword: Option<keyword::not>,
+    pub in_token: Token![in],
+    pub list: InList,
+}
+
+impl In {
+    // Taken from https://www.postgresql.org/docs/18/sql-syntax-lexical.html#SQL-PRECEDENCE
+    pub const PRECEDENCE: u32 = 6;
+
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(Token![in]) || (input.peek(keyword::not) && input.peek2(Token![in]))
+    }
+
+    /// Parses the remainder of the expression after its left-hand side.
+    pub fn parse_postfix(lhs: Expr, input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            lhs: Box::new(lhs),
+            not_keyword: input.parse()?,
+            in_token: input.parse()?,
+            list: input.parse()?,
+        })
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    /// Only lists of expressions are inferred, since the nullability of the elements of a
+    /// subquery or bind parameter is unknown.
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let InList::Exprs { list, .. } = &self.list else {
+            return None;
+        };
+        InferredType::derived(
+            parse_quote!(bool),
+            std::iter::once(self.lhs.infer_type(scope_id))
+                .chain(list.iter().map(|item| item.infer_type(scope_id))),
+        )
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.lhs
+            .span()
+            .join(self.list.span())
+            .unwrap_or(self.lhs.span())
+    }
+}
+
+pub fn visit_in<'a>(visit: &mut (impl Visit<'a> + ?Sized), r#in: &'a In) {
+    visit.visit_expr(&r#in.lhs);
+    match &r#in.list {
+        InList::Exprs { list, .. } => {
+            for item in list {
+                visit.visit_expr(item);
+            }
+        }
+        InList::Subquery { command, .. } => visit.visit_command(command),
+        InList::BindParam(bind_param) => visit.visit_bind_param(bind_param),
+    }
+}
+
+impl ToTokens for In {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let lhs = &self.lhs;
+        let not = self.not_keyword.is_some();
+        let list = &self.list;
+        quote! {
+            ::kosame::repr::expr::In::new(&#lhs, #not, #list)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for In {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.lhs.pretty_print(printer);
+        printer.scan_break();
+        " ".pretty_print(printer);
+        if let Some(not_keyword) = &self.not_keyword {
+            not_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+        }
+        self.in_token.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.list.pretty_print(printer);
+    }
+}
+
+pub enum InList {
+    Exprs {
+        paren_token: syn::token::Paren,
+        list: Punctuated<Expr, Token![,]>,
+    },
+    Subquery {
+        paren_token: syn::token::Paren,
+        command: Box<Command>,
+    },
+    /// A bind parameter holding the whole list as a `Vec<T>`.
+    BindParam(BindParam),
+}
+
+impl InList {
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Exprs { paren_token, .. } | Self::Subquery { paren_token, .. } => {
+                paren_token.span.span()
+            }
+            Self::BindParam(bind_param) => bind_param.span(),
+        }
+    }
+}
+
+impl Parse for InList {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if BindParam::peek(input) {
+            return Ok(Self::BindParam(input.parse()?));
+        }
+
+        if Subquery::peek(input) {
+            let content;
+            return Ok(Self::Subquery {
+                paren_token: parenthesized!(content in input),
+                command: content.parse()?,
+            });
+        }
+
+        let content;
+        let paren_token = parenthesized!(content in input);
+        let list = content.parse_terminated(Expr::parse, Token![,])?;
+        if list.is_empty() {
+            return Err(syn::Error::new(
+                paren_token.span.join(),
+                "the list of `in` cannot be empty",
+            ));
+        }
+
+        Ok(Self::Exprs { paren_token, list })
+    }
+}
+
+impl ToTokens for InList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Exprs { list, .. } => {
+                let list = list.iter();
+                quote! { ::kosame::repr::expr::InList::Exprs(&[#(#list),*]) }
+            }
+            Self::Subquery { command, .. } => {
+                quote! { ::kosame::repr::expr::InList::Subquery(&#command) }
+            }
+            Self::BindParam(bind_param) => {
+                quote! { ::kosame::repr::expr::InList::BindParam(#bind_param) }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for InList {
+    fn pretty_print(&self, printer: &mut Printer) {
+        match self {
+            Self::Exprs { paren_token, list } => {
+                paren_token.pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                    list.pretty_print(printer);
+                });
+            }
+            Self::Subquery {
+                paren_token,
+                command,
+            } => pretty_print_command(printer, paren_token, command),
+            Self::BindParam(bind_param) => bind_param.pretty_print(printer),
+        }
+    }
+}
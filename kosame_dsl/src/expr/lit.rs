@@ -1,4 +1,5 @@
 use crate::{
+    data_type::DataType,
     inferred_type::InferredType,
     keyword,
     pretty::{PrettyPrint, Printer},
@@ -11,6 +12,7 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident,
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
 };
 
@@ -21,17 +23,48 @@ pub enum Lit {
     Str(syn::LitStr),
     Bool(syn::LitBool),
     Null(keyword::null),
+    /// A string literal of a date/time type, e.g. `date "2024-01-01"` or `interval "7 days"`.
+    Typed {
+        type_name: Ident,
+        string: syn::LitStr,
+    },
 }
 
+/// The types that may precede a string literal to give it that type.
+const TYPED_LIT_TYPES: &[&str] = &["date", "time", "timestamp", "timestamptz", "interval"];
+
 impl Lit {
     #[must_use]
     pub fn infer_name(&self) -> Option<&Ident> {
         None
     }
 
+    /// Infers the type PostgreSQL gives the literal. Integers are `int` or `bigint` depending on
+    /// their size, while floats are `numeric` and `null` has no type on its own, so neither of
+    /// them can be inferred. Typed literals take the Rust type of their data type, if it has one.
     #[must_use]
     pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+        let rust_type = match self {
+            Self::Int(inner) => {
+                let value = inner.base10_parse::<i64>().ok()?;
+                if i32::try_from(value).is_ok() {
+                    parse_quote!(i32)
+                } else {
+                    parse_quote!(i64)
+                }
+            }
+            Self::Str(_) => parse_quote!(::std::string::String),
+            Self::Bool(_) => parse_quote!(bool),
+            Self::Typed { type_name, .. } => {
+                let data_type = DataType {
+                    name: type_name.clone(),
+                    array_brackets: Vec::new(),
+                };
+                syn::parse2(data_type.rust_type()?).ok()?
+            }
+            Self::Float(_) | Self::Null(_) => return None,
+        };
+        InferredType::derived(rust_type, [])
     }
 
     #[must_use]
@@ -42,6 +75,10 @@ impl Lit {
             Self::Str(inner) => inner.span(),
             Self::Bool(inner) => inner.span(),
             Self::Null(inner) => inner.span(),
+            Self::Typed { type_name, string } => type_name
+                .span()
+                .join(string.span())
+                .unwrap_or(type_name.span()),
         }
     }
 }
@@ -53,6 +90,22 @@ impl Parse for Lit {
         if input.peek(keyword::null) {
             return Ok(Self::Null(input.parse()?));
         }
+        if input.peek(Ident) && input.peek2(syn::LitStr) {
+            let type_name: Ident = input.parse()?;
+            if !TYPED_LIT_TYPES.iter().any(|name| type_name == name) {
+                return Err(syn::Error::new(
+                    type_name.span(),
+                    format!(
+                        "unsupported typed literal, expected one of: {}",
+                        TYPED_LIT_TYPES.join(", ")
+                    ),
+                ));
+            }
+            return Ok(Self::Typed {
+                type_name,
+                string: input.parse()?,
+            });
+        }
         let lit = input.parse::<syn::Lit>()?;
         Ok(match lit {
             syn::Lit::Int(inner) => Self::Int(inner),
@@ -77,6 +130,10 @@ impl ToTokens for Lit {
             Self::Str(inner) => quote! { ::kosame::repr::expr::Lit::Str(#inner) },
             Self::Bool(inner) => quote! { ::kosame::repr::expr::Lit::Bool(#inner) },
             Self::Null(_) => quote! { ::kosame::repr::expr::Lit::Null },
+            Self::Typed { type_name, string } => {
+                let type_name = type_name.to_string();
+                quote! { ::kosame::repr::expr::Lit::Typed(#type_name, #string) }
+            }
         };
         token_stream.to_tokens(tokens);
     }
@@ -90,6 +147,11 @@ impl PrettyPrint for Lit {
             Self::Str(inner) => inner.pretty_print(printer),
             Self::Bool(inner) => inner.pretty_print(printer),
             Self::Null(inner) => inner.pretty_print(printer),
+            Self::Typed { type_name, string } => {
+                type_name.pretty_print(printer);
+                " ".pretty_print(printer);
+                string.pretty_print(printer);
+            }
         }
     }
 }
@@ -1,34 +1,64 @@
+mod array;
+mod between;
 mod binary;
 mod bind_param;
 mod call;
+mod case;
 mod cast;
+mod collate;
 mod column_ref;
+mod exists;
+mod extract;
+mod r#in;
 mod lit;
 mod paren;
+mod quantified;
 mod raw;
+mod subquery;
+mod subscript;
 mod unary;
 
 use std::ops::Deref;
 
+pub use array::*;
+pub use between::*;
 pub use binary::*;
 pub use bind_param::*;
 pub use call::*;
+pub use case::*;
 pub use cast::*;
+pub use collate::*;
 pub use column_ref::*;
+pub use exists::*;
+pub use extract::*;
+pub use r#in::*;
 pub use lit::*;
 pub use paren::*;
+pub use quantified::*;
 pub use raw::*;
+pub use subquery::*;
+pub use subscript::*;
 pub use unary::*;
 
 // Re-export visit functions
+pub use array::visit_array;
+pub use between::visit_between;
 pub use binary::visit_binary;
 pub use bind_param::visit_bind_param;
 pub use call::visit_call;
+pub use case::visit_case;
 pub use cast::visit_cast;
+pub use collate::visit_collate;
 pub use column_ref::visit_column_ref;
+pub use exists::visit_exists;
+pub use extract::visit_extract;
+pub use r#in::visit_in;
 pub use lit::visit_lit;
 pub use paren::visit_paren;
+pub use quantified::visit_quantified;
 pub use raw::visit_raw;
+pub use subquery::visit_subquery;
+pub use subscript::visit_subscript;
 pub use unary::visit_unary;
 
 use proc_macro2::{Span, TokenStream};
@@ -36,7 +66,6 @@ use quote::{ToTokens, quote};
 use syn::{
     Ident,
     parse::{Parse, ParseStream},
-    spanned::Spanned,
 };
 
 use crate::{
@@ -47,28 +76,48 @@ use crate::{
 };
 
 pub enum Expr {
+    Array(Array),
+    Between(Between),
     Binary(Binary),
     BindParam(BindParam),
     Call(Call),
+    Case(Case),
     Cast(Cast),
+    Collate(Collate),
     ColumnRef(ColumnRef),
+    Exists(Exists),
+    Extract(Extract),
+    In(In),
     Lit(Lit),
     Paren(Paren),
+    Quantified(Quantified),
     Raw(Raw),
+    Subquery(Subquery),
+    Subscript(Subscript),
     Unary(Unary),
 }
 
 macro_rules! variants {
     ($macro:ident!()) => {
         $macro!(
+            Array
+            Between
             Binary
             BindParam
             Call
+            Case
             Cast
+            Collate
             ColumnRef
+            Exists
+            Extract
+            In
             Lit
             Paren
+            Quantified
             Raw
+            Subquery
+            Subscript
             Unary
         )
     };
@@ -102,10 +151,19 @@ impl Expr {
     }
 
     fn parse_prefix(input: ParseStream) -> syn::Result<Expr> {
-        if input.peek(syn::token::Paren) {
-            Ok(Expr::Paren(input.parse()?))
+        if Subquery::peek(input) {
+            Ok(Expr::Subquery(input.parse()?))
+        } else if input.peek(syn::token::Paren) {
+            Subscript::parse_postfix(Expr::Paren(input.parse()?), input)
         } else if BindParam::peek(input) {
-            Ok(Expr::BindParam(input.parse()?))
+            Subscript::parse_postfix(Expr::BindParam(input.parse()?), input)
+        } else if Array::peek(input) {
+            Ok(Expr::Array(input.parse()?))
+        } else if Quantified::peek(input) {
+            Err(syn::Error::new(
+                input.span(),
+                "array comparisons like `any(...)` must follow a comparison operator",
+            ))
         } else if Raw::peek(input) {
             Ok(Expr::Raw(input.parse()?))
         } else if UnOp::peek(input) {
@@ -115,6 +173,12 @@ impl Expr {
                 op,
                 Self::parse_expr(input, precedence)?,
             )))
+        } else if Exists::peek(input) {
+            Ok(Expr::Exists(input.parse()?))
+        } else if Extract::peek(input) {
+            Ok(Expr::Extract(input.parse()?))
+        } else if Case::peek(input) {
+            Ok(Expr::Case(input.parse()?))
         } else if Cast::peek(input) {
             Ok(Expr::Cast(input.parse()?))
         } else if input.fork().parse::<Lit>().is_ok() {
@@ -122,7 +186,7 @@ impl Expr {
         } else if Call::peek(input) {
             Ok(Expr::Call(input.parse()?))
         } else if input.fork().parse::<ColumnRef>().is_ok() {
-            Ok(Expr::ColumnRef(input.parse()?))
+            Subscript::parse_postfix(Expr::ColumnRef(input.parse()?), input)
         } else {
             Err(syn::Error::new(input.span(), "expected expression"))
         }
@@ -131,25 +195,91 @@ impl Expr {
     fn parse_expr(input: ParseStream, min_precedence: u32) -> syn::Result<Expr> {
         let mut lhs = Self::parse_prefix(input)?;
 
-        while let Some(bin_op) = BinOp::peek(input) {
-            let precedence = bin_op.precedence();
-            if precedence < min_precedence {
+        loop {
+            if let Some(bin_op) = BinOp::peek(input) {
+                let precedence = bin_op.precedence();
+                if precedence < min_precedence {
+                    break;
+                }
+
+                let next_precedence = if bin_op.associativity() == Associativity::Left {
+                    precedence + 1
+                } else {
+                    precedence
+                };
+
+                let bin_op = input.parse::<BinOp>()?;
+                let rhs = if bin_op.is_comparison() && Quantified::peek(input) {
+                    Expr::Quantified(input.parse()?)
+                } else {
+                    Self::parse_expr(input, next_precedence)?
+                };
+
+                lhs = Expr::Binary(Binary::new(lhs, bin_op, rhs));
+            } else if In::peek(input) {
+                if In::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::In(In::parse_postfix(lhs, input)?);
+            } else if Between::peek(input) {
+                if Between::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::Between(Between::parse_postfix(lhs, input)?);
+            } else if Collate::peek(input) {
+                if Collate::PRECEDENCE < min_precedence {
+                    break;
+                }
+                lhs = Expr::Collate(Collate::parse_postfix(lhs, input)?);
+            } else {
                 break;
             }
+        }
 
-            let next_precedence = if bin_op.associativity() == Associativity::Left {
-                precedence + 1
-            } else {
-                precedence
-            };
+        Ok(lhs)
+    }
+
+    /// Whether this is a bind parameter, possibly in parentheses.
+    #[must_use]
+    pub fn is_bind_param(&self) -> bool {
+        match self {
+            Self::BindParam(_) => true,
+            Self::Paren(paren) => paren.expr.is_bind_param(),
+            _ => false,
+        }
+    }
+
+    /// The operands of the top-level `and` chain of the expression, e.g. `a`, `b` and `c` for
+    /// `a and b and c`.
+    #[must_use]
+    pub fn conjuncts(&self) -> Vec<&Expr> {
+        match self {
+            Expr::Binary(binary) if matches!(binary.op, BinOp::And(_)) => {
+                let mut conjuncts = binary.lhs.conjuncts();
+                conjuncts.extend(binary.rhs.conjuncts());
+                conjuncts
+            }
+            expr => vec![expr],
+        }
+    }
 
-            let bin_op = input.parse()?;
-            let rhs = Self::parse_expr(input, next_precedence)?;
+    /// The names of the optional bind parameters used anywhere in the expression, including its
+    /// subqueries.
+    #[must_use]
+    pub fn optional_bind_params(&self) -> Vec<&Ident> {
+        struct OptionalBindParams<'a>(Vec<&'a Ident>);
 
-            lhs = Expr::Binary(Binary::new(lhs, bin_op, rhs));
+        impl<'a> Visit<'a> for OptionalBindParams<'a> {
+            fn visit_bind_param(&mut self, bind_param: &'a BindParam) {
+                if bind_param.is_optional() && !self.0.contains(&&bind_param.name) {
+                    self.0.push(&bind_param.name);
+                }
+            }
         }
 
-        Ok(lhs)
+        let mut visitor = OptionalBindParams(Vec::new());
+        visitor.visit_expr(self);
+        visitor.0
     }
 
     #[must_use]
@@ -168,14 +298,24 @@ impl Expr {
 
 pub fn visit_expr<'a>(visit: &mut (impl Visit<'a> + ?Sized), expr: &'a Expr) {
     match expr {
+        Expr::Array(inner) => visit.visit_array(inner),
+        Expr::Between(inner) => visit.visit_between(inner),
         Expr::Binary(inner) => visit.visit_binary(inner),
         Expr::BindParam(inner) => visit.visit_bind_param(inner),
         Expr::Call(inner) => visit.visit_call(inner),
+        Expr::Case(inner) => visit.visit_case(inner),
         Expr::Cast(inner) => visit.visit_cast(inner),
+        Expr::Collate(inner) => visit.visit_collate(inner),
         Expr::ColumnRef(inner) => visit.visit_column_ref(inner),
+        Expr::Exists(inner) => visit.visit_exists(inner),
+        Expr::Extract(inner) => visit.visit_extract(inner),
+        Expr::In(inner) => visit.visit_in(inner),
         Expr::Lit(inner) => visit.visit_lit(inner),
         Expr::Paren(inner) => visit.visit_paren(inner),
+        Expr::Quantified(inner) => visit.visit_quantified(inner),
         Expr::Raw(inner) => visit.visit_raw(inner),
+        Expr::Subquery(inner) => visit.visit_subquery(inner),
+        Expr::Subscript(inner) => visit.visit_subscript(inner),
         Expr::Unary(inner) => visit.visit_unary(inner),
     }
 }
@@ -234,6 +374,40 @@ impl Parse for ExprRoot {
     }
 }
 
+impl ExprRoot {
+    /// Generates the expression of a `where` or `having` clause. Each condition of the top-level
+    /// `and` chain that refers to optional bind parameters is wrapped, so that it can be left out
+    /// of the SQL if one of them is `None`.
+    #[must_use]
+    pub fn to_condition_tokens(&self) -> TokenStream {
+        fn condition_tokens(expr: &Expr) -> TokenStream {
+            if let Expr::Binary(binary) = expr
+                && let BinOp::And(_) = binary.op
+            {
+                let lhs = condition_tokens(&binary.lhs);
+                let op = &binary.op;
+                let rhs = condition_tokens(&binary.rhs);
+                return quote! {
+                    ::kosame::repr::expr::Expr::Binary(::kosame::repr::expr::Binary::new(&#lhs, #op, &#rhs))
+                };
+            }
+
+            let optional_bind_params = expr.optional_bind_params();
+            if optional_bind_params.is_empty() {
+                return expr.to_token_stream();
+            }
+            quote! {
+                ::kosame::repr::expr::Expr::Optional(::kosame::repr::expr::Optional::new(
+                    &[#(params::#optional_bind_params::BIND_PARAM),*],
+                    &#expr,
+                ))
+            }
+        }
+
+        condition_tokens(&self.0)
+    }
+}
+
 impl ToTokens for ExprRoot {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         self.0.to_tokens(tokens);
@@ -0,0 +1,143 @@
+use crate::{
+    inferred_type::InferredType,
+    keyword,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+/// An array comparison such as the right-hand side of `id = any(:ids)`. Only valid directly after
+/// a comparison operator.
+pub struct Quantified {
+    pub quantifier: Quantifier,
+    pub paren_token: syn::token::Paren,
+    pub expr: Box<Expr>,
+}
+
+impl Quantified {
+    pub fn peek(input: ParseStream) -> bool {
+        Quantifier::peek(input) && input.peek2(syn::token::Paren)
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
+        None
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.quantifier
+            .span()
+            .join(self.paren_token.span.span())
+            .unwrap_or(self.quantifier.span())
+    }
+}
+
+pub fn visit_quantified<'a>(visit: &mut (impl Visit<'a> + ?Sized), quantified: &'a Quantified) {
+    visit.visit_expr(&quantified.expr);
+}
+
+impl Parse for Quantified {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            quantifier: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            expr: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Quantified {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let quantifier = &self.quantifier;
+        let expr = &self.expr;
+        quote! {
+            ::kosame::repr::expr::Quantified::new(#quantifier, &#expr)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Quantified {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.quantifier.pretty_print(printer);
+        self.paren_token
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                self.expr.pretty_print(printer);
+            });
+    }
+}
+
+pub enum Quantifier {
+    Any(keyword::any),
+    All(keyword::all),
+    Some(keyword::some),
+}
+
+impl Quantifier {
+    pub fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::any) || input.peek(keyword::all) || input.peek(keyword::some)
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Any(inner) => inner.span,
+            Self::All(inner) => inner.span,
+            Self::Some(inner) => inner.span,
+        }
+    }
+}
+
+impl Parse for Quantifier {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::any) {
+            Ok(Self::Any(input.parse()?))
+        } else if lookahead.peek(keyword::all) {
+            Ok(Self::All(input.parse()?))
+        } else if lookahead.peek(keyword::some) {
+            Ok(Self::Some(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for Quantifier {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Any(_) => quote! { ::kosame::repr::expr::Quantifier::Any },
+            Self::All(_) => quote! { ::kosame::repr::expr::Quantifier::All },
+            Self::Some(_) => quote! { ::kosame::repr::expr::Quantifier::Some },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Quantifier {
+    fn pretty_print(&self, printer: &mut Printer) {
+        match self {
+            Self::Any(inner) => inner.pretty_print(printer),
+            Self::All(inner) => inner.pretty_print(printer),
+            Self::Some(inner) => inner.pretty_print(printer),
+        }
+    }
+}
@@ -4,17 +4,26 @@ use crate::{
     scopes::ScopeId,
 };
 
-use super::Visit;
-use proc_macro2::{Span, TokenStream};
+use super::{Expr, Visit};
+use proc_macro2::{Group, Span, TokenStream, TokenTree};
 use quote::{ToTokens, quote};
 use syn::{
     Ident, LitStr, Token,
     parse::{Parse, ParseStream},
 };
 
+/// Raw SQL text like `$"ts_rank({posts.search}, plainto_tsquery({:q}))"`. The `{...}` segments
+/// are parsed as expressions, whereas the rest of the string is inserted verbatim. `{{` and `}}`
+/// are literal braces.
 pub struct Raw {
     pub dollar_token: Token![$],
     pub string: LitStr,
+    pub segments: Vec<RawSegment>,
+}
+
+pub enum RawSegment {
+    Text(String),
+    Expr(Box<Expr>),
 }
 
 impl Raw {
@@ -41,21 +50,111 @@ impl Raw {
     }
 }
 
-pub fn visit_raw<'a>(_visit: &mut (impl Visit<'a> + ?Sized), _raw: &'a Raw) {}
+pub fn visit_raw<'a>(visit: &mut (impl Visit<'a> + ?Sized), raw: &'a Raw) {
+    for segment in &raw.segments {
+        if let RawSegment::Expr(expr) = segment {
+            visit.visit_expr(expr);
+        }
+    }
+}
 
 impl Parse for Raw {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let dollar_token = input.parse()?;
+        let string: LitStr = input.parse()?;
+        let segments = parse_segments(&string)?;
         Ok(Self {
-            dollar_token: input.parse()?,
-            string: input.parse()?,
+            dollar_token,
+            string,
+            segments,
         })
     }
 }
 
+fn parse_segments(string: &LitStr) -> syn::Result<Vec<RawSegment>> {
+    let value = string.value();
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut chars = value.chars().peekable();
+    while let Some(char) = chars.next() {
+        match char {
+            '{' if chars.next_if_eq(&'{').is_some() => text.push('{'),
+            '}' if chars.next_if_eq(&'}').is_some() => text.push('}'),
+            '{' => {
+                let mut expr = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(char) => expr.push(char),
+                        None => {
+                            return Err(syn::Error::new(
+                                string.span(),
+                                "unterminated `{` in raw SQL, use `{{` for a literal brace",
+                            ));
+                        }
+                    }
+                }
+                if !text.is_empty() {
+                    segments.push(RawSegment::Text(std::mem::take(&mut text)));
+                }
+                segments.push(RawSegment::Expr(Box::new(parse_expr(
+                    &expr,
+                    string.span(),
+                )?)));
+            }
+            '}' => {
+                return Err(syn::Error::new(
+                    string.span(),
+                    "unmatched `}` in raw SQL, use `}}` for a literal brace",
+                ));
+            }
+            char => text.push(char),
+        }
+    }
+    if !text.is_empty() {
+        segments.push(RawSegment::Text(text));
+    }
+    Ok(segments)
+}
+
+/// Parses an interpolated expression, pointing all of its tokens at the string literal.
+fn parse_expr(expr: &str, span: Span) -> syn::Result<Expr> {
+    fn respan(tokens: TokenStream, span: Span) -> TokenStream {
+        tokens
+            .into_iter()
+            .map(|mut token| {
+                if let TokenTree::Group(group) = &token {
+                    let mut new_group = Group::new(group.delimiter(), respan(group.stream(), span));
+                    new_group.set_span(span);
+                    token = TokenTree::Group(new_group);
+                } else {
+                    token.set_span(span);
+                }
+                token
+            })
+            .collect()
+    }
+
+    // Braces in raw SQL that are meant literally, like in `'{1,2}'::int[]`, end up here.
+    let invalid = |error: &dyn std::fmt::Display| {
+        syn::Error::new(
+            span,
+            format!(
+                "invalid raw SQL expression: {error}, use `{{{{` and `}}}}` for literal braces"
+            ),
+        )
+    };
+    let tokens: TokenStream = expr.parse().map_err(|error| invalid(&error))?;
+    syn::parse2(respan(tokens, span)).map_err(|error| invalid(&error))
+}
+
 impl ToTokens for Raw {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let string = &self.string;
-        quote! { ::kosame::repr::expr::Raw::new(#string) }.to_tokens(tokens);
+        let segments = self.segments.iter().map(|segment| match segment {
+            RawSegment::Text(text) => quote! { ::kosame::repr::expr::RawSegment::Text(#text) },
+            RawSegment::Expr(expr) => quote! { ::kosame::repr::expr::RawSegment::Expr(#expr) },
+        });
+        quote! { ::kosame::repr::expr::Raw::new(&[#(#segments),*]) }.to_tokens(tokens);
     }
 }
 
@@ -65,3 +164,46 @@ impl PrettyPrint for Raw {
         self.string.pretty_print(printer);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(raw: &Raw) -> Vec<Option<&str>> {
+        raw.segments
+            .iter()
+            .map(|segment| match segment {
+                RawSegment::Text(text) => Some(text.as_str()),
+                RawSegment::Expr(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_escaped_braces() {
+        let raw = syn::parse_str::<Raw>(r#"$"'{{1,2}}'::int[]""#).unwrap();
+        assert_eq!(texts(&raw), [Some("'{1,2}'::int[]")]);
+    }
+
+    #[test]
+    fn test_escaped_braces_around_expr() {
+        let raw = syn::parse_str::<Raw>(r#"$"'{{\"a\": 1}}'::jsonb <@ {posts.data}""#).unwrap();
+        assert_eq!(texts(&raw), [Some(r#"'{"a": 1}'::jsonb <@ "#), None]);
+    }
+
+    #[test]
+    fn test_unescaped_braces() {
+        let error = syn::parse_str::<Raw>(r#"$"'{1,2}'::int[]""#).err().unwrap();
+        assert!(
+            error
+                .to_string()
+                .contains("use `{{` and `}}` for literal braces")
+        );
+    }
+
+    #[test]
+    fn test_unmatched_brace() {
+        let error = syn::parse_str::<Raw>(r#"$"'1}'""#).err().unwrap();
+        assert!(error.to_string().contains("use `}}` for a literal brace"));
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    command::Command,
+    inferred_type::InferredType,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    scopes::ScopeId,
+};
+
+use super::Visit;
+use proc_macro2::{Delimiter, Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, parenthesized,
+    parse::{Parse, ParseStream},
+    spanned::Spanned,
+};
+
+/// A scalar subquery like `(select max(id) from posts)`.
+pub struct Subquery {
+    pub paren_token: syn::token::Paren,
+    pub command: Box<Command>,
+}
+
+impl Subquery {
+    /// Checks whether the next token is a parenthesized command rather than a parenthesized
+    /// expression.
+    pub fn peek(input: ParseStream) -> bool {
+        input
+            .cursor()
+            .group(Delimiter::Parenthesis)
+            .and_then(|(content, ..)| content.ident())
+            .is_some_and(|(ident, _)| ident == "select" || ident == "with")
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        None
+    }
+
+    /// Infers the type of the single column returned by the subquery. The subquery might not
+    /// return any rows, so the type is always nullable.
+    #[must_use]
+    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let mut fields = self.command.fields()?.iter();
+        let field = fields.next()?;
+        if fields.next().is_some() {
+            return None;
+        }
+
+        let inferred_type = field.infer_type(self.command.fields_scope_id())?;
+        Some(InferredType::Nullable(Box::new(inferred_type)))
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.paren_token.span.span()
+    }
+}
+
+pub fn visit_subquery<'a>(visit: &mut (impl Visit<'a> + ?Sized), subquery: &'a Subquery) {
+    visit.visit_command(&subquery.command);
+}
+
+impl Parse for Subquery {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            paren_token: parenthesized!(content in input),
+            command: content.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Subquery {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let command = &self.command;
+        quote! {
+            ::kosame::repr::expr::Subquery::new(&#command)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Subquery {
+    fn pretty_print(&self, printer: &mut Printer) {
+        pretty_print_command(printer, &self.paren_token, &self.command);
+    }
+}
+
+/// Prints a parenthesized command that is part of an expression. The continuation indent of the
+/// surrounding expression is undone, so that the command is indented like a `from` subquery.
+pub(crate) fn pretty_print_command(
+    printer: &mut Printer,
+    paren_token: &syn::token::Paren,
+    command: &Command,
+) {
+    printer.scan_indent(-1);
+    paren_token.pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+        command.pretty_print(printer);
+    });
+    printer.scan_indent(1);
+}
@@ -0,0 +1,126 @@
+use crate::{
+    inferred_type::InferredType,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    quote_option::QuoteOption,
+    scopes::ScopeId,
+};
+
+use super::{Expr, Visit};
+use proc_macro2::{Span, TokenStream};
+use quote::{ToTokens, quote};
+use syn::{Ident, Token, bracketed, parse::ParseStream, spanned::Spanned};
+
+/// An array subscript like `tags[1]`, or a slice like `tags[2:3]` whose bounds may be omitted.
+pub struct Subscript {
+    pub expr: Box<Expr>,
+    pub bracket_token: syn::token::Bracket,
+    pub lower: Option<Box<Expr>>,
+    pub colon_token: Option<Token![:]>,
+    pub upper: Option<Box<Expr>>,
+}
+
+impl Subscript {
+    /// Parses the subscripts that follow `expr`, if any.
+    pub fn parse_postfix(mut expr: Expr, input: ParseStream) -> syn::Result<Expr> {
+        while input.peek(syn::token::Bracket) {
+            let content;
+            let bracket_token = bracketed!(content in input);
+            // A colon followed by an identifier starts a bind parameter rather than a slice
+            // without lower bound.
+            let lower = if content.peek(Token![:]) && !content.peek2(Ident) {
+                None
+            } else {
+                Some(Box::new(content.parse()?))
+            };
+            let colon_token: Option<Token![:]> = content.parse()?;
+            let upper = if colon_token.is_some() && !content.is_empty() {
+                Some(Box::new(content.parse()?))
+            } else {
+                None
+            };
+            if !content.is_empty() {
+                return Err(content.error("expected `]`"));
+            }
+            expr = Expr::Subscript(Self {
+                expr: Box::new(expr),
+                bracket_token,
+                lower,
+                colon_token,
+                upper,
+            });
+        }
+        Ok(expr)
+    }
+
+    #[must_use]
+    pub fn is_slice(&self) -> bool {
+        self.colon_token.is_some()
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn infer_name(&self) -> Option<&Ident> {
+        self.expr.infer_name()
+    }
+
+    /// A slice has the type of the array, whereas a single element is null if the subscript is
+    /// out of bounds.
+    #[must_use]
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        let array = self.expr.infer_type(scope_id)?;
+        if self.is_slice() {
+            Some(array)
+        } else {
+            Some(InferredType::Nullable(Box::new(InferredType::Element(
+                Box::new(array),
+            ))))
+        }
+    }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.expr
+            .span()
+            .join(self.bracket_token.span.span())
+            .unwrap_or(self.expr.span())
+    }
+}
+
+pub fn visit_subscript<'a>(visit: &mut (impl Visit<'a> + ?Sized), subscript: &'a Subscript) {
+    visit.visit_expr(&subscript.expr);
+    if let Some(lower) = &subscript.lower {
+        visit.visit_expr(lower);
+    }
+    if let Some(upper) = &subscript.upper {
+        visit.visit_expr(upper);
+    }
+}
+
+impl ToTokens for Subscript {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let expr = &self.expr;
+        let lower = QuoteOption(self.lower.as_ref().map(|lower| quote! { &#lower }));
+        let upper = QuoteOption(self.upper.as_ref().map(|upper| quote! { &#upper }));
+        let slice = self.is_slice();
+        quote! {
+            ::kosame::repr::expr::Subscript::new(&#expr, #lower, #upper, #slice)
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Subscript {
+    fn pretty_print(&self, printer: &mut Printer) {
+        self.expr.pretty_print(printer);
+        self.bracket_token
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                if let Some(lower) = &self.lower {
+                    lower.pretty_print(printer);
+                }
+                self.colon_token.pretty_print(printer);
+                if let Some(upper) = &self.upper {
+                    upper.pretty_print(printer);
+                }
+            });
+    }
+}
@@ -1,8 +1,9 @@
-use proc_macro2::TokenStream;
+use proc_macro2::{Span, TokenStream};
 use quote::{ToTokens, quote};
 use syn::{
-    Ident,
+    Ident, Token,
     parse::{Parse, ParseStream},
+    parse_quote,
 };
 
 use crate::{
@@ -34,8 +35,22 @@ impl Unary {
     }
 
     #[must_use]
-    pub fn infer_type(&self, _scope_id: ScopeId) -> Option<InferredType<'_>> {
-        None
+    pub fn infer_type(&self, scope_id: ScopeId) -> Option<InferredType<'_>> {
+        match self.op {
+            UnOp::Not(_) => {
+                InferredType::derived(parse_quote!(bool), [self.operand.infer_type(scope_id)])
+            }
+            UnOp::Plus(_) | UnOp::Minus(_) => self.operand.infer_type(scope_id),
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub fn span(&self) -> Span {
+        self.op
+            .span()
+            .join(self.operand.span())
+            .unwrap_or(self.op.span())
     }
 }
 
@@ -57,13 +72,17 @@ impl ToTokens for Unary {
 impl PrettyPrint for Unary {
     fn pretty_print(&self, printer: &mut Printer) {
         self.op.pretty_print(printer);
-        " ".pretty_print(printer);
+        if let UnOp::Not(_) = self.op {
+            " ".pretty_print(printer);
+        }
         self.operand.pretty_print(printer);
     }
 }
 
 #[allow(unused)]
 pub enum UnOp {
+    Plus(Token![+]),
+    Minus(Token![-]),
     Not(keyword::not),
 }
 
@@ -76,15 +95,29 @@ impl UnOp {
     pub fn precedence(&self) -> u32 {
         // Taken from https://www.postgresql.org/docs/18/sql-syntax-lexical.html#SQL-PRECEDENCE
         match self {
+            Self::Plus(_) | Self::Minus(_) => 12,
             Self::Not(_) => 3,
         }
     }
+
+    #[must_use]
+    pub fn span(&self) -> Span {
+        match self {
+            Self::Plus(inner) => inner.span,
+            Self::Minus(inner) => inner.span,
+            Self::Not(inner) => inner.span,
+        }
+    }
 }
 
 impl Parse for UnOp {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let lookahead = input.lookahead1();
-        if lookahead.peek(keyword::not) {
+        if lookahead.peek(Token![+]) {
+            return Ok(Self::Plus(input.parse()?));
+        } else if lookahead.peek(Token![-]) {
+            return Ok(Self::Minus(input.parse()?));
+        } else if lookahead.peek(keyword::not) {
             return Ok(Self::Not(input.parse()?));
         }
 
@@ -102,13 +135,15 @@ impl ToTokens for UnOp {
             };
         }
 
-        branches!(Not);
+        branches!(Plus Minus Not);
     }
 }
 
 impl PrettyPrint for UnOp {
     fn pretty_print(&self, printer: &mut Printer) {
         match self {
+            Self::Plus(inner) => inner.pretty_print(printer),
+            Self::Minus(inner) => inner.pretty_print(printer),
             Self::Not(inner) => inner.pretty_print(printer),
         }
     }
@@ -0,0 +1,139 @@
+use crate::{
+    clause::SelectCore,
+    command::Command,
+    correlations::Correlations,
+    expr::{self, Call, Expr, Lit},
+    inferred_type::resolve_not_null_type,
+    path_ext::PathExt,
+    query::{Node, Query},
+    scopes::{ScopeId, Scopes},
+    statement::Statement,
+    visit::{self, Visit},
+};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Type, parse_quote};
+
+struct FunctionCallsBuilder<'a> {
+    calls: Vec<(&'a Call, ScopeId)>,
+    scope_ids: Vec<ScopeId>,
+}
+
+impl FunctionCallsBuilder<'_> {
+    fn scoped(&mut self, scope_id: ScopeId, f: impl FnOnce(&mut Self)) {
+        self.scope_ids.push(scope_id);
+        f(self);
+        self.scope_ids.pop();
+    }
+}
+
+impl<'a> Visit<'a> for FunctionCallsBuilder<'a> {
+    fn visit_call(&mut self, call: &'a Call) {
+        if call.function.get_ident().is_none()
+            && let Some(scope_id) = self.scope_ids.last().copied()
+        {
+            self.calls.push((call, scope_id));
+        }
+        expr::visit_call(self, call);
+    }
+
+    fn visit_command(&mut self, command: &'a Command) {
+        self.scoped(command.scope_id, |this| visit::visit_command(this, command));
+    }
+
+    fn visit_select_core(&mut self, select_core: &'a SelectCore) {
+        self.scoped(select_core.scope_id, |this| {
+            visit::visit_select_core(this, select_core);
+        });
+    }
+
+    fn visit_node(&mut self, node: &'a Node) {
+        self.scoped(node.scope_id, |this| visit::visit_node(this, node));
+    }
+}
+
+/// The calls of functions declared with `function!`, whose arguments are checked against the
+/// declaration at compile time. Only the declaring macro knows the argument types, so the check
+/// is left to the trait solver.
+pub struct FunctionCalls {
+    checks: Vec<TokenStream>,
+}
+
+impl FunctionCalls {
+    #[must_use]
+    pub fn from_statement(
+        statement: &Statement,
+        correlations: &Correlations<'_>,
+        scopes: &Scopes<'_>,
+    ) -> Self {
+        let mut builder = FunctionCallsBuilder {
+            calls: Vec::new(),
+            scope_ids: Vec::new(),
+        };
+        builder.visit_statement(statement);
+        Self::new(&builder, correlations, scopes)
+    }
+
+    #[must_use]
+    pub fn from_query(query: &Query, correlations: &Correlations<'_>, scopes: &Scopes<'_>) -> Self {
+        let mut builder = FunctionCallsBuilder {
+            calls: Vec::new(),
+            scope_ids: Vec::new(),
+        };
+        builder.visit_node(&query.body);
+        Self::new(&builder, correlations, scopes)
+    }
+
+    fn new(
+        builder: &FunctionCallsBuilder<'_>,
+        correlations: &Correlations<'_>,
+        scopes: &Scopes<'_>,
+    ) -> Self {
+        let checks = builder
+            .calls
+            .iter()
+            .map(|(call, scope_id)| {
+                // The error points at the call if all tokens of the path share its span.
+                let function = call.function.to_call_site(1);
+                let function = function.to_token_stream().into_iter().map(|mut token| {
+                    token.set_span(call.span());
+                    token
+                });
+                let function = TokenStream::from_iter(function);
+                let args = call.params.iter().map(|param| -> Type {
+                    // String literals are of an unknown type in PostgreSQL until they are
+                    // passed to an argument.
+                    if let Expr::Lit(Lit::Str(_)) = param {
+                        return parse_quote!(::kosame::infer::Unknown);
+                    }
+                    param
+                        .infer_type(*scope_id)
+                        .and_then(|inferred_type| {
+                            resolve_not_null_type(correlations, scopes, inferred_type)
+                        })
+                        .unwrap_or_else(|| parse_quote!(::kosame::infer::Unknown))
+                });
+                quote_spanned! {call.span()=>
+                    let _ = check_arguments::<#function::Arguments, (#(#args,)*), _>;
+                }
+            })
+            .collect();
+        Self { checks }
+    }
+}
+
+impl ToTokens for FunctionCalls {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if self.checks.is_empty() {
+            return;
+        }
+        let checks = &self.checks;
+        quote! {
+            const _: () = {
+                const fn check_arguments<F: ::kosame::infer::Arguments<A, M>, A, M>() {}
+                #(#checks)*
+            };
+        }
+        .to_tokens(tokens);
+    }
+}
@@ -1,4 +1,6 @@
-use syn::{Ident, Path, parse_quote};
+use proc_macro2::Span;
+use quote::format_ident;
+use syn::{GenericArgument, Ident, Path, PathArguments, Type, parse_quote};
 
 use crate::{
     correlations::{CorrelationId, Correlations},
@@ -10,6 +12,30 @@ use crate::{
 #[derive(Debug)]
 pub enum InferredType<'a> {
     RustType(&'a Path),
+    /// A type that follows from the expression itself, like `bool` for a comparison. It is
+    /// nullable if any of the operands is nullable.
+    Derived {
+        rust_type: Box<Type>,
+        operands: Vec<InferredType<'a>>,
+    },
+    /// The result of an arithmetic operator, following the numeric type promotion of PostgreSQL.
+    Arithmetic {
+        lhs: Box<InferredType<'a>>,
+        rhs: Box<InferredType<'a>>,
+    },
+    /// The result of the `||` operator, which concatenates strings and arrays.
+    Concat {
+        lhs: Box<InferredType<'a>>,
+        rhs: Box<InferredType<'a>>,
+    },
+    /// An array of the common type of the elements, which are nullable if any of them is
+    /// nullable, as for `array[...]`. The array itself is never null.
+    Array(Vec<InferredType<'a>>),
+    /// The common type of the branches of a `case` expression, which is nullable if any of them
+    /// is nullable.
+    Case(Vec<InferredType<'a>>),
+    /// The element type of an array, e.g. for `unnest(...)`.
+    Element(Box<InferredType<'a>>),
     Scope {
         scope_id: ScopeId,
         table: Option<&'a Ident>,
@@ -24,6 +50,52 @@ pub enum InferredType<'a> {
         table_path: &'a TablePath,
         column: &'a Ident,
     },
+    /// The column at the given position of a table, e.g. for an `insert` without a column list.
+    TableColumnAt {
+        table_path: &'a TablePath,
+        index: usize,
+    },
+    /// Makes the inner type nullable, e.g. for a `case` expression without `else`.
+    Nullable(Box<InferredType<'a>>),
+    /// The common type of the operands, which is only nullable if all of them are nullable, as
+    /// for `coalesce`.
+    Coalesce(Vec<InferredType<'a>>),
+    /// The `Output` of a trait in `kosame::infer` for the type of the operand, e.g. `Sum` for
+    /// `sum(...)`. It is nullable if the operand is nullable.
+    Projection {
+        trait_name: &'static str,
+        operand: Box<InferredType<'a>>,
+    },
+    /// The return type of a function declared with the `function!` macro. It is nullable if any
+    /// of the arguments is nullable or if the function is not `strict`.
+    Function {
+        path: &'a Path,
+        args: Vec<InferredType<'a>>,
+    },
+    /// The declared type of an argument of a function declared with the `function!` macro, e.g.
+    /// for a bind parameter passed to it. It is not null.
+    FunctionArg {
+        path: &'a Path,
+        index: usize,
+    },
+    /// Makes the inner type not null, e.g. for a bind parameter that takes the common type of
+    /// the other arguments of `coalesce`.
+    NotNull(Box<InferredType<'a>>),
+}
+
+impl<'a> InferredType<'a> {
+    /// Creates a [`InferredType::Derived`] type, unless the type of one of the operands is
+    /// unknown, which leaves its nullability unknown as well.
+    #[must_use]
+    pub fn derived(
+        rust_type: Type,
+        operands: impl IntoIterator<Item = Option<InferredType<'a>>>,
+    ) -> Option<Self> {
+        Some(Self::Derived {
+            rust_type: Box::new(rust_type),
+            operands: operands.into_iter().collect::<Option<_>>()?,
+        })
+    }
 }
 
 #[must_use]
@@ -32,18 +104,151 @@ pub fn resolve_type(
     scopes: &Scopes<'_>,
     correlation_id: CorrelationId,
     column: &Ident,
-) -> Option<Path> {
-    let mut inferred_type = correlations.infer_type(correlation_id, column)?;
+) -> Option<Type> {
+    let inferred_type = correlations.infer_type(correlation_id, column)?;
+    resolve(correlations, scopes, inferred_type).map(Resolved::into_type)
+}
+
+/// Resolves the type of an expression without its nullability, e.g. for a bind parameter.
+#[must_use]
+pub fn resolve_not_null_type(
+    correlations: &Correlations<'_>,
+    scopes: &Scopes<'_>,
+    inferred_type: InferredType<'_>,
+) -> Option<Type> {
+    resolve(correlations, scopes, inferred_type).map(|resolved| resolved.rust_type)
+}
+
+#[allow(clippy::too_many_lines)]
+fn resolve<'a>(
+    correlations: &'a Correlations<'a>,
+    scopes: &Scopes<'_>,
+    mut inferred_type: InferredType<'a>,
+) -> Option<Resolved> {
     let mut combined_nullable = false;
     for _ in 0..1024 {
-        match inferred_type {
-            InferredType::RustType(rust_type) => return Some(rust_type.clone()),
+        let resolved = match inferred_type {
+            InferredType::RustType(rust_type) => Resolved::from_rust_type(rust_type),
+            InferredType::Derived {
+                rust_type,
+                operands,
+            } => {
+                let mut nullability = Nullability::NotNull;
+                for operand in operands {
+                    let operand = resolve(correlations, scopes, operand)?;
+                    nullability = nullability.or(operand.nullability);
+                }
+                Resolved {
+                    rust_type: *rust_type,
+                    nullability,
+                    column: None,
+                }
+            }
+            InferredType::Arithmetic { lhs, rhs } => {
+                let lhs = resolve(correlations, scopes, *lhs)?;
+                let rhs = resolve(correlations, scopes, *rhs)?;
+                Resolved {
+                    rust_type: promote("Arithmetic", lhs.rust_type, &rhs.rust_type),
+                    nullability: lhs.nullability.or(rhs.nullability),
+                    column: None,
+                }
+            }
+            InferredType::Concat { lhs, rhs } => {
+                let lhs = resolve(correlations, scopes, *lhs)?;
+                let rhs = resolve(correlations, scopes, *rhs)?;
+                Resolved {
+                    rust_type: promote("Concat", lhs.rust_type, &rhs.rust_type),
+                    nullability: lhs.nullability.or(rhs.nullability),
+                    column: None,
+                }
+            }
+            InferredType::Array(elements) => {
+                let element = resolve_common(correlations, scopes, elements)?.into_type();
+                Resolved {
+                    rust_type: parse_quote!(::std::vec::Vec<#element>),
+                    nullability: Nullability::NotNull,
+                    column: None,
+                }
+            }
+            InferredType::Case(branches) => resolve_common(correlations, scopes, branches)?,
+            InferredType::Element(array) => {
+                let array = resolve(correlations, scopes, *array)?;
+                if let Some(column) = array.column {
+                    Resolved {
+                        rust_type: parse_quote!(#column::ElementNotNull),
+                        nullability: Nullability::Deferred(vec![
+                            parse_quote!(#column::ElementNullability),
+                        ]),
+                        column: None,
+                    }
+                } else {
+                    Resolved::element(&array.rust_type)
+                }
+            }
+            InferredType::Coalesce(operands) => {
+                let mut operands = operands.into_iter();
+                let first = resolve(correlations, scopes, operands.next()?)?;
+                let mut rust_type = first.rust_type;
+                let mut nullability = first.nullability;
+                for operand in operands {
+                    let operand = resolve(correlations, scopes, operand)?;
+                    rust_type = promote("Common", rust_type, &operand.rust_type);
+                    nullability = nullability.and(operand.nullability);
+                }
+                Resolved {
+                    rust_type,
+                    nullability,
+                    column: None,
+                }
+            }
+            InferredType::Projection {
+                trait_name,
+                operand,
+            } => {
+                let operand = resolve(correlations, scopes, *operand)?;
+                let operand_type = &operand.rust_type;
+                let trait_name = Ident::new(trait_name, Span::call_site());
+                Resolved {
+                    rust_type: parse_quote! {
+                        <#operand_type as ::kosame::infer::#trait_name>::Output
+                    },
+                    nullability: operand.nullability,
+                    column: None,
+                }
+            }
+            InferredType::Function { path, args } => {
+                let path = path.to_call_site(1);
+                let mut nullability = Nullability::Deferred(vec![parse_quote!(#path::Nullability)]);
+                for arg in args {
+                    let arg = resolve(correlations, scopes, arg)?;
+                    nullability = nullability.or(arg.nullability);
+                }
+                Resolved {
+                    rust_type: parse_quote!(#path::TypeNotNull),
+                    nullability,
+                    column: None,
+                }
+            }
+            InferredType::FunctionArg { path, index } => {
+                let path = path.to_call_site(1);
+                let arg = format_ident!("A{index}");
+                Resolved {
+                    rust_type: parse_quote!(#path::arguments::#arg),
+                    nullability: Nullability::NotNull,
+                    column: None,
+                }
+            }
+            InferredType::NotNull(inner) => Resolved {
+                nullability: Nullability::NotNull,
+                ..resolve(correlations, scopes, *inner)?
+            },
             InferredType::Scope {
                 scope_id,
                 table,
                 column,
             } => {
                 inferred_type = scopes.infer_type(scope_id, table, column)?;
+                continue;
             }
             InferredType::Correlation {
                 correlation_id,
@@ -52,17 +257,200 @@ pub fn resolve_type(
             } => {
                 combined_nullable = combined_nullable || nullable;
                 inferred_type = correlations.infer_type(correlation_id, column)?;
+                continue;
+            }
+            InferredType::Nullable(inner) => {
+                combined_nullable = true;
+                inferred_type = *inner;
+                continue;
             }
             InferredType::TableColumn { table_path, column } => {
                 let table_path = table_path.as_path().to_call_site(1);
-                if combined_nullable {
-                    return Some(
-                        parse_quote!(::core::option::Option<#table_path::columns::#column::TypeNotNull>),
-                    );
+                let column: Path = parse_quote!(#table_path::columns::#column);
+                Resolved {
+                    rust_type: parse_quote!(#column::TypeNotNull),
+                    nullability: Nullability::Deferred(vec![parse_quote!(#column::Nullability)]),
+                    column: Some(column),
+                }
+            }
+            InferredType::TableColumnAt { table_path, index } => {
+                let table_path = table_path.as_path().to_call_site(1);
+                let column = format_ident!("column_{index}");
+                let column: Path = parse_quote!(#table_path::columns_by_index::#column);
+                Resolved {
+                    rust_type: parse_quote!(#column::TypeNotNull),
+                    nullability: Nullability::Deferred(vec![parse_quote!(#column::Nullability)]),
+                    column: Some(column),
                 }
-                return Some(parse_quote!(#table_path::columns::#column::Type));
             }
+        };
+
+        if combined_nullable {
+            return Some(Resolved {
+                rust_type: resolved.rust_type,
+                nullability: Nullability::Nullable,
+                column: resolved.column,
+            });
         }
+        return Some(resolved);
     }
     panic!("infinite type inference loop detected");
 }
+
+/// Resolves the common type of the operands, which is nullable if any of them is nullable.
+fn resolve_common<'a>(
+    correlations: &'a Correlations<'a>,
+    scopes: &Scopes<'_>,
+    operands: Vec<InferredType<'a>>,
+) -> Option<Resolved> {
+    let mut operands = operands.into_iter();
+    let first = resolve(correlations, scopes, operands.next()?)?;
+    let mut rust_type = first.rust_type;
+    let mut nullability = first.nullability;
+    for operand in operands {
+        let operand = resolve(correlations, scopes, operand)?;
+        rust_type = promote("Common", rust_type, &operand.rust_type);
+        nullability = nullability.or(operand.nullability);
+    }
+    Some(Resolved {
+        rust_type,
+        nullability,
+        column: None,
+    })
+}
+
+/// A resolved type, split into the type without nullability and the nullability itself.
+struct Resolved {
+    rust_type: Type,
+    nullability: Nullability,
+    /// The module of the table column if this is exactly the type of that column, apart from its
+    /// nullability.
+    column: Option<Path>,
+}
+
+impl Resolved {
+    fn from_rust_type(rust_type: &Path) -> Self {
+        // Type overrides are taken as written, so an `Option` is the only way to tell that they
+        // are nullable.
+        if let Some(segment) = rust_type.segments.last()
+            && segment.ident == "Option"
+            && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+            && let Some(GenericArgument::Type(inner)) = arguments.args.first()
+            && arguments.args.len() == 1
+        {
+            return Self {
+                rust_type: inner.clone(),
+                nullability: Nullability::Nullable,
+                column: None,
+            };
+        }
+
+        Self {
+            rust_type: parse_quote!(#rust_type),
+            nullability: Nullability::NotNull,
+            column: None,
+        }
+    }
+
+    /// The element of an array type that is not a table column, whose element type is declared
+    /// by the table. Arrays built by Kosame are known to be `Vec`s, whereas the element type of
+    /// other types like function results is left to `kosame::infer::Element`.
+    fn element(rust_type: &Type) -> Self {
+        if let Type::Path(type_path) = rust_type
+            && type_path.qself.is_none()
+            && let Some(segment) = type_path.path.segments.last()
+            && segment.ident == "Vec"
+            && let PathArguments::AngleBracketed(arguments) = &segment.arguments
+            && let Some(GenericArgument::Type(Type::Path(element))) = arguments.args.first()
+            && element.qself.is_none()
+        {
+            return Self::from_rust_type(&element.path);
+        }
+
+        Self {
+            rust_type: parse_quote!(<#rust_type as ::kosame::infer::Element>::Output),
+            nullability: Nullability::NotNull,
+            column: None,
+        }
+    }
+
+    fn into_type(self) -> Type {
+        let rust_type = self.rust_type;
+        match self.nullability {
+            Nullability::NotNull => rust_type,
+            Nullability::Nullable => parse_quote!(::core::option::Option<#rust_type>),
+            Nullability::Deferred(nullabilities) => {
+                if let Some(column) = self.column {
+                    return parse_quote!(#column::Type);
+                }
+
+                let nullability = Nullability::combine(nullabilities);
+                parse_quote!(<#nullability as ::kosame::infer::Nullability>::Apply<#rust_type>)
+            }
+        }
+    }
+}
+
+/// The type that two types are promoted to by a trait in `kosame::infer`, e.g. `Arithmetic`.
+/// Identical types are kept as they are to keep the generated types readable.
+fn promote(trait_name: &str, lhs: Type, rhs: &Type) -> Type {
+    if lhs == *rhs {
+        lhs
+    } else {
+        let trait_name = Ident::new(trait_name, Span::call_site());
+        parse_quote!(<#lhs as ::kosame::infer::#trait_name<#rhs>>::Output)
+    }
+}
+
+enum Nullability {
+    NotNull,
+    Nullable,
+    /// Nullable if any of the `kosame::infer::Nullability` types is nullable. These come from
+    /// table columns and declared functions, whose nullability only their own macro knows.
+    Deferred(Vec<Type>),
+}
+
+impl Nullability {
+    fn or(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Nullable, _) | (_, Self::Nullable) => Self::Nullable,
+            (Self::NotNull, other) | (other, Self::NotNull) => other,
+            (Self::Deferred(mut nullabilities), Self::Deferred(other)) => {
+                for nullability in other {
+                    if !nullabilities.contains(&nullability) {
+                        nullabilities.push(nullability);
+                    }
+                }
+                Self::Deferred(nullabilities)
+            }
+        }
+    }
+
+    fn and(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::NotNull, _) | (_, Self::NotNull) => Self::NotNull,
+            (Self::Nullable, other) | (other, Self::Nullable) => other,
+            (Self::Deferred(lhs), Self::Deferred(rhs)) => {
+                let lhs = Self::combine(lhs);
+                let rhs = Self::combine(rhs);
+                Self::Deferred(vec![parse_quote! {
+                    <#lhs as ::kosame::infer::Nullability>::And<#rhs>
+                }])
+            }
+        }
+    }
+
+    /// Combines deferred nullabilities into a single type.
+    fn combine(nullabilities: Vec<Type>) -> Type {
+        let mut nullabilities = nullabilities.into_iter();
+        let mut result = nullabilities
+            .next()
+            .expect("deferred nullabilities cannot be empty");
+        for nullability in nullabilities {
+            result = parse_quote! {
+                <#result as ::kosame::infer::Nullability>::Or<#nullability>
+            };
+        }
+        result
+    }
+}
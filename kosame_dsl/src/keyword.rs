@@ -59,24 +59,43 @@ macro_rules! keyword_group {
     };
 }
 
+custom_keyword!(after);
 custom_keyword!(all);
 custom_keyword!(and);
+custom_keyword!(any);
+custom_keyword!(array);
 custom_keyword!(asc);
+custom_keyword!(between);
 custom_keyword!(by);
+custom_keyword!(case);
 custom_keyword!(cast);
+custom_keyword!(collate);
+custom_keyword!(conflict);
+custom_keyword!(constraint);
 custom_keyword!(create);
 custom_keyword!(cross);
+custom_keyword!(current);
 custom_keyword!(default);
 custom_keyword!(delete);
+custom_keyword!(depth);
 custom_keyword!(desc);
 custom_keyword!(distinct);
 custom_keyword!(driver);
+custom_keyword!(elements_not_null);
+custom_keyword!(end);
 custom_keyword!(except);
+custom_keyword!(exists);
+custom_keyword!(extract);
+custom_keyword!(filter);
 custom_keyword!(first);
+custom_keyword!(following);
 custom_keyword!(from);
 custom_keyword!(full);
+custom_keyword!(function);
 custom_keyword!(group);
+custom_keyword!(groups);
 custom_keyword!(having);
+custom_keyword!(ilike);
 custom_keyword!(inner);
 custom_keyword!(insert);
 custom_keyword!(intersect);
@@ -88,33 +107,54 @@ custom_keyword!(kosame);
 custom_keyword!(last);
 custom_keyword!(lateral);
 custom_keyword!(left);
+custom_keyword!(like);
 custom_keyword!(limit);
 custom_keyword!(natural);
 custom_keyword!(not);
+custom_keyword!(nothing);
 custom_keyword!(null);
 custom_keyword!(nulls);
 custom_keyword!(offset);
 custom_keyword!(on);
 custom_keyword!(or);
 custom_keyword!(order);
+custom_keyword!(over);
+custom_keyword!(partition);
 custom_keyword!(__pass);
+custom_keyword!(preceding);
 custom_keyword!(primary);
+custom_keyword!(range);
+custom_keyword!(recursive);
 custom_keyword!(references);
 custom_keyword!(rename);
 custom_keyword!(returning);
+custom_keyword!(returns);
 custom_keyword!(right);
+custom_keyword!(row);
+custom_keyword!(rows);
 custom_keyword!(select);
 custom_keyword!(set);
+custom_keyword!(some);
+custom_keyword!(strict);
 custom_keyword!(__table);
 custom_keyword!(table);
+custom_keyword!(then);
 custom_keyword!(ty);
+custom_keyword!(unbounded);
 custom_keyword!(union);
 custom_keyword!(update);
 custom_keyword!(using);
 custom_keyword!(values);
+custom_keyword!(when);
+custom_keyword!(window);
 custom_keyword!(with);
 
-keyword_group!(group_attribute { driver, rename, ty });
+keyword_group!(group_attribute {
+    driver,
+    elements_not_null,
+    rename,
+    ty
+});
 keyword_group!(group_column_constraint {
     not,
     default,
@@ -127,6 +167,17 @@ keyword_group!(group_command {
     update,
     delete
 });
+keyword_group!(group_conflict_action { nothing, update });
+keyword_group!(group_frame_direction {
+    preceding,
+    following
+});
+keyword_group!(group_insert_source {
+    values,
+    default,
+    select,
+    with
+});
 keyword_group!(group_join {
     left,
     right,
@@ -7,6 +7,7 @@ pub mod data_type;
 pub mod doc;
 pub mod driver;
 pub mod expr;
+pub mod function_calls;
 pub mod inferred_type;
 pub mod keyword;
 pub mod parse_option;
@@ -0,0 +1,107 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    expr::ExprRoot,
+    keyword,
+    parse_option::ParseOption,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    quote_option::QuoteOption,
+    visit::Visit,
+};
+
+pub struct Distinct {
+    pub distinct_keyword: keyword::distinct,
+    pub on: Option<DistinctOn>,
+}
+
+impl ParseOption for Distinct {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::distinct)
+    }
+}
+
+pub fn visit_distinct<'a>(visit: &mut (impl Visit<'a> + ?Sized), distinct: &'a Distinct) {
+    if let Some(on) = &distinct.on {
+        for expr in &on.exprs {
+            visit.visit_expr_root(expr);
+        }
+    }
+}
+
+impl Parse for Distinct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            distinct_keyword: input.call(keyword::distinct::parse_autocomplete)?,
+            on: input.call(DistinctOn::parse_option)?,
+        })
+    }
+}
+
+impl ToTokens for Distinct {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let on = QuoteOption(self.on.as_ref().map(|on| {
+            let exprs = on.exprs.iter();
+            quote! { &[#(#exprs),*] }
+        }));
+        quote! { ::kosame::repr::part::Distinct::new(#on) }.to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Distinct {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.distinct_keyword.pretty_print(printer);
+        if let Some(on) = &self.on {
+            " ".pretty_print(printer);
+            on.pretty_print(printer);
+        }
+    }
+}
+
+pub struct DistinctOn {
+    pub on_keyword: keyword::on,
+    pub paren_token: syn::token::Paren,
+    pub exprs: Punctuated<ExprRoot, Token![,]>,
+}
+
+impl ParseOption for DistinctOn {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::on) && input.peek2(syn::token::Paren)
+    }
+}
+
+impl Parse for DistinctOn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            on_keyword: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            exprs: content.parse_terminated(ExprRoot::parse, Token![,])?,
+        };
+
+        if result.exprs.is_empty() {
+            return Err(syn::Error::new(
+                result.paren_token.span.join(),
+                "`distinct on` requires at least one expression",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+impl PrettyPrint for DistinctOn {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.on_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.paren_token
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                self.exprs.pretty_print(printer);
+            });
+    }
+}
@@ -1,15 +1,19 @@
 mod alias;
 mod column_list;
+mod distinct;
 mod set_op;
 mod table_alias;
 mod table_path;
 mod target_table;
 mod type_override;
+mod window_spec;
 
 pub use alias::*;
 pub use column_list::*;
+pub use distinct::*;
 pub use set_op::*;
 pub use table_alias::*;
 pub use table_path::*;
 pub use target_table::*;
 pub use type_override::*;
+pub use window_spec::*;
@@ -6,6 +6,7 @@ use syn::{
 };
 
 use crate::{
+    correlations::CorrelationId,
     parse_option::ParseOption,
     part::{Alias, TablePath},
     path_ext::PathExt,
@@ -17,6 +18,7 @@ use crate::{
 pub struct TargetTable {
     pub table: TablePath,
     pub alias: Option<Alias>,
+    pub correlation_id: CorrelationId,
 }
 
 impl TargetTable {
@@ -49,6 +51,7 @@ impl Parse for TargetTable {
         Ok(Self {
             table: input.parse()?,
             alias: input.call(Alias::parse_option)?,
+            correlation_id: CorrelationId::new(),
         })
     }
 }
@@ -67,9 +70,6 @@ impl ToTokens for TargetTable {
 impl PrettyPrint for TargetTable {
     fn pretty_print(&self, printer: &mut Printer<'_>) {
         self.table.pretty_print(printer);
-        if let Some(alias) = &self.alias {
-            " ".pretty_print(printer);
-            alias.pretty_print(printer);
-        }
+        self.alias.pretty_print(printer);
     }
 }
@@ -0,0 +1,445 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    clause::OrderBy,
+    expr::ExprRoot,
+    keyword,
+    parse_option::ParseOption,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    quote_option::QuoteOption,
+    visit::Visit,
+};
+
+/// The window of a window function call, e.g. `over w` or `over (partition by post_id)`.
+pub struct Over {
+    pub over_keyword: keyword::over,
+    pub window: OverWindow,
+}
+
+#[allow(clippy::large_enum_variant)]
+pub enum OverWindow {
+    Name(Ident),
+    Spec {
+        paren_token: syn::token::Paren,
+        spec: WindowSpec,
+    },
+}
+
+impl ParseOption for Over {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::over)
+    }
+}
+
+impl Parse for Over {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            over_keyword: input.call(keyword::over::parse_autocomplete)?,
+            window: if input.peek(syn::token::Paren) {
+                let content;
+                OverWindow::Spec {
+                    paren_token: parenthesized!(content in input),
+                    spec: content.parse()?,
+                }
+            } else {
+                OverWindow::Name(input.parse()?)
+            },
+        })
+    }
+}
+
+impl ToTokens for Over {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match &self.window {
+            OverWindow::Name(name) => {
+                let name = name.to_string();
+                quote! { ::kosame::repr::part::Over::Window(#name) }
+            }
+            OverWindow::Spec { spec, .. } => quote! { ::kosame::repr::part::Over::Spec(#spec) },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Over {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.over_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        match &self.window {
+            OverWindow::Name(name) => name.pretty_print(printer),
+            OverWindow::Spec { paren_token, spec } => {
+                paren_token.pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                    spec.pretty_print(printer);
+                });
+            }
+        }
+    }
+}
+
+/// A window definition like `partition by post_id order by upvotes desc`, inside the
+/// parentheses of `over (...)` or `window w as (...)`.
+pub struct WindowSpec {
+    /// The name of a window defined in the `window` clause that this definition extends.
+    pub existing: Option<Ident>,
+    pub partition_by: Option<PartitionBy>,
+    pub order_by: Option<OrderBy>,
+    pub frame: Option<Frame>,
+}
+
+pub fn visit_window_spec<'a>(visit: &mut (impl Visit<'a> + ?Sized), window_spec: &'a WindowSpec) {
+    if let Some(partition_by) = &window_spec.partition_by {
+        for expr in &partition_by.exprs {
+            visit.visit_expr_root(expr);
+        }
+    }
+    if let Some(order_by) = &window_spec.order_by {
+        visit.visit_order_by(order_by);
+    }
+    if let Some(frame) = &window_spec.frame {
+        for bound in std::iter::once(&frame.start).chain(frame.end.as_ref().map(|end| &end.1)) {
+            if let FrameBound::Preceding(expr, _) | FrameBound::Following(expr, _) = bound {
+                visit.visit_expr_root(expr);
+            }
+        }
+    }
+}
+
+impl Parse for WindowSpec {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            existing: if input.peek(Ident)
+                && !PartitionBy::peek(input)
+                && !OrderBy::peek(input)
+                && !Frame::peek(input)
+            {
+                Some(input.parse()?)
+            } else {
+                None
+            },
+            partition_by: input.call(PartitionBy::parse_option)?,
+            order_by: input.call(OrderBy::parse_option)?,
+            frame: input.call(Frame::parse_option)?,
+        })
+    }
+}
+
+impl ToTokens for WindowSpec {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let existing = QuoteOption(self.existing.as_ref().map(Ident::to_string));
+        let partition_by = self
+            .partition_by
+            .iter()
+            .flat_map(|partition_by| partition_by.exprs.iter());
+        let order_by = self
+            .order_by
+            .iter()
+            .flat_map(|order_by| order_by.items.iter());
+        let frame = QuoteOption::from(&self.frame);
+        quote! {
+            ::kosame::repr::part::WindowSpec::new(
+                #existing,
+                &[#(#partition_by),*],
+                &[#(#order_by),*],
+                #frame,
+            )
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for WindowSpec {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        let mut first = true;
+        let mut separate = |printer: &mut Printer<'_>| {
+            if !first {
+                printer.scan_break();
+                " ".pretty_print(printer);
+            }
+            first = false;
+        };
+
+        if let Some(existing) = &self.existing {
+            separate(printer);
+            existing.pretty_print(printer);
+        }
+        if let Some(partition_by) = &self.partition_by {
+            separate(printer);
+            partition_by.pretty_print(printer);
+        }
+        if let Some(order_by) = &self.order_by {
+            separate(printer);
+            order_by.order_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+            order_by.by_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+            pretty_print_list(&order_by.items, printer);
+        }
+        if let Some(frame) = &self.frame {
+            separate(printer);
+            frame.pretty_print(printer);
+        }
+    }
+}
+
+pub struct PartitionBy {
+    pub partition_keyword: keyword::partition,
+    pub by_keyword: keyword::by,
+    pub exprs: Punctuated<ExprRoot, Token![,]>,
+}
+
+impl ParseOption for PartitionBy {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::partition) && input.peek2(keyword::by)
+    }
+}
+
+impl Parse for PartitionBy {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            partition_keyword: input.call(keyword::partition::parse_autocomplete)?,
+            by_keyword: input.call(keyword::by::parse_autocomplete)?,
+            exprs: {
+                let mut punctuated = Punctuated::new();
+                loop {
+                    punctuated.push(input.parse()?);
+                    if !input.peek(Token![,]) {
+                        break;
+                    }
+                    punctuated.push_punct(input.parse()?);
+                }
+                punctuated
+            },
+        })
+    }
+}
+
+impl PrettyPrint for PartitionBy {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.partition_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.by_keyword.pretty_print(printer);
+        " ".pretty_print(printer);
+        pretty_print_list(&self.exprs, printer);
+    }
+}
+
+/// Prints a list without the trailing comma that a broken `Punctuated` gets, which cannot be
+/// parsed in front of the next part of a window definition.
+fn pretty_print_list<T: PrettyPrint>(list: &Punctuated<T, Token![,]>, printer: &mut Printer<'_>) {
+    printer.scan_begin(BreakMode::Inconsistent);
+    for pair in list.pairs() {
+        pair.value().pretty_print(printer);
+        if let Some(punct) = pair.punct() {
+            punct.pretty_print(printer);
+            printer.scan_break();
+            " ".pretty_print(printer);
+        }
+    }
+    printer.scan_end();
+}
+
+/// The frame clause of a window definition, e.g. `rows between unbounded preceding and current
+/// row`. Without `between`, the frame ends at the current row.
+pub struct Frame {
+    pub unit: FrameUnit,
+    pub between_keyword: Option<keyword::between>,
+    pub start: FrameBound,
+    pub end: Option<(keyword::and, FrameBound)>,
+}
+
+impl ParseOption for Frame {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::range) || input.peek(keyword::rows) || input.peek(keyword::groups)
+    }
+}
+
+impl Parse for Frame {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let unit = input.parse()?;
+        let between_keyword: Option<keyword::between> = input.parse()?;
+        let start = input.parse()?;
+        let end = if between_keyword.is_some() {
+            Some((
+                input.call(keyword::and::parse_autocomplete)?,
+                input.parse()?,
+            ))
+        } else {
+            None
+        };
+        Ok(Self {
+            unit,
+            between_keyword,
+            start,
+            end,
+        })
+    }
+}
+
+impl ToTokens for Frame {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let unit = &self.unit;
+        let start = &self.start;
+        let end = QuoteOption(self.end.as_ref().map(|(_, end)| end));
+        quote! { ::kosame::repr::part::Frame::new(#unit, #start, #end) }.to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Frame {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.unit.pretty_print(printer);
+        " ".pretty_print(printer);
+        if let Some(between_keyword) = &self.between_keyword {
+            between_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+        }
+        self.start.pretty_print(printer);
+        if let Some((and_keyword, end)) = &self.end {
+            " ".pretty_print(printer);
+            and_keyword.pretty_print(printer);
+            " ".pretty_print(printer);
+            end.pretty_print(printer);
+        }
+    }
+}
+
+pub enum FrameUnit {
+    Range(keyword::range),
+    Rows(keyword::rows),
+    Groups(keyword::groups),
+}
+
+impl Parse for FrameUnit {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::range) {
+            Ok(Self::Range(input.parse()?))
+        } else if lookahead.peek(keyword::rows) {
+            Ok(Self::Rows(input.parse()?))
+        } else if lookahead.peek(keyword::groups) {
+            Ok(Self::Groups(input.parse()?))
+        } else {
+            Err(lookahead.error())
+        }
+    }
+}
+
+impl ToTokens for FrameUnit {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Range(_) => quote! { ::kosame::repr::part::FrameUnit::Range },
+            Self::Rows(_) => quote! { ::kosame::repr::part::FrameUnit::Rows },
+            Self::Groups(_) => quote! { ::kosame::repr::part::FrameUnit::Groups },
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for FrameUnit {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        match self {
+            Self::Range(range) => range.pretty_print(printer),
+            Self::Rows(rows) => rows.pretty_print(printer),
+            Self::Groups(groups) => groups.pretty_print(printer),
+        }
+    }
+}
+
+pub enum FrameBound {
+    UnboundedPreceding(keyword::unbounded, keyword::preceding),
+    Preceding(ExprRoot, keyword::preceding),
+    CurrentRow(keyword::current, keyword::row),
+    Following(ExprRoot, keyword::following),
+    UnboundedFollowing(keyword::unbounded, keyword::following),
+}
+
+impl Parse for FrameBound {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(keyword::current) {
+            return Ok(Self::CurrentRow(
+                input.call(keyword::current::parse_autocomplete)?,
+                input.call(keyword::row::parse_autocomplete)?,
+            ));
+        }
+
+        if input.peek(keyword::unbounded) {
+            let unbounded = input.call(keyword::unbounded::parse_autocomplete)?;
+            let lookahead = input.lookahead1();
+            return if lookahead.peek(keyword::preceding) {
+                Ok(Self::UnboundedPreceding(unbounded, input.parse()?))
+            } else if lookahead.peek(keyword::following) {
+                Ok(Self::UnboundedFollowing(unbounded, input.parse()?))
+            } else {
+                keyword::group_frame_direction::error(input);
+            };
+        }
+
+        let offset = input.parse()?;
+        let lookahead = input.lookahead1();
+        if lookahead.peek(keyword::preceding) {
+            Ok(Self::Preceding(offset, input.parse()?))
+        } else if lookahead.peek(keyword::following) {
+            Ok(Self::Following(offset, input.parse()?))
+        } else {
+            keyword::group_frame_direction::error(input);
+        }
+    }
+}
+
+impl ToTokens for FrameBound {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::UnboundedPreceding(..) => {
+                quote! { ::kosame::repr::part::FrameBound::UnboundedPreceding }
+            }
+            Self::Preceding(offset, _) => {
+                quote! { ::kosame::repr::part::FrameBound::Preceding(#offset) }
+            }
+            Self::CurrentRow(..) => quote! { ::kosame::repr::part::FrameBound::CurrentRow },
+            Self::Following(offset, _) => {
+                quote! { ::kosame::repr::part::FrameBound::Following(#offset) }
+            }
+            Self::UnboundedFollowing(..) => {
+                quote! { ::kosame::repr::part::FrameBound::UnboundedFollowing }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for FrameBound {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        match self {
+            Self::UnboundedPreceding(unbounded, preceding) => {
+                unbounded.pretty_print(printer);
+                " ".pretty_print(printer);
+                preceding.pretty_print(printer);
+            }
+            Self::Preceding(offset, preceding) => {
+                offset.pretty_print(printer);
+                " ".pretty_print(printer);
+                preceding.pretty_print(printer);
+            }
+            Self::CurrentRow(current, row) => {
+                current.pretty_print(printer);
+                " ".pretty_print(printer);
+                row.pretty_print(printer);
+            }
+            Self::Following(offset, following) => {
+                offset.pretty_print(printer);
+                " ".pretty_print(printer);
+                following.pretty_print(printer);
+            }
+            Self::UnboundedFollowing(unbounded, following) => {
+                unbounded.pretty_print(printer);
+                " ".pretty_print(printer);
+                following.pretty_print(printer);
+            }
+        }
+    }
+}
@@ -69,7 +69,19 @@ impl_token!(-, "-");
 impl_token!(>, ">");
 impl_token!(<, "<");
 impl_token!($, "$");
+impl_token!(?, "?");
+impl_token!(@, "@");
+impl_token!(|, "|");
+impl_token!(&, "&");
+impl_token!(&&, "&&");
+impl_token!(||, "||");
+impl_token!(^, "^");
+impl_token!(<<, "<<");
+impl_token!(>>, ">>");
 impl_token!(as, "as");
+impl_token!(do, "do");
+impl_token!(else, "else");
+impl_token!(in, "in");
 impl_token!(=>, "=>");
 impl_token!(<=, "<=");
 impl_token!(where, "where");
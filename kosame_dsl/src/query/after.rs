@@ -0,0 +1,131 @@
+use proc_macro2::TokenStream;
+use quote::{ToTokens, quote};
+use syn::{
+    Ident, Token, parenthesized,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+use crate::{
+    clause::{Clause, OrderBy},
+    expr::{BindParam, Expr},
+    keyword,
+    parse_option::ParseOption,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+    visit::Visit,
+};
+
+/// Keyset pagination: `after (created_at, id) = :cursor`.
+pub struct After {
+    pub after_keyword: keyword::after,
+    pub paren_token: syn::token::Paren,
+    pub columns: Punctuated<Ident, Token![,]>,
+    pub eq_token: Token![=],
+    pub bind_param: BindParam,
+}
+
+impl After {
+    /// Checks that the keyset columns are exactly the items of the node's `order by` clause,
+    /// which the generated comparison is derived from.
+    pub fn validate(&self, order_by: Option<&OrderBy>) -> syn::Result<()> {
+        let Some(order_by) = order_by else {
+            return Err(syn::Error::new(
+                self.after_keyword.span,
+                "`after` requires an `order by` clause on the same node",
+            ));
+        };
+
+        if order_by.items.len() != self.columns.len() {
+            return Err(syn::Error::new(
+                self.paren_token.span.join(),
+                "the columns of `after` must match the items of the `order by` clause",
+            ));
+        }
+
+        for (column, item) in self.columns.iter().zip(&order_by.items) {
+            match &*item.expr {
+                Expr::ColumnRef(column_ref) if column_ref.name == *column => {}
+                _ => {
+                    return Err(syn::Error::new(
+                        column.span(),
+                        format!("expected `order by` item for column `{column}` at this position"),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.columns.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.columns.is_empty()
+    }
+}
+
+impl ParseOption for After {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::after) && input.peek2(syn::token::Paren)
+    }
+}
+
+pub fn visit_after<'a>(visit: &mut (impl Visit<'a> + ?Sized), after: &'a After) {
+    visit.visit_bind_param(&after.bind_param);
+}
+
+impl Parse for After {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            after_keyword: input.call(keyword::after::parse_autocomplete)?,
+            paren_token: parenthesized!(content in input),
+            columns: content.parse_terminated(Ident::parse, Token![,])?,
+            eq_token: input.parse()?,
+            bind_param: input.parse()?,
+        };
+
+        if result.columns.is_empty() {
+            return Err(syn::Error::new(
+                result.paren_token.span.join(),
+                "at least one column must be specified for `after`",
+            ));
+        }
+
+        Ok(result)
+    }
+}
+
+impl ToTokens for After {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let name = &self.bind_param.name;
+        quote! { ::kosame::repr::query::After::new(params::#name::BIND_PARAMS) }.to_tokens(tokens);
+    }
+}
+
+struct AfterBody<'a>(&'a After);
+
+impl PrettyPrint for AfterBody<'_> {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        let after = self.0;
+        after
+            .paren_token
+            .pretty_print(printer, Some(BreakMode::Inconsistent), |printer| {
+                after.columns.pretty_print(printer);
+            });
+        " ".pretty_print(printer);
+        after.eq_token.pretty_print(printer);
+        " ".pretty_print(printer);
+        after.bind_param.pretty_print(printer);
+    }
+}
+
+impl PrettyPrint for After {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        Clause::new(&[&self.after_keyword], &AfterBody(self)).pretty_print(printer);
+    }
+}
@@ -1,6 +1,7 @@
-use super::Node;
+use super::{Node, Recursive};
 use crate::{
     expr::ExprRoot,
+    keyword,
     parse_option::ParseOption,
     part::{Alias, TypeOverride},
     path_ext::PathExt,
@@ -26,6 +27,7 @@ pub enum Field {
     Relation {
         attrs: Vec<Attribute>,
         name: Ident,
+        recursive: Option<Recursive>,
         node: Box<Node>,
         alias: Option<Alias>,
     },
@@ -73,6 +75,37 @@ impl Field {
         matches!(self, Self::Column { .. })
     }
 
+    /// For recursive relations, returns the field that nests the relation into its own row type.
+    #[must_use]
+    pub fn to_recursive_row_field(
+        &self,
+        table_path: &Path,
+        node_path: &QueryNodePath,
+    ) -> Option<RowField> {
+        let Field::Relation {
+            attrs,
+            name,
+            recursive: Some(_),
+            alias,
+            ..
+        } = self
+        else {
+            return None;
+        };
+
+        let alias_or_name = alias.as_ref().map_or(name, |alias| &alias.ident).clone();
+        let inner_type = node_path
+            .clone()
+            .appended(name.clone())
+            .to_struct_name("Row");
+
+        Some(RowField::new(
+            attrs.clone(),
+            alias_or_name,
+            quote! { #table_path::relations::#name::target_table::relations::#name::RecursiveType<#inner_type> },
+        ))
+    }
+
     #[must_use]
     pub fn to_row_field(&self, table_path: &Path, node_path: &QueryNodePath) -> RowField {
         match self {
@@ -132,10 +165,11 @@ impl Parse for Field {
         let fork = input.fork();
         let ident = fork.parse::<Ident>();
 
-        if input.peek2(syn::token::Brace) {
+        if input.peek2(syn::token::Brace) || input.peek2(keyword::recursive) {
             Ok(Self::Relation {
                 attrs,
                 name: input.parse()?,
+                recursive: input.call(Recursive::parse_option)?,
                 node: input.parse()?,
                 alias: input.call(Alias::parse_option)?,
             })
@@ -179,12 +213,17 @@ impl PrettyPrint for Field {
             Self::Relation {
                 attrs,
                 name,
+                recursive,
                 node,
                 alias,
             } => {
                 attrs.pretty_print(printer);
                 name.pretty_print(printer);
                 " ".pretty_print(printer);
+                if let Some(recursive) = recursive {
+                    recursive.pretty_print(printer);
+                    " ".pretty_print(printer);
+                }
                 node.pretty_print(printer);
                 alias.pretty_print(printer);
             }
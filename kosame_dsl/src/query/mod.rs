@@ -1,15 +1,20 @@
+mod after;
 mod field;
 mod node;
 mod node_path;
+mod recursive;
 mod star;
 
+pub use after::visit_after;
+pub use after::*;
 pub use field::*;
 pub use node::visit_node;
 pub use node::*;
 pub use node_path::*;
+pub use recursive::*;
 
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
     Attribute, Ident,
     parse::{Parse, ParseStream},
@@ -19,6 +24,7 @@ use crate::{
     attribute::{CustomMeta, MetaLocation},
     bind_params::{BindParams, BindParamsClosure},
     correlations::{CorrelationId, Correlations},
+    function_calls::FunctionCalls,
     parse_option::ParseOption,
     part::{Alias, TablePath},
     path_ext::PathExt,
@@ -56,6 +62,73 @@ impl Parse for Query {
     }
 }
 
+impl Query {
+    /// Returns the methods of a paginated query, which return a page along with the cursor for
+    /// the next one. The inherent methods take precedence over the ones of the `Query` trait. The
+    /// keyset columns are checked to be `not null`, which only the table knows.
+    fn pagination_tokens(&self, lifetime: Option<&TokenStream>) -> Option<TokenStream> {
+        self.body.after.as_ref().map(|after| {
+            let cursor_len = after.len();
+            let table_path = self.table.as_path().to_call_site(1);
+            let keyset_assertions = after.columns.iter().map(|column| {
+                // The error points at the column if all tokens of the path share its span.
+                let table_path = table_path.to_token_stream().into_iter().map(|mut token| {
+                    token.set_span(column.span());
+                    token
+                });
+                let table_path = TokenStream::from_iter(table_path);
+                quote_spanned! {column.span()=>
+                    let _ = assert_not_null::<#table_path::columns::#column::Nullability>;
+                }
+            });
+            quote! {
+                const _: () = {
+                    const fn assert_not_null<N: ::kosame::query::KeysetNullability>() {}
+                    #(#keyset_assertions)*
+                };
+
+                impl #lifetime ::kosame::query::PaginatedQuery for Query #lifetime {}
+
+                impl #lifetime Query #lifetime {
+                    pub async fn query_vec<'c, C>(
+                        &self,
+                        connection: &mut C,
+                    ) -> ::kosame::Result<::kosame::query::Page<Row>>
+                    where
+                        C: ::kosame::driver::Connection,
+                        C::Row: ::kosame::query::CursorRow,
+                        Params #lifetime: ::kosame::params::Params<C::Params<'c>>,
+                        for<'b> Row: From<&'b C::Row>,
+                    {
+                        ::kosame::query::PaginatedQuery::query_page(self, connection).await
+                    }
+
+                    pub fn query_vec_sync<'c, C>(
+                        &self,
+                        connection: &mut C,
+                    ) -> ::kosame::Result<::kosame::query::Page<Row>>
+                    where
+                        C: ::kosame::driver::Connection,
+                        C::Row: ::kosame::query::CursorRow,
+                        Params #lifetime: ::kosame::params::Params<C::Params<'c>>,
+                        for<'b> Row: From<&'b C::Row>,
+                    {
+                        ::kosame::query::PaginatedQuery::query_page_sync(self, connection)
+                    }
+
+                    pub fn encode_cursor(cursor: &::kosame::query::Cursor) -> String {
+                        cursor.encode()
+                    }
+
+                    pub fn decode_cursor(cursor: &str) -> ::kosame::Result<::kosame::query::Cursor> {
+                        ::kosame::query::Cursor::decode(cursor, #cursor_len)
+                    }
+                }
+            }
+        })
+    }
+}
+
 impl ToTokens for Query {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
         let module_name = match &self.alias {
@@ -63,14 +136,15 @@ impl ToTokens for Query {
             None => &Ident::new("internal", Span::call_site()),
         };
 
-        let bind_params = BindParams::from(self);
         let correlations = Correlations::from(self);
         let scopes = Scopes::from(self);
+        let bind_params = BindParams::from_query(self, &correlations, &scopes);
+        let function_calls = FunctionCalls::from_query(self, &correlations, &scopes);
 
         let node_tokens = {
             let mut tokens = proc_macro2::TokenStream::new();
             self.body
-                .to_row_tokens(&mut tokens, self, &QueryNodePath::new());
+                .to_row_tokens(&mut tokens, self, &QueryNodePath::new(), None);
             tokens
         };
 
@@ -83,6 +157,8 @@ impl ToTokens for Query {
 
         let lifetime = (!bind_params.is_empty()).then_some(quote! { <'a> });
 
+        let pagination = self.pagination_tokens(lifetime.as_ref());
+
         let module_tokens = quote! {
             pub mod #module_name {
                 #correlations
@@ -108,9 +184,13 @@ impl ToTokens for Query {
                     }
                 }
 
+                #pagination
+
                 #bind_params
 
                 #scopes
+
+                #function_calls
             }
         };
 
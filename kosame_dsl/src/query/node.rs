@@ -1,20 +1,25 @@
 use crate::clause::peek_clause;
 use crate::pretty::{BreakMode, Delim, PrettyPrint, Printer};
 use crate::{
-    clause::{Limit, Offset, OrderBy, Where},
+    clause::{GroupBy, Having, Limit, Offset, OrderBy, Where},
+    expr::Expr,
     parse_option::ParseOption,
+    part::Distinct,
     quote_option::QuoteOption,
-    row::Row,
+    row::{Row, RowField},
     visit::Visit,
 };
 
 use super::star::Star;
-use super::{CorrelationId, Field, Ident, PathExt, Query, QueryNodePath, ScopeId};
+use super::{
+    After, CorrelationId, Field, Ident, PathExt, Query, QueryNodePath, Recursive, ScopeId,
+};
 use proc_macro2::{Span, TokenStream};
-use quote::{ToTokens, quote};
+use quote::{ToTokens, quote, quote_spanned};
 use syn::{
     Path, PathSegment, Token, braced,
     parse::{Parse, ParseStream},
+    parse_quote,
     punctuated::Punctuated,
 };
 
@@ -22,20 +27,81 @@ pub struct Node {
     pub correlation_id: CorrelationId,
     pub scope_id: ScopeId,
     pub brace_token: syn::token::Brace,
+    pub distinct: Option<Distinct>,
     pub star: Option<Star>,
     pub fields: Punctuated<Field, Token![,]>,
     pub r#where: Option<Where>,
+    pub after: Option<After>,
+    pub group_by: Option<GroupBy>,
+    pub having: Option<Having>,
     pub order_by: Option<OrderBy>,
     pub limit: Option<Limit>,
     pub offset: Option<Offset>,
 }
 
 impl Node {
+    /// Returns `true` if the rows of the node are aggregated by `group by` or `having`.
+    #[must_use]
+    pub fn is_grouped(&self) -> bool {
+        self.group_by.is_some() || self.having.is_some()
+    }
+
+    /// Checks that every field of an aggregated node can be selected after grouping. Columns must
+    /// be listed in `group by`, everything else has to go through an expression.
+    fn validate_grouping(&self) -> syn::Result<()> {
+        if !self.is_grouped() {
+            return Ok(());
+        }
+
+        if let Some(star) = &self.star {
+            return Err(syn::Error::new(
+                star.star_token.span,
+                "`*` cannot be used in a node with `group by` or `having`",
+            ));
+        }
+
+        for field in &self.fields {
+            match field {
+                Field::Column { name, .. } => {
+                    let grouped = self
+                        .group_by
+                        .iter()
+                        .flat_map(|group_by| &group_by.items)
+                        .any(|item| {
+                            matches!(
+                                &*item.expr,
+                                Expr::ColumnRef(column_ref)
+                                    if column_ref.correlation.is_none() && column_ref.name == *name
+                            )
+                        });
+                    if !grouped {
+                        return Err(syn::Error::new(
+                            name.span(),
+                            format!(
+                                "column `{name}` must appear in the `group by` clause or be used in an aggregate expression"
+                            ),
+                        ));
+                    }
+                }
+                Field::Relation { name, .. } => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        "relations cannot be selected in a node with `group by` or `having`",
+                    ));
+                }
+                Field::Expr { .. } => {}
+            }
+        }
+
+        Ok(())
+    }
+
     pub fn to_row_tokens(
         &self,
         tokens: &mut TokenStream,
         query: &Query,
         node_path: &QueryNodePath,
+        recursive_field: Option<RowField>,
     ) {
         let table_path = node_path.resolve(query.table.as_path());
         tokens.extend(self.to_autocomplete_module_tokens(
@@ -51,8 +117,13 @@ impl Node {
                 .as_ref()
                 .and_then(|star| star.alias.is_some().then(|| star.to_row_field(&table_path)));
 
+            let mut attrs = query.outer_attrs.clone();
+            if recursive_field.is_some() {
+                attrs.push(parse_quote! { #[recursive] });
+            }
+
             Row::new(
-                query.outer_attrs.clone(),
+                attrs,
                 node_path.to_struct_name("Row"),
                 star_field
                     .into_iter()
@@ -61,6 +132,7 @@ impl Node {
                             .iter()
                             .map(|field| field.to_row_field(&table_path, node_path)),
                     )
+                    .chain(recursive_field)
                     .collect(),
             )
         };
@@ -82,10 +154,32 @@ impl Node {
 
         // Recursively call to_tokens on child nodes.
         for field in &self.fields {
-            if let Field::Relation { name, node, .. } = field {
-                let mut node_path = node_path.clone();
-                node_path.append(name.clone());
-                node.to_row_tokens(tokens, query, &node_path);
+            if let Field::Relation {
+                name,
+                recursive,
+                node,
+                ..
+            } = field
+            {
+                let table_path = table_path.to_call_site(1);
+                if let Some(recursive) = recursive {
+                    quote_spanned! {recursive.recursive.span=>
+                        const _: () = ::core::assert!(
+                            #table_path::relations::#name::RELATION.is_self_referencing(),
+                            "recursive relations must reference their own table",
+                        );
+                    }
+                    .to_tokens(tokens);
+                }
+
+                let mut child_node_path = node_path.clone();
+                child_node_path.append(name.clone());
+                node.to_row_tokens(
+                    tokens,
+                    query,
+                    &child_node_path,
+                    field.to_recursive_row_field(&table_path, node_path),
+                );
             }
         }
     }
@@ -139,9 +233,14 @@ impl Node {
                         });
                     }
                     Field::Relation {
-                        name, node, alias, ..
+                        name,
+                        recursive,
+                        node,
+                        alias,
+                        ..
                     } => {
                         let alias = QuoteOption::from(alias);
+                        let depth = QuoteOption(recursive.as_ref().map(Recursive::depth));
 
                         let node_path = node_path.clone().appended(name.clone());
 
@@ -160,7 +259,8 @@ impl Node {
                             ::kosame::repr::query::Field::Relation {
                                 relation: &#relation_path::RELATION,
                                 node: #tokens,
-                                alias: #alias
+                                alias: #alias,
+                                depth: #depth
                             }
                         });
                     }
@@ -177,9 +277,13 @@ impl Node {
                 }
             }
 
+            let distinct = QuoteOption::from(&self.distinct);
             let star = self.star.is_some();
 
             let r#where = QuoteOption::from(&self.r#where);
+            let after = QuoteOption::from(&self.after);
+            let group_by = QuoteOption::from(&self.group_by);
+            let having = QuoteOption::from(&self.having);
             let order_by = QuoteOption::from(&self.order_by);
             let limit = QuoteOption::from(&self.limit);
             let offset = QuoteOption::from(&self.offset);
@@ -187,9 +291,13 @@ impl Node {
             quote! {
                 ::kosame::repr::query::Node::new(
                     &#table_path_call_site::TABLE,
+                    #distinct,
                     #star,
                     &[#(#fields),*],
                     #r#where,
+                    #after,
+                    #group_by,
+                    #having,
                     #order_by,
                     #limit,
                     #offset,
@@ -201,6 +309,9 @@ impl Node {
 }
 
 pub fn visit_node<'a>(visit: &mut (impl Visit<'a> + ?Sized), node: &'a Node) {
+    if let Some(inner) = node.distinct.as_ref() {
+        visit.visit_distinct(inner);
+    }
     for field in &node.fields {
         match field {
             Field::Relation { node, .. } => visit.visit_node(node),
@@ -212,6 +323,15 @@ pub fn visit_node<'a>(visit: &mut (impl Visit<'a> + ?Sized), node: &'a Node) {
     if let Some(inner) = node.r#where.as_ref() {
         visit.visit_where(inner);
     }
+    if let Some(inner) = node.after.as_ref() {
+        visit.visit_after(inner);
+    }
+    if let Some(inner) = node.group_by.as_ref() {
+        visit.visit_group_by(inner);
+    }
+    if let Some(inner) = node.having.as_ref() {
+        visit.visit_having(inner);
+    }
     if let Some(inner) = node.order_by.as_ref() {
         visit.visit_order_by(inner);
     }
@@ -228,6 +348,8 @@ impl Parse for Node {
         let content;
         let brace_token = braced!(content in input);
 
+        let distinct = content.call(Distinct::parse_option)?;
+
         let star = if content.fork().parse::<Star>().is_ok() {
             let star = Some(content.parse()?);
             if !content.is_empty() {
@@ -240,7 +362,7 @@ impl Parse for Node {
 
         let mut fields = Punctuated::<Field, _>::new();
         while !content.is_empty() {
-            if peek_clause(&content) {
+            if peek_clause(&content) || After::peek(&content) {
                 break;
             }
 
@@ -271,19 +393,52 @@ impl Parse for Node {
                 ));
             }
             existing.push(name_string);
+
+            if let Field::Relation { node, .. } = field
+                && let Some(after) = &node.after
+            {
+                return Err(syn::Error::new(
+                    after.after_keyword.span,
+                    "`after` is only supported on the top-level node of a query",
+                ));
+            }
+
+            if let Field::Relation {
+                node,
+                recursive: Some(recursive),
+                ..
+            } = field
+                && node.is_grouped()
+            {
+                return Err(syn::Error::new(
+                    recursive.recursive.span,
+                    "recursive relations cannot use `group by` or `having`",
+                ));
+            }
         }
 
-        Ok(Self {
+        let node = Self {
             correlation_id: CorrelationId::new(),
             scope_id: ScopeId::new(),
             brace_token,
+            distinct,
             star,
             fields,
             r#where: content.call(Where::parse_option)?,
+            after: content.call(After::parse_option)?,
+            group_by: content.call(GroupBy::parse_option)?,
+            having: content.call(Having::parse_option)?,
             order_by: content.call(OrderBy::parse_option)?,
             limit: content.call(Limit::parse_option)?,
             offset: content.call(Offset::parse_option)?,
-        })
+        };
+
+        if let Some(after) = &node.after {
+            after.validate(node.order_by.as_ref())?;
+        }
+        node.validate_grouping()?;
+
+        Ok(node)
     }
 }
 
@@ -291,11 +446,19 @@ impl PrettyPrint for Node {
     fn pretty_print(&self, printer: &mut Printer<'_>) {
         self.brace_token
             .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                self.distinct.pretty_print(printer);
+                if self.distinct.is_some() && (self.star.is_some() || !self.fields.is_empty()) {
+                    " ".pretty_print(printer);
+                }
+
                 self.star.pretty_print(printer);
 
                 if self.star.is_some()
                     && (!self.fields.is_empty()
                         || self.r#where.is_some()
+                        || self.after.is_some()
+                        || self.group_by.is_some()
+                        || self.having.is_some()
                         || self.order_by.is_some()
                         || self.limit.is_some()
                         || self.offset.is_some())
@@ -312,6 +475,9 @@ impl PrettyPrint for Node {
                 self.fields.pretty_print(printer);
                 printer.scan_same_line_trivia();
                 self.r#where.pretty_print(printer);
+                self.after.pretty_print(printer);
+                self.group_by.pretty_print(printer);
+                self.having.pretty_print(printer);
                 self.order_by.pretty_print(printer);
                 self.limit.pretty_print(printer);
                 self.offset.pretty_print(printer);
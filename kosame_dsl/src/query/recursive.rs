@@ -0,0 +1,69 @@
+use syn::{
+    LitInt, Token, parenthesized,
+    parse::{Parse, ParseStream},
+};
+
+use crate::{
+    keyword,
+    parse_option::ParseOption,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+};
+
+pub struct Recursive {
+    pub recursive: keyword::recursive,
+    pub paren_token: syn::token::Paren,
+    pub depth_keyword: keyword::depth,
+    pub eq_token: Token![=],
+    pub depth: LitInt,
+}
+
+impl Recursive {
+    /// The maximum number of levels loaded below the parent node.
+    #[must_use]
+    pub fn depth(&self) -> u32 {
+        self.depth
+            .base10_parse()
+            .expect("depth was validated while parsing")
+    }
+}
+
+impl ParseOption for Recursive {
+    fn peek(input: ParseStream) -> bool {
+        input.peek(keyword::recursive)
+    }
+}
+
+impl Parse for Recursive {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        let result = Self {
+            recursive: input.parse()?,
+            paren_token: parenthesized!(content in input),
+            depth_keyword: content.call(keyword::depth::parse_autocomplete)?,
+            eq_token: content.parse()?,
+            depth: content.parse()?,
+        };
+
+        match result.depth.base10_parse::<u32>() {
+            Ok(depth) if depth > 0 => Ok(result),
+            _ => Err(syn::Error::new(
+                result.depth.span(),
+                "recursion depth must be a positive integer",
+            )),
+        }
+    }
+}
+
+impl PrettyPrint for Recursive {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.recursive.pretty_print(printer);
+        self.paren_token
+            .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                self.depth_keyword.pretty_print(printer);
+                " ".pretty_print(printer);
+                self.eq_token.pretty_print(printer);
+                " ".pretty_print(printer);
+                self.depth.pretty_print(printer);
+            });
+    }
+}
@@ -12,7 +12,7 @@ use convert_case::{Case, Casing};
 use proc_macro2::TokenStream;
 use quote::{ToTokens, quote};
 use syn::{
-    Attribute, Ident,
+    Attribute, GenericArgument, Ident, Path, PathArguments, Type,
     parse::{Parse, ParseStream},
 };
 
@@ -35,14 +35,32 @@ impl Column {
             ),
         }
     }
+
+    /// Whether the column can be left out of an insert without violating a constraint, because
+    /// it has a default value or is a serial column.
+    pub fn has_default(&self) -> bool {
+        self.constraints.default().is_some()
+            || matches!(
+                self.data_type.sql_name().as_str(),
+                "smallserial" | "serial" | "bigserial"
+            )
+    }
 }
 
 impl Parse for Column {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let attrs = Attribute::parse_outer(input)?;
-        CustomMeta::parse_attrs(&attrs, MetaLocation::Column)?;
+        let meta = CustomMeta::parse_attrs(&attrs, MetaLocation::Column)?;
         let name = input.parse()?;
-        let data_type = input.parse()?;
+        let data_type: DataType = input.parse()?;
+        if let Some(elements_not_null) = &meta.elements_not_null
+            && !data_type.is_array()
+        {
+            return Err(syn::Error::new(
+                elements_not_null.path.span,
+                "`elements_not_null` can only be used on array columns",
+            ));
+        }
 
         Ok(Self {
             attrs,
@@ -62,19 +80,42 @@ impl ToTokens for Column {
         let rust_name = self.rust_name();
 
         let data_type = &self.data_type;
-        let data_type_string = data_type.name.to_string();
-        let rust_type_not_null = if let Some(type_override) = meta.type_override {
+        let data_type_string = data_type.sql_name();
+        let rust_type_not_null = if let Some(type_override) = &meta.type_override {
             type_override.value.to_call_site(3).to_token_stream()
         } else {
-            quote! { #data_type }
+            data_type.column_rust_type(meta.elements_not_null.is_some())
+        };
+        let rust_type_nullable = quote! { Option<#rust_type_not_null> };
+        let nullable =
+            self.constraints.not_null().is_none() && self.constraints.primary_key().is_none();
+        let rust_type_auto = if nullable {
+            rust_type_nullable.clone()
+        } else {
+            rust_type_not_null.clone()
+        };
+        let nullability = if nullable {
+            quote! { ::kosame::infer::Nullable }
+        } else {
+            quote! { ::kosame::infer::NotNull }
+        };
+
+        // The element type of an array column, used to infer subscripts and `unnest(...)`.
+        // Other types are left to `kosame::infer::Element`.
+        let element = match &meta.type_override {
+            Some(type_override) => element_of_type_override(&type_override.value),
+            None => data_type
+                .element_rust_type()
+                .map(|rust_type| (rust_type, meta.elements_not_null.is_none())),
+        };
+        let (element_not_null, element_nullability) = match element {
+            Some((rust_type, false)) => (rust_type, quote! { ::kosame::infer::NotNull }),
+            Some((rust_type, true)) => (rust_type, quote! { ::kosame::infer::Nullable }),
+            None => (
+                quote! { <TypeNotNull as ::kosame::infer::Element>::Output },
+                quote! { ::kosame::infer::NotNull },
+            ),
         };
-        let rust_type_nullable = quote! { Option<#data_type> };
-        let rust_type_auto =
-            if self.constraints.not_null().is_none() && self.constraints.primary_key().is_none() {
-                rust_type_nullable.clone()
-            } else {
-                rust_type_not_null.clone()
-            };
 
         let not_null = self.constraints.not_null().is_some();
         let primary_key = self.constraints.primary_key().is_some();
@@ -92,19 +133,49 @@ impl ToTokens for Column {
                 pub const COLUMN: ::kosame::repr::schema::Column<'_> = ::kosame::repr::schema::Column::new(
                     #name,
                     #data_type_string,
-                    #not_null,
                     #primary_key,
+                    #not_null,
                     #default,
                 );
                 pub type TypeNotNull = #rust_type_not_null;
                 pub type TypeNullable = #rust_type_nullable;
                 pub type Type = #rust_type_auto;
+                pub type Nullability = #nullability;
+                pub type ElementNotNull = #element_not_null;
+                pub type ElementNullability = #element_nullability;
             }
         }
         .to_tokens(tokens);
     }
 }
 
+/// The element type of a `Vec` type override and whether the elements are nullable, e.g. `i32` and
+/// `true` for `Vec<Option<i32>>`.
+fn element_of_type_override(type_override: &Path) -> Option<(TokenStream, bool)> {
+    let element = single_type_argument(type_override, "Vec")?;
+    if let Type::Path(type_path) = element
+        && type_path.qself.is_none()
+        && let Some(inner) = single_type_argument(&type_path.path, "Option")
+    {
+        return Some((inner.to_token_stream(), true));
+    }
+    Some((element.to_token_stream(), false))
+}
+
+fn single_type_argument<'a>(path: &'a Path, ident: &str) -> Option<&'a Type> {
+    let segment = path.segments.last()?;
+    if segment.ident != ident {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return None;
+    };
+    match arguments.args.first()? {
+        GenericArgument::Type(rust_type) if arguments.args.len() == 1 => Some(rust_type),
+        _ => None,
+    }
+}
+
 impl PrettyPrint for Column {
     fn pretty_print(&self, printer: &mut Printer<'_>) {
         self.attrs.pretty_print(printer);
@@ -0,0 +1,200 @@
+use crate::{
+    attribute::{CustomMeta, MetaLocation},
+    data_type::DataType,
+    doc::Doc,
+    keyword,
+    path_ext::PathExt,
+    pretty::{BreakMode, Delim, PrettyPrint, Printer},
+};
+
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{ToTokens, format_ident, quote};
+use syn::{
+    Attribute, Ident, Token,
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+};
+
+/// A user defined SQL function, declared with `create function name(args) returns type`.
+///
+/// Statements call it by its Rust path, e.g. `schema::slugify(posts.title)`. Unless the function
+/// is `strict`, PostgreSQL calls it on null input as well, so its result may always be null.
+pub struct Function {
+    pub attrs: Vec<Attribute>,
+
+    pub create_kw: keyword::create,
+    pub function_kw: keyword::function,
+    pub name: Ident,
+
+    pub paren: syn::token::Paren,
+    pub args: Punctuated<FunctionArg, Token![,]>,
+
+    pub returns_kw: keyword::returns,
+    pub return_type: DataType,
+    pub strict_kw: Option<keyword::strict>,
+
+    pub semi_token: Option<Token![;]>,
+}
+
+impl Function {
+    #[must_use]
+    pub fn rust_name(&self) -> Ident {
+        let meta = CustomMeta::parse_attrs(&self.attrs, MetaLocation::Function)
+            .expect("custom meta should be checked earlier");
+        match meta.rename {
+            Some(rename) => rename.value,
+            None => Ident::new(
+                &self.name.to_string().to_case(Case::Snake),
+                self.name.span(),
+            ),
+        }
+    }
+}
+
+impl Parse for Function {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        Ok(Self {
+            attrs: {
+                let attrs = Attribute::parse_outer(input)?;
+                CustomMeta::parse_attrs(&attrs, MetaLocation::Function)?;
+                attrs
+            },
+            create_kw: input.call(keyword::create::parse_autocomplete)?,
+            function_kw: input.call(keyword::function::parse_autocomplete)?,
+            name: input.parse()?,
+            paren: syn::parenthesized!(content in input),
+            args: content.parse_terminated(FunctionArg::parse, Token![,])?,
+            returns_kw: input.call(keyword::returns::parse_autocomplete)?,
+            return_type: input.parse()?,
+            strict_kw: input.parse()?,
+            semi_token: input.parse()?,
+        })
+    }
+}
+
+impl ToTokens for Function {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let meta = CustomMeta::parse_attrs(&self.attrs, MetaLocation::Function)
+            .expect("custom meta should be checked earlier");
+
+        let name = self.name.to_string();
+        let rust_name = self.rust_name();
+
+        let rust_type_not_null = if let Some(type_override) = meta.type_override {
+            type_override.value.to_call_site(1).to_token_stream()
+        } else {
+            self.return_type.column_rust_type(false)
+        };
+        let nullability = if self.strict_kw.is_some() {
+            quote! { ::kosame::infer::NotNull }
+        } else {
+            quote! { ::kosame::infer::Nullable }
+        };
+
+        // Arguments of a type without a Rust equivalent are not checked.
+        let mut args = Vec::new();
+        let mut markers = Vec::new();
+        let mut generics = Vec::new();
+        let mut arg_aliases = Vec::new();
+        for (index, arg) in self.args.iter().enumerate() {
+            let arg_type = format_ident!("A{index}");
+            if let Some(rust_type) = arg.data_type.rust_type() {
+                arg_aliases.push(quote! { pub type #arg_type = #rust_type; });
+                let marker = format_ident!("M{index}");
+                generics.push(quote! { #arg_type: ::kosame::infer::Argument<#rust_type, #marker> });
+                generics.push(marker.to_token_stream());
+                markers.push(marker);
+            } else {
+                // Like bind parameters whose type cannot be inferred, these accept any value.
+                #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+                arg_aliases.push(quote! {
+                    pub type #arg_type = (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync);
+                });
+                generics.push(arg_type.to_token_stream());
+            }
+            args.push(arg_type);
+        }
+
+        let doc = Doc::new(self);
+
+        quote! {
+            #doc
+            pub mod #rust_name {
+                pub const FUNCTION_NAME: &str = #name;
+                pub type TypeNotNull = #rust_type_not_null;
+                /// Nullability of the result for arguments that are not null.
+                pub type Nullability = #nullability;
+
+                /// The declared arguments, which calls of the function are checked against.
+                pub struct Arguments;
+
+                impl<#(#generics),*> ::kosame::infer::Arguments<(#(#args,)*), (#(#markers,)*)>
+                    for Arguments
+                {
+                }
+
+                /// The types of the declared arguments, which bind parameters passed to the
+                /// function take.
+                pub mod arguments {
+                    #(#arg_aliases)*
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
+impl PrettyPrint for Function {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        self.attrs.pretty_print(printer);
+        self.create_kw.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.function_kw.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.name.pretty_print(printer);
+        self.paren
+            .pretty_print(printer, Some(BreakMode::Consistent), |printer| {
+                self.args.pretty_print(printer);
+            });
+        " ".pretty_print(printer);
+        self.returns_kw.pretty_print(printer);
+        " ".pretty_print(printer);
+        self.return_type.pretty_print(printer);
+        if let Some(strict_kw) = &self.strict_kw {
+            " ".pretty_print(printer);
+            strict_kw.pretty_print(printer);
+        }
+        self.semi_token.pretty_print(printer);
+    }
+}
+
+/// A function argument with an optional name, e.g. `text` or `input text`.
+pub struct FunctionArg {
+    pub name: Option<Ident>,
+    pub data_type: DataType,
+}
+
+impl Parse for FunctionArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            name: if input.peek(Ident) && input.peek2(Ident) {
+                Some(input.parse()?)
+            } else {
+                None
+            },
+            data_type: input.parse()?,
+        })
+    }
+}
+
+impl PrettyPrint for FunctionArg {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        if let Some(name) = &self.name {
+            name.pretty_print(printer);
+            " ".pretty_print(printer);
+        }
+        self.data_type.pretty_print(printer);
+    }
+}
@@ -1,6 +1,8 @@
 mod column;
 mod column_constraint;
+mod function;
 mod relation;
 mod table;
 
+pub use function::*;
 pub use table::*;
@@ -9,6 +9,7 @@ use syn::{
 };
 
 use crate::{
+    expr::Lit,
     path_ext::PathExt,
     pretty::{BreakMode, Delim, PrettyPrint, Printer},
 };
@@ -17,11 +18,11 @@ pub struct Relation {
     pub name: Ident,
     pub colon: Token![:],
     pub source_paren: syn::token::Paren,
-    pub source_columns: Punctuated<Ident, Token![,]>,
+    pub source_columns: Punctuated<RelationColumn, Token![,]>,
     pub arrow: Arrow,
     pub target_table: syn::Path,
     pub target_paren: syn::token::Paren,
-    pub target_columns: Punctuated<Ident, Token![,]>,
+    pub target_columns: Punctuated<RelationColumn, Token![,]>,
 }
 
 impl Parse for Relation {
@@ -32,11 +33,11 @@ impl Parse for Relation {
             name: input.parse()?,
             colon: input.parse()?,
             source_paren: parenthesized!(source_content in input),
-            source_columns: source_content.parse_terminated(Ident::parse, Token![,])?,
+            source_columns: source_content.parse_terminated(RelationColumn::parse, Token![,])?,
             arrow: input.parse()?,
             target_table: input.parse()?,
             target_paren: parenthesized!(dest_content in input),
-            target_columns: dest_content.parse_terminated(Ident::parse, Token![,])?,
+            target_columns: dest_content.parse_terminated(RelationColumn::parse, Token![,])?,
         };
 
         if result.source_columns.is_empty() {
@@ -54,6 +55,29 @@ impl Parse for Relation {
             );
         }
 
+        for (source, target) in result.source_columns.iter().zip(&result.target_columns) {
+            if let (RelationColumn::Lit(_), RelationColumn::Lit(lit)) = (source, target) {
+                emit_error!(
+                    lit.span(),
+                    "a literal can only be compared to a column in relation `{}`",
+                    result.name
+                );
+            }
+        }
+        if !result.source_columns.is_empty()
+            && result
+                .source_columns
+                .iter()
+                .zip(&result.target_columns)
+                .all(|(source, target)| source.is_lit() || target.is_lit())
+        {
+            emit_error!(
+                result.source_paren.span.span(),
+                "relation `{}` must join at least one pair of columns",
+                result.name
+            );
+        }
+
         Ok(result)
     }
 }
@@ -65,10 +89,32 @@ impl ToTokens for Relation {
 
         let target_table = &self.target_table.to_call_site(3);
 
-        let source_columns = self.source_columns.iter().collect::<Vec<_>>();
-        let target_columns = self.target_columns.iter().collect::<Vec<_>>();
+        let source_columns = self
+            .source_columns
+            .iter()
+            .filter_map(RelationColumn::column)
+            .collect::<Vec<_>>();
+        let target_columns = self
+            .target_columns
+            .iter()
+            .filter_map(RelationColumn::column)
+            .collect::<Vec<_>>();
+        let source_items = self
+            .source_columns
+            .iter()
+            .map(|column| column.to_repr_tokens(quote! { source_columns }));
+        let target_items = self
+            .target_columns
+            .iter()
+            .map(|column| column.to_repr_tokens(quote! { target_columns }));
 
         let arrow = &self.arrow;
+        // A row that contains itself needs indirection unless the relation already collects
+        // into a `Vec`.
+        let recursive_type = match &self.arrow {
+            Arrow::ManyToOne(..) => quote! { ::kosame::relation::ZeroOrOne<Box<T>> },
+            Arrow::OneToMany(..) => quote! { ::kosame::relation::Many<T> },
+        };
 
         quote! {
             pub mod #name {
@@ -85,12 +131,13 @@ impl ToTokens for Relation {
                 pub const RELATION: ::kosame::repr::schema::Relation<'_> = ::kosame::repr::schema::Relation::new(
                     #name_string,
                     super::super::TABLE_NAME,
-                    &[#(&source_columns::#source_columns::COLUMN),*],
+                    &[#(#source_items),*],
                     target_table::TABLE_NAME,
-                    &[#(&target_columns::#target_columns::COLUMN),*],
+                    &[#(#target_items),*],
                 );
 
                 pub type Type<T> = #arrow;
+                pub type RecursiveType<T> = #recursive_type;
             }
         }
         .to_tokens(tokens);
@@ -124,6 +171,56 @@ impl PrettyPrint for Relation {
     }
 }
 
+/// One side of a relation's join condition: either a column or a constant, such as the
+/// discriminator of a polymorphic relation.
+pub enum RelationColumn {
+    Column(Ident),
+    Lit(Lit),
+}
+
+impl RelationColumn {
+    #[must_use]
+    pub fn column(&self) -> Option<&Ident> {
+        match self {
+            Self::Column(ident) => Some(ident),
+            Self::Lit(_) => None,
+        }
+    }
+
+    #[must_use]
+    pub fn is_lit(&self) -> bool {
+        matches!(self, Self::Lit(_))
+    }
+
+    fn to_repr_tokens(&self, columns_module: impl ToTokens) -> TokenStream {
+        match self {
+            Self::Column(ident) => quote! {
+                ::kosame::repr::schema::RelationColumn::Column(&#columns_module::#ident::COLUMN)
+            },
+            Self::Lit(lit) => quote! { ::kosame::repr::schema::RelationColumn::Lit(#lit) },
+        }
+    }
+}
+
+impl Parse for RelationColumn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::Lit) {
+            Ok(Self::Lit(input.parse()?))
+        } else {
+            Ok(Self::Column(input.parse()?))
+        }
+    }
+}
+
+impl PrettyPrint for RelationColumn {
+    fn pretty_print(&self, printer: &mut Printer<'_>) {
+        match self {
+            Self::Column(inner) => inner.pretty_print(printer),
+            Self::Lit(inner) => inner.pretty_print(printer),
+        }
+    }
+}
+
 #[allow(unused)]
 pub enum Arrow {
     ManyToOne(Token![=>]),
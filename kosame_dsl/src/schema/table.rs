@@ -66,6 +66,152 @@ impl Parse for Table {
     }
 }
 
+impl Table {
+    /// Generates the `Insert` struct, which only holds values, and implements `InsertRow` and the
+    /// driver parameters for it.
+    fn insert_impls(&self, tokens: &mut TokenStream) {
+        let fields = self.columns.iter().map(|column| {
+            let name = column.rust_name();
+            if column.has_default() {
+                quote! { pub #name: ::core::option::Option<columns::#name::Type> }
+            } else {
+                quote! { pub #name: columns::#name::Type }
+            }
+        });
+        let defaults = self
+            .columns
+            .iter()
+            .enumerate()
+            .filter(|(_, column)| column.has_default())
+            .map(|(index, column)| {
+                let name = column.rust_name();
+                quote! { #index => self.#name.is_none(), }
+            });
+
+        quote! {
+            #[derive(Debug)]
+            pub struct Insert {
+                #(#fields,)*
+            }
+
+            impl ::kosame::statement::InsertRow for Insert {
+                const TABLE: ::kosame::repr::schema::Table<'static> = TABLE;
+
+                #[allow(clippy::match_single_binding)]
+                fn is_default(&self, column: usize) -> bool {
+                    match column {
+                        #(#defaults)*
+                        _ => false,
+                    }
+                }
+            }
+        }
+        .to_tokens(tokens);
+
+        #[cfg(any(feature = "postgres", feature = "tokio-postgres"))]
+        {
+            let values = self.columns.iter().map(|column| {
+                let name = column.rust_name();
+                if column.has_default() {
+                    quote! {
+                        if let ::core::option::Option::Some(value) = &row.#name {
+                            params.push(::kosame::driver::postgres_types::AsToSql::as_to_sql(value));
+                        }
+                    }
+                } else {
+                    quote! {
+                        params.push(::kosame::driver::postgres_types::AsToSql::as_to_sql(&row.#name));
+                    }
+                }
+            });
+            let bounds = self.columns.iter().map(|column| {
+                let name = column.rust_name();
+                quote! { columns::#name::Type: ::kosame::driver::postgres_types::AsToSql<'a> }
+            });
+            quote! {
+                impl<'a> ::kosame::params::Params<Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)>> for &'a Insert
+                where
+                    #(#bounds),*
+                {
+                    fn to_driver(&self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
+                        let row: &'a Insert = self;
+                        let mut params: Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> = ::std::vec::Vec::new();
+                        #(#values)*
+                        params
+                    }
+                }
+            }
+            .to_tokens(tokens);
+        }
+    }
+
+    /// Implements the binary `COPY` traits for the generated `Select` struct and adds the
+    /// `copy_in` and `copy_out` associated functions to it.
+    #[cfg(feature = "tokio-postgres")]
+    fn copy_impls(&self, tokens: &mut TokenStream) {
+        let names = self
+            .columns
+            .iter()
+            .map(Column::rust_name)
+            .collect::<Vec<_>>();
+        let indices = 0..names.len();
+
+        quote! {
+            impl ::kosame::driver::tokio_postgres::CopyRow for Select {
+                const TABLE: ::kosame::repr::schema::Table<'static> = TABLE;
+            }
+
+            impl<'a> ::kosame::driver::tokio_postgres::CopyInRow<'a> for Select
+            where
+                #(columns::#names::Type: ::kosame::driver::postgres_types::AsToSql<'a>),*
+            {
+                fn copy_in_values(&'a self) -> Vec<&'a (dyn ::kosame::driver::postgres_types::ToSql + ::std::marker::Sync + 'a)> {
+                    vec![#(::kosame::driver::postgres_types::AsToSql::as_to_sql(&self.#names)),*]
+                }
+            }
+
+            impl<'a> ::kosame::driver::tokio_postgres::CopyOutRow<'a> for Select
+            where
+                #(columns::#names::Type: ::kosame::driver::postgres_types::FromSql<'a>),*
+            {
+                fn from_copy_out_row(
+                    row: &'a ::kosame::driver::tokio_postgres::BinaryCopyOutRow,
+                ) -> ::core::result::Result<Self, ::kosame::driver::tokio_postgres::Error> {
+                    ::core::result::Result::Ok(Self {
+                        #(#names: row.try_get(#indices)?,)*
+                    })
+                }
+            }
+
+            impl Select {
+                /// Writes the rows into the table with the binary `COPY` format and returns the
+                /// number of copied rows.
+                pub async fn copy_in<C, I>(client: &C, rows: I) -> ::kosame::Result<u64>
+                where
+                    C: ::kosame::driver::tokio_postgres::CopyClient,
+                    I: ::core::iter::IntoIterator,
+                    I::Item: ::std::borrow::Borrow<Self>,
+                    for<'a> Self: ::kosame::driver::tokio_postgres::CopyInRow<'a>,
+                {
+                    ::kosame::driver::tokio_postgres::copy_in::<Self, _, _>(client, rows).await
+                }
+
+                /// Reads the rows of the table with the binary `COPY` format.
+                pub async fn copy_out<C>(
+                    client: &C,
+                ) -> ::kosame::Result<::kosame::driver::tokio_postgres::CopyOut<Self>>
+                where
+                    C: ::kosame::driver::tokio_postgres::CopyClient,
+                    for<'a> Self: ::kosame::driver::tokio_postgres::CopyOutRow<'a>,
+                {
+                    ::kosame::driver::tokio_postgres::copy_out::<Self, _>(client).await
+                }
+            }
+        }
+        .to_tokens(tokens);
+    }
+}
+
 impl ToTokens for Table {
     #[allow(clippy::too_many_lines)]
     fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -83,6 +229,7 @@ impl ToTokens for Table {
             .iter()
             .map(Column::rust_name)
             .collect::<Vec<_>>();
+        let column_indices = (0..column_names.len()).map(|index| format_ident!("column_{index}"));
         let relation_names = self
             .relations
             .iter()
@@ -101,6 +248,11 @@ impl ToTokens for Table {
                 .collect(),
         );
 
+        let mut impls = TokenStream::new();
+        self.insert_impls(&mut impls);
+        #[cfg(feature = "tokio-postgres")]
+        self.copy_impls(&mut impls);
+
         let star_macro = {
             let unique_macro_name = unique_macro!("__kosame_star_{}", self.name.span());
             let fields = self.columns.iter().map(|column| {
@@ -173,6 +325,10 @@ impl ToTokens for Table {
                     pub use super::relations::*;
                 }
 
+                pub mod columns_by_index {
+                    #(pub use super::columns::#column_names as #column_indices;)*
+                }
+
                 pub const TABLE_NAME: &str = #name;
                 pub const TABLE: ::kosame::repr::schema::Table<'_> = ::kosame::repr::schema::Table::new(
                     #name,
@@ -182,6 +338,13 @@ impl ToTokens for Table {
 
                 #select_struct
 
+                #impls
+
+                /// Inserts the rows with as few `insert` statements as possible.
+                pub fn insert_many(rows: &[Insert]) -> ::kosame::statement::InsertMany<'_, Insert> {
+                    ::kosame::statement::InsertMany::new(rows)
+                }
+
                 #star_macro
                 #inject_macro
             }
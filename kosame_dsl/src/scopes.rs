@@ -5,13 +5,13 @@ use quote::{ToTokens, format_ident, quote};
 use syn::Ident;
 
 use crate::{
-    clause::{FromChain, FromItem, SelectCore, With},
+    clause::{Excluded, FromChain, FromCombinator, FromItem, SelectCore, With},
     command::{Command, SelectChain},
     correlations::CorrelationId,
     inferred_type::InferredType,
     part::TargetTable,
     query::{self, Query},
-    visit::Visit,
+    visit::{self, Visit},
 };
 
 thread_local! {
@@ -79,6 +79,11 @@ pub trait Scoped {
         None
     }
 
+    #[must_use]
+    fn excluded(&self) -> Option<&Excluded> {
+        None
+    }
+
     #[must_use]
     #[allow(clippy::wrong_self_convention)]
     fn from_chain(&self) -> Option<&FromChain>;
@@ -132,8 +137,17 @@ impl<'a> Scope<'a> {
         table: Option<&Ident>,
         column: &'b Ident,
     ) -> Option<InferredType<'b>> {
-        let table = table?;
-        let item = self.items.iter().find(|item| item.name() == Some(table))?;
+        let item = if let Some(table) = table {
+            self.items.iter().find(|item| item.name() == Some(table))?
+        } else {
+            // An unqualified column is unambiguous if the scope has a single table of its own.
+            let mut items = self.items.iter().filter(|item| !item.is_inherited());
+            let item = items.next()?;
+            if items.next().is_some() {
+                return None;
+            }
+            item
+        };
         Some(InferredType::Correlation {
             correlation_id: item.correlation_id(),
             column,
@@ -159,7 +173,7 @@ impl ToTokens for Scope<'_> {
             .items
             .iter()
             .filter(|item| !item.is_inherited())
-            .filter_map(|item| item.name());
+            .filter_map(ScopeItem::name);
 
         quote! {
             pub mod #name {
@@ -175,9 +189,11 @@ impl ToTokens for Scope<'_> {
     }
 }
 
+#[derive(Clone, Copy)]
 pub enum ScopeItem<'a> {
     TargetTable {
         target_table: &'a TargetTable,
+        inherited_from: Option<ScopeId>,
     },
     FromItem {
         from_item: &'a FromItem,
@@ -187,25 +203,31 @@ pub enum ScopeItem<'a> {
     QueryNode {
         node: &'a query::Node,
         name: &'a Ident,
+        inherited: bool,
+    },
+    Excluded {
+        excluded: &'a Excluded,
     },
 }
 
-impl ScopeItem<'_> {
+impl<'a> ScopeItem<'a> {
     #[must_use]
     pub fn correlation_id(&self) -> CorrelationId {
         match self {
-            Self::TargetTable { target_table, .. } => target_table.table.correlation_id,
+            Self::TargetTable { target_table, .. } => target_table.correlation_id,
+            Self::Excluded { excluded } => excluded.correlation_id,
             Self::FromItem { from_item, .. } => from_item.correlation_id(),
             Self::QueryNode { node, .. } => node.correlation_id,
         }
     }
 
     #[must_use]
-    pub fn name(&self) -> Option<&Ident> {
-        match self {
+    pub fn name(&self) -> Option<&'a Ident> {
+        match *self {
             Self::TargetTable { target_table, .. } => Some(target_table.name()),
             Self::FromItem { from_item, .. } => from_item.name(),
             Self::QueryNode { name, .. } => Some(name),
+            Self::Excluded { excluded } => Some(&excluded.name),
         }
     }
 
@@ -213,102 +235,168 @@ impl ScopeItem<'_> {
     pub fn nullable(&self) -> bool {
         match self {
             Self::FromItem { nullable, .. } => *nullable,
-            Self::TargetTable { .. } | Self::QueryNode { .. } => false,
+            Self::TargetTable { .. } | Self::QueryNode { .. } | Self::Excluded { .. } => false,
         }
     }
 
     #[must_use]
     pub fn is_inherited(&self) -> bool {
         match self {
-            Self::FromItem { inherited_from, .. } => inherited_from.is_some(),
-            Self::TargetTable { .. } | Self::QueryNode { .. } => false,
+            Self::TargetTable { inherited_from, .. } | Self::FromItem { inherited_from, .. } => {
+                inherited_from.is_some()
+            }
+            Self::QueryNode { inherited, .. } => *inherited,
+            // Columns of `excluded` must always be qualified, so it is treated like an item of an
+            // outer scope.
+            Self::Excluded { .. } => true,
         }
     }
-}
 
-impl<'a> From<&'a Command> for Scopes<'a> {
-    fn from(value: &'a Command) -> Self {
-        #[derive(Default)]
-        struct Visitor<'a> {
-            scopes: Vec<Scope<'a>>,
-            inherited_from_items: Vec<(ScopeId, &'a FromItem)>,
+    /// Returns the item as seen from a scope nested in the scope with the given ID.
+    #[must_use]
+    pub fn inherit(&self, scope_id: ScopeId) -> Self {
+        match *self {
+            Self::TargetTable { target_table, .. } => Self::TargetTable {
+                target_table,
+                inherited_from: Some(scope_id),
+            },
+            Self::FromItem { from_item, .. } => Self::FromItem {
+                from_item,
+                inherited_from: Some(scope_id),
+                nullable: false,
+            },
+            Self::QueryNode { node, name, .. } => Self::QueryNode {
+                node,
+                name,
+                inherited: true,
+            },
+            Self::Excluded { .. } => *self,
         }
+    }
+}
 
-        impl<'a> Visit<'a> for Visitor<'a> {
-            fn visit_command(&mut self, command: &'a Command) {
-                self.visit_scoped(command);
-            }
+/// Collects the scopes of a command and of all commands nested in it, be it through `with`,
+/// subqueries in `from` or subquery expressions.
+#[derive(Default)]
+struct CommandVisitor<'a> {
+    scopes: Vec<Scope<'a>>,
+    inherited_items: Vec<ScopeItem<'a>>,
+}
 
-            fn visit_select_core(&mut self, select_core: &'a SelectCore) {
-                self.visit_scoped(select_core);
-            }
-        }
+impl<'a> Visit<'a> for CommandVisitor<'a> {
+    fn visit_command(&mut self, command: &'a Command) {
+        self.visit_scoped(command, |visitor| {
+            visit::visit_command_type(visitor, &command.command_type);
+        });
+    }
 
-        impl<'a> Visitor<'a> {
-            fn visit_scoped(&mut self, scoped: &'a dyn Scoped) {
-                let scope_id = scoped.scope_id();
-                let from_items_truncate = self.inherited_from_items.len();
+    fn visit_select_core(&mut self, select_core: &'a SelectCore) {
+        self.visit_scoped(select_core, |visitor| {
+            visit::visit_select_core(visitor, select_core);
+        });
+    }
 
-                let mut items = Vec::new();
-                let mut shadow = HashSet::new();
+    // Select chains and from chains are visited by `visit_scoped`, before the items of the scope
+    // are inherited by the rest of the command.
+    fn visit_select_chain(&mut self, _select_chain: &'a SelectChain) {}
 
-                if let Some(with) = scoped.with() {
-                    self.visit_with(with);
-                }
+    fn visit_from_chain(&mut self, _from_chain: &'a FromChain) {}
 
-                if let Some(select_chain) = scoped.select_chain() {
-                    self.visit_select_chain(select_chain);
-                }
+    // Query nodes get their scopes from the query visitor.
+    fn visit_node(&mut self, _node: &'a query::Node) {}
+}
 
-                if let Some(target_table) = scoped.target_table() {
-                    shadow.insert(target_table.name());
-                    items.push(ScopeItem::TargetTable { target_table });
-                }
+impl<'a> CommandVisitor<'a> {
+    /// Creates the scope of a command or select core. `visit_exprs` visits the expressions of
+    /// the scoped node, so that subqueries in them inherit the items of the scope.
+    fn visit_scoped(&mut self, scoped: &'a dyn Scoped, visit_exprs: impl FnOnce(&mut Self)) {
+        let scope_id = scoped.scope_id();
+        let inherited_items_truncate = self.inherited_items.len();
 
-                if let Some(from_chain) = scoped.from_chain() {
-                    let nullables = from_chain.nullables();
+        let mut items = Vec::new();
+        let mut shadow = HashSet::new();
 
-                    for (from_item, nullable) in from_chain.into_iter().zip(nullables.into_iter()) {
-                        self.inherited_from_items.push((scope_id, from_item));
+        if let Some(with) = scoped.with() {
+            self.visit_with(with);
+        }
 
-                        if let Some(name) = from_item.name() {
-                            shadow.insert(name);
-                        }
+        if let Some(select_chain) = scoped.select_chain() {
+            visit::visit_select_chain(self, select_chain);
+        }
 
-                        // Avoid processing the subquery twice.
-                        if scoped.select_chain().is_none()
-                            && let FromItem::Subquery { command, .. } = from_item
-                        {
-                            self.visit_command(command);
-                        }
+        if let Some(target_table) = scoped.target_table() {
+            let item = ScopeItem::TargetTable {
+                target_table,
+                inherited_from: None,
+            };
+            shadow.insert(target_table.name());
+            self.inherited_items.push(item.inherit(scope_id));
+            items.push(item);
+
+            if let Some(excluded) = scoped.excluded() {
+                let item = ScopeItem::Excluded { excluded };
+                shadow.insert(&excluded.name);
+                self.inherited_items.push(item);
+                items.push(item);
+            }
+        }
 
-                        items.push(ScopeItem::FromItem {
-                            from_item,
-                            inherited_from: None,
-                            nullable,
-                        });
+        if let Some(from_chain) = scoped.from_chain() {
+            let nullables = from_chain.nullables();
+
+            for (from_item, nullable) in from_chain.into_iter().zip(nullables) {
+                let item = ScopeItem::FromItem {
+                    from_item,
+                    inherited_from: None,
+                    nullable,
+                };
+                self.inherited_items.push(item.inherit(scope_id));
+
+                if let Some(name) = from_item.name() {
+                    shadow.insert(name);
+                }
+
+                // Avoid processing the subquery twice.
+                if scoped.select_chain().is_none() {
+                    match from_item {
+                        FromItem::Subquery { command, .. } => self.visit_command(command),
+                        FromItem::Function { call, .. } => self.visit_call(call),
+                        FromItem::Table { .. } => {}
                     }
                 }
 
-                self.inherited_from_items.truncate(from_items_truncate);
+                items.push(item);
+            }
 
-                for (inherited_from, from_item) in &self.inherited_from_items {
-                    if let Some(name) = from_item.name()
-                        && !shadow.contains(name)
-                    {
-                        items.push(ScopeItem::FromItem {
-                            from_item,
-                            inherited_from: Some(*inherited_from),
-                            nullable: false,
-                        });
+            if scoped.select_chain().is_none() {
+                for combinator in &from_chain.combinators {
+                    if let FromCombinator::Join { on, .. } = combinator {
+                        self.visit_expr_root(&on.expr);
                     }
                 }
+            }
+        }
 
-                self.scopes.push(Scope::new(scope_id, items));
+        visit_exprs(self);
+
+        self.inherited_items.truncate(inherited_items_truncate);
+
+        // Nearer scopes shadow the ones further out.
+        for item in self.inherited_items.iter().rev() {
+            if let Some(name) = item.name()
+                && shadow.insert(name)
+            {
+                items.push(*item);
             }
         }
 
-        let mut visitor = Visitor::default();
+        self.scopes.push(Scope::new(scope_id, items));
+    }
+}
+
+impl<'a> From<&'a Command> for Scopes<'a> {
+    fn from(value: &'a Command) -> Self {
+        let mut visitor = CommandVisitor::default();
         visitor.visit_command(value);
         Scopes {
             scopes: visitor.scopes,
@@ -321,22 +409,48 @@ impl<'a> From<&'a Query> for Scopes<'a> {
         struct Visitor<'a> {
             scopes: Vec<Scope<'a>>,
             name: &'a Ident,
+            ancestors: Vec<(&'a query::Node, &'a Ident)>,
         }
 
         impl<'a> Visit<'a> for Visitor<'a> {
             fn visit_node(&mut self, node: &'a query::Node) {
                 let scope_id = node.scope_id;
-                let items = vec![ScopeItem::QueryNode {
+                let mut items = vec![ScopeItem::QueryNode {
                     node,
                     name: self.name,
+                    inherited: false,
                 }];
 
+                // Ancestor nodes can be referenced by name, with nearer nodes shadowing the ones
+                // further out.
+                let mut shadow = HashSet::from([self.name]);
+                for (node, name) in self.ancestors.iter().rev() {
+                    if shadow.insert(name) {
+                        items.push(ScopeItem::QueryNode {
+                            node,
+                            name,
+                            inherited: true,
+                        });
+                    }
+                }
+
+                // Subqueries in the expressions of the node can reference the node and its
+                // ancestors.
+                let mut commands = CommandVisitor {
+                    scopes: Vec::new(),
+                    inherited_items: items.iter().map(|item| item.inherit(scope_id)).collect(),
+                };
+                query::visit_node(&mut commands, node);
+                self.scopes.append(&mut commands.scopes);
+
+                self.ancestors.push((node, self.name));
                 for field in &node.fields {
                     if let query::Field::Relation { node, name, .. } = field {
                         self.name = name;
                         self.visit_node(node);
                     }
                 }
+                self.ancestors.pop();
 
                 self.scopes.push(Scope::new(scope_id, items));
             }
@@ -345,6 +459,7 @@ impl<'a> From<&'a Query> for Scopes<'a> {
         let mut visitor = Visitor {
             scopes: Vec::new(),
             name: &value.table.as_path().segments.last().unwrap().ident,
+            ancestors: Vec::new(),
         };
         visitor.visit_node(&value.body);
         Scopes {
@@ -10,6 +10,7 @@ use crate::{
     bind_params::{BindParams, BindParamsClosure},
     command::Command,
     correlations::{CorrelationId, Correlations},
+    function_calls::FunctionCalls,
     parse_option::ParseOption,
     part::Alias,
     pretty::{BreakMode, Delim, PrettyPrint, Printer},
@@ -121,9 +122,10 @@ impl ToTokens for Statement {
             None => &Ident::new("internal", Span::call_site()),
         };
 
-        let bind_params = BindParams::from(self);
         let correlations = Correlations::from(&self.command);
         let scopes = Scopes::from(&self.command);
+        let bind_params = BindParams::from_statement(self, &correlations, &scopes);
+        let function_calls = FunctionCalls::from_statement(self, &correlations, &scopes);
 
         let command = &self.command;
         let fields = command.fields();
@@ -171,6 +173,7 @@ impl ToTokens for Statement {
                 #bind_params
                 #correlations
                 #scopes
+                #function_calls
             }
         };
 
@@ -1,25 +1,29 @@
 use crate::{
     clause::{
         Field, Fields, From, FromChain, FromCombinator, FromItem, GroupBy, Having, Limit, Offset,
-        OrderBy, Returning, Set, SetItem, Values, ValuesItem, ValuesRow, Where, With, WithItem,
+        OnConflict, OrderBy, Returning, Set, SetItem, Values, ValuesItem, ValuesRow, Where, Window,
+        With, WithItem,
     },
     command::{
         Command, CommandType, Delete, Insert, Select as SelectCommand, SelectChain,
         SelectCombinator, SelectItem, Update, Using,
     },
-    expr::{Binary, BindParam, Call, Cast, ColumnRef, Expr, ExprRoot, Lit, Paren, Raw, Unary},
-    part::{TablePath, TargetTable},
-    query::Node,
+    expr::{
+        Array, Between, Binary, BindParam, Call, Case, Cast, Collate, ColumnRef, Exists, Expr,
+        ExprRoot, Extract, In, Lit, Paren, Quantified, Raw, Subquery, Subscript, Unary,
+    },
+    part::{Distinct, TablePath, TargetTable, WindowSpec},
+    query::{After, Node},
     statement::Statement,
 };
 
 pub use crate::{
     clause::{
         visit_field, visit_fields, visit_from, visit_from_chain, visit_from_combinator,
-        visit_from_item, visit_group_by, visit_having, visit_limit, visit_offset, visit_order_by,
-        visit_returning, visit_select_clause, visit_select_core, visit_set, visit_set_item,
-        visit_values, visit_values_item, visit_values_row, visit_where, visit_with,
-        visit_with_item,
+        visit_from_item, visit_group_by, visit_having, visit_limit, visit_offset,
+        visit_on_conflict, visit_order_by, visit_returning, visit_select_clause, visit_select_core,
+        visit_set, visit_set_item, visit_values, visit_values_item, visit_values_row, visit_where,
+        visit_window, visit_with, visit_with_item,
     },
     command::{
         visit_command, visit_command_type, visit_delete, visit_insert, visit_select_chain,
@@ -27,11 +31,13 @@ pub use crate::{
         visit_using,
     },
     expr::{
-        visit_binary, visit_bind_param, visit_call, visit_cast, visit_column_ref, visit_expr,
-        visit_expr_root, visit_lit, visit_paren, visit_raw, visit_unary,
+        visit_array, visit_between, visit_binary, visit_bind_param, visit_call, visit_case,
+        visit_cast, visit_collate, visit_column_ref, visit_exists, visit_expr, visit_expr_root,
+        visit_extract, visit_in, visit_lit, visit_paren, visit_quantified, visit_raw,
+        visit_subquery, visit_subscript, visit_unary,
     },
-    part::{visit_table_path, visit_target_table},
-    query::visit_node,
+    part::{visit_distinct, visit_table_path, visit_target_table, visit_window_spec},
+    query::{visit_after, visit_node},
     statement::visit_statement,
 };
 
@@ -45,6 +51,14 @@ pub trait Visit<'a> {
         visit_expr_root(self, expr_root);
     }
 
+    fn visit_array(&mut self, array: &'a Array) {
+        visit_array(self, array);
+    }
+
+    fn visit_between(&mut self, between: &'a Between) {
+        visit_between(self, between);
+    }
+
     fn visit_binary(&mut self, binary: &'a Binary) {
         visit_binary(self, binary);
     }
@@ -57,14 +71,34 @@ pub trait Visit<'a> {
         visit_call(self, call);
     }
 
+    fn visit_case(&mut self, case: &'a Case) {
+        visit_case(self, case);
+    }
+
     fn visit_cast(&mut self, cast: &'a Cast) {
         visit_cast(self, cast);
     }
 
+    fn visit_collate(&mut self, collate: &'a Collate) {
+        visit_collate(self, collate);
+    }
+
     fn visit_column_ref(&mut self, column_ref: &'a ColumnRef) {
         visit_column_ref(self, column_ref);
     }
 
+    fn visit_exists(&mut self, exists: &'a Exists) {
+        visit_exists(self, exists);
+    }
+
+    fn visit_extract(&mut self, extract: &'a Extract) {
+        visit_extract(self, extract);
+    }
+
+    fn visit_in(&mut self, r#in: &'a In) {
+        visit_in(self, r#in);
+    }
+
     fn visit_lit(&mut self, lit: &'a Lit) {
         visit_lit(self, lit);
     }
@@ -73,10 +107,22 @@ pub trait Visit<'a> {
         visit_paren(self, paren);
     }
 
+    fn visit_quantified(&mut self, quantified: &'a Quantified) {
+        visit_quantified(self, quantified);
+    }
+
     fn visit_raw(&mut self, raw: &'a Raw) {
         visit_raw(self, raw);
     }
 
+    fn visit_subquery(&mut self, subquery: &'a Subquery) {
+        visit_subquery(self, subquery);
+    }
+
+    fn visit_subscript(&mut self, subscript: &'a Subscript) {
+        visit_subscript(self, subscript);
+    }
+
     fn visit_unary(&mut self, unary: &'a Unary) {
         visit_unary(self, unary);
     }
@@ -122,6 +168,10 @@ pub trait Visit<'a> {
         visit_offset(self, offset);
     }
 
+    fn visit_on_conflict(&mut self, on_conflict: &'a OnConflict) {
+        visit_on_conflict(self, on_conflict);
+    }
+
     fn visit_order_by(&mut self, order_by: &'a OrderBy) {
         visit_order_by(self, order_by);
     }
@@ -162,6 +212,10 @@ pub trait Visit<'a> {
         visit_where(self, r#where);
     }
 
+    fn visit_window(&mut self, window: &'a Window) {
+        visit_window(self, window);
+    }
+
     fn visit_with(&mut self, with: &'a With) {
         visit_with(self, with);
     }
@@ -212,6 +266,10 @@ pub trait Visit<'a> {
     }
 
     // Part nodes
+    fn visit_distinct(&mut self, distinct: &'a Distinct) {
+        visit_distinct(self, distinct);
+    }
+
     fn visit_table_path(&mut self, table_path: &'a TablePath) {
         visit_table_path(self, table_path);
     }
@@ -220,11 +278,19 @@ pub trait Visit<'a> {
         visit_target_table(self, target_table);
     }
 
+    fn visit_window_spec(&mut self, window_spec: &'a WindowSpec) {
+        visit_window_spec(self, window_spec);
+    }
+
     // Query nodes
     fn visit_node(&mut self, node: &'a Node) {
         visit_node(self, node);
     }
 
+    fn visit_after(&mut self, after: &'a After) {
+        visit_after(self, after);
+    }
+
     // Statement node
     fn visit_statement(&mut self, statement: &'a Statement) {
         visit_statement(self, statement);
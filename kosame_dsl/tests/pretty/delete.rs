@@ -149,3 +149,25 @@ using
 }"
     );
 }
+
+#[test]
+fn delete_with_using_join_and_returning() {
+    assert_pretty!(Statement:
+        "{
+delete from schema::comments as c using schema::posts as p inner join schema::users as u on u.id = p.author_id where c.post_id = p.id and u.banned returning c.id, p.title, u.name
+}",
+        "{
+    delete from
+        schema::comments as c
+    using
+        schema::posts as p
+        inner join schema::users as u on u.id = p.author_id
+    where
+        c.post_id = p.id and u.banned
+    returning
+        c.id,
+        p.title,
+        u.name,
+}"
+    );
+}
@@ -0,0 +1,22 @@
+use kosame_dsl::schema::Function;
+
+use crate::pretty::assert_pretty;
+
+#[test]
+fn simple() {
+    assert_pretty!(Function:
+        "{ create  function slugify( text )  returns text }",
+        "{ create function slugify(text) returns text }"
+    );
+}
+
+#[test]
+fn named_args_strict() {
+    assert_pretty!(Function:
+        "{
+create function add(a int,b int) returns int
+strict;
+}",
+        "{ create function add(a int, b int) returns int strict; }"
+    );
+}
@@ -229,3 +229,95 @@ insert into schema::posts values (1, \"very long title one\", \"very long conten
 }"
     );
 }
+
+#[test]
+fn insert_on_conflict_do_nothing() {
+    assert_pretty!(Statement:
+        "{
+insert into schema::posts values (0, \"title\") on conflict (id) where id > 0 do nothing
+}",
+        "{
+    insert into
+        schema::posts
+    values
+        (0, \"title\"),
+    on conflict (id) where id > 0
+        do nothing
+}"
+    );
+}
+
+#[test]
+fn insert_on_conflict_do_update() {
+    assert_pretty!(Statement:
+        "{
+insert into schema::posts values (0, \"title\", 1)
+on conflict on constraint posts_pkey do update
+set title = excluded.title, upvotes = posts.upvotes + excluded.upvotes
+where posts.upvotes < excluded.upvotes
+returning posts.id
+}",
+        "{
+    insert into
+        schema::posts
+    values
+        (0, \"title\", 1),
+    on conflict on constraint posts_pkey
+        do update
+    set
+        title = excluded.title,
+        upvotes = posts.upvotes + excluded.upvotes,
+    where
+        posts.upvotes < excluded.upvotes
+    returning
+        posts.id,
+}"
+    );
+}
+
+#[test]
+fn insert_on_conflict_inline() {
+    assert_pretty!(Statement:
+        "{ insert into posts values (0)   on conflict   do nothing }",
+        "{ insert into posts values (0) on conflict do nothing }"
+    );
+}
+
+#[test]
+fn insert_column_list() {
+    assert_pretty!(Statement:
+        "{ insert into schema::posts (title,content) values (:title, :content) }",
+        "{
+    insert into
+        schema::posts (title, content)
+    values
+        (:title, :content),
+}"
+    );
+}
+
+#[test]
+fn insert_select() {
+    assert_pretty!(Statement:
+        "{ insert into schema::posts (title, content) select drafts.title, drafts.content from schema::drafts where drafts.ready }",
+        "{
+    insert into
+        schema::posts (title, content)
+    select
+        drafts.title,
+        drafts.content,
+    from
+        schema::drafts
+    where
+        drafts.ready
+}"
+    );
+}
+
+#[test]
+fn insert_default_values() {
+    assert_pretty!(Statement:
+        "{ insert into schema::posts   default values }",
+        "{ insert into schema::posts default values }"
+    );
+}
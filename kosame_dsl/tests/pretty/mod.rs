@@ -1,4 +1,5 @@
 mod delete;
+mod function;
 mod insert;
 mod query;
 mod r#select;
@@ -84,6 +84,64 @@ schema::posts {
     );
 }
 
+#[test]
+fn recursive_relation() {
+    assert_pretty!(Query:
+        "{
+schema::categories {
+    id,
+    children recursive ( depth=5 ){id,name,order by name asc},
+}
+}",
+        "{
+    schema::categories {
+        id,
+        children recursive(depth = 5) { id, name order by name asc },
+    }
+}"
+    );
+}
+
+#[test]
+fn after() {
+    assert_pretty!(Query:
+        "{ schema::posts { id, after(ts,id)=:cursor order by ts, id limit 20 } }",
+        "{
+    schema::posts { id after (ts, id) = :cursor order by ts, id limit 20 }
+}"
+    );
+}
+
+#[test]
+fn correlated_where() {
+    assert_pretty!(Query:
+        "{ schema::posts { comments { id, where created_at>posts.published_at limit 5 } } }",
+        "{
+    schema::posts { comments { id where created_at > posts.published_at limit 5 } }
+}"
+    );
+}
+
+#[test]
+fn distinct_on() {
+    assert_pretty!(Query:
+        "{ schema::comments { distinct on(post_id,author_id) id, order by post_id } }",
+        "{
+    schema::comments { distinct on (post_id, author_id) id order by post_id }
+}"
+    );
+}
+
+#[test]
+fn group_by_having() {
+    assert_pretty!(Query:
+        "{ schema::comments { post_id, group by post_id having count(id)>1 } }",
+        "{
+    schema::comments { post_id group by post_id having count(id) > 1 }
+}"
+    );
+}
+
 #[test]
 fn with_attributes() {
     assert_pretty!(Query:
@@ -49,6 +49,190 @@ select posts.id, from schema::posts where id = :post_id
     );
 }
 
+#[test]
+fn select_with_typed_bind_param() {
+    assert_pretty!(Statement:
+        "{ select id from posts where title = :t  :  String }",
+        "{ select id from posts where title = :t: String }"
+    );
+}
+
+#[test]
+fn select_with_optional_bind_param() {
+    assert_pretty!(Statement:
+        "{ select id from posts where title = :t ?  :  String }",
+        "{ select id from posts where title = :t?: String }"
+    );
+}
+
+#[test]
+fn select_with_in() {
+    assert_pretty!(Statement:
+        "{ select id, from schema::posts where id not in(1,2,3) }",
+        "{ select id from schema::posts where id not in (1, 2, 3) }"
+    );
+}
+
+#[test]
+fn select_with_in_bind_param() {
+    assert_pretty!(Statement:
+        "{ select id, from schema::posts where id not in   :ids }",
+        "{ select id from schema::posts where id not in :ids }"
+    );
+}
+
+#[test]
+fn select_with_any() {
+    assert_pretty!(Statement:
+        "{ select id, from schema::posts where id = any( :ids ) }",
+        "{ select id from schema::posts where id = any(:ids) }"
+    );
+}
+
+#[test]
+fn select_with_between() {
+    assert_pretty!(Statement:
+        "{ select id, from schema::posts where id between :a and:b }",
+        "{ select id from schema::posts where id between :a and :b }"
+    );
+}
+
+#[test]
+fn select_with_like() {
+    assert_pretty!(Statement:
+        "{ select id, from schema::posts where title not ilike   :p }",
+        "{ select id from schema::posts where title not ilike :p }"
+    );
+}
+
+#[test]
+fn select_with_count_star() {
+    assert_pretty!(Statement:
+        "{ select count( * ) as total from schema::posts }",
+        "{ select count(*) as total from schema::posts }"
+    );
+}
+
+#[test]
+fn select_with_declared_function() {
+    assert_pretty!(Statement:
+        "{ select schema::slugify(title) from schema::posts }",
+        "{ select schema::slugify(title) from schema::posts }"
+    );
+}
+
+#[test]
+fn select_with_filter() {
+    assert_pretty!(Statement:
+        "{ select count(*) filter(where  id > 1) from posts }",
+        "{ select count(*) filter (where id > 1) from posts }"
+    );
+}
+
+#[test]
+fn select_with_window_function() {
+    assert_pretty!(Statement:
+        "{
+select row_number() over(partition by comments.post_id order by comments.upvotes desc rows between unbounded preceding and current row) as rn,
+from schema::comments
+}",
+        "{
+    select
+        row_number() over (
+                partition by comments.post_id
+                order by comments.upvotes desc
+                rows between unbounded preceding and current row
+            ) as rn,
+    from
+        schema::comments
+}"
+    );
+}
+
+#[test]
+fn select_with_window_clause() {
+    assert_pretty!(Statement:
+        "{
+select rank() over w as rnk, from schema::comments window w as (partition by post_id)
+}",
+        "{
+    select
+        rank() over w as rnk,
+    from
+        schema::comments
+    window
+        w as (partition by post_id),
+}"
+    );
+}
+
+#[test]
+fn select_with_case() {
+    assert_pretty!(Statement:
+        "{ select case  id when 1 then  true end as a: bool, }",
+        "{ select case id when 1 then true end as a: bool }"
+    );
+}
+
+#[test]
+fn select_with_case_break() {
+    assert_pretty!(Statement:
+        "{
+select case when posts.upvotes > 100 then \"very popular\" when posts.upvotes > 10 then \"well known\" end as label: String,
+from schema::posts
+}",
+        "{
+    select
+        case
+            when posts.upvotes > 100 then \"very popular\"
+            when posts.upvotes > 10 then \"well known\"
+        end as label: String,
+    from
+        schema::posts
+}"
+    );
+}
+
+#[test]
+fn select_with_exists() {
+    assert_pretty!(Statement:
+        "{ select id from p where not exists(select 1 from t) }",
+        "{ select id from p where not exists (select 1 from t) }"
+    );
+}
+
+#[test]
+fn select_with_in_subquery() {
+    assert_pretty!(Statement:
+        "{ select id from posts where id in(select id from t) }",
+        "{ select id from posts where id in (select id from t) }"
+    );
+}
+
+#[test]
+fn select_with_scalar_subquery() {
+    assert_pretty!(Statement:
+        "{
+select id, (select max(comments.upvotes) from schema::comments where comments.post_id = posts.id) as top_upvotes,
+from schema::posts
+}",
+        "{
+    select
+        id,
+        (
+            select
+                max(comments.upvotes),
+            from
+                schema::comments
+            where
+                comments.post_id = posts.id
+        ) as top_upvotes,
+    from
+        schema::posts
+}"
+    );
+}
+
 #[test]
 fn select_with_joins() {
     assert_pretty!(Statement:
@@ -224,3 +408,178 @@ fn union_all() {
 }"
     );
 }
+
+#[test]
+fn select_with_json_operators() {
+    assert_pretty!(Statement:
+        "{ select data->\"a\" as a, data ->>\"b\" as b, from schema::posts where data #> :path ?| :keys }",
+        "{
+    select
+        data -> \"a\" as a,
+        data ->> \"b\" as b,
+    from
+        schema::posts
+    where
+        data #> :path ?| :keys
+}"
+    );
+}
+
+#[test]
+fn select_with_json_containment() {
+    assert_pretty!(Statement:
+        "{
+    select
+        id,
+    from
+        posts
+    where
+        data @> :doc and (:doc) ? \"k\"
+}",
+        "{
+    select
+        id,
+    from
+        posts
+    where
+        data @> :doc and (:doc) ? \"k\"
+}"
+    );
+}
+
+#[test]
+fn select_with_arrays() {
+    assert_pretty!(Statement:
+        "{
+    select tags[1] as first, tags [ 2 : ] as rest, tags[:n], array[id, 2] as ids,
+    array(select id from comments) as comment_ids,
+    tags || array[\"x\"] as appended,
+    from posts
+    where tags && :tags and tags @> array[\"a\"]
+}",
+        "{
+    select
+        tags[1] as first,
+        tags[2:] as rest,
+        tags[:n],
+        array[id, 2] as ids,
+        array(select id from comments) as comment_ids,
+        tags || array[\"x\"] as appended,
+    from
+        posts
+    where
+        tags && :tags and tags @> array[\"a\"]
+}"
+    );
+}
+
+#[test]
+fn select_from_unnest() {
+    assert_pretty!(Statement:
+        "{
+    select posts.id, tag.tag from posts cross join unnest(posts.tags) as tag
+}",
+        "{
+    select
+        posts.id,
+        tag.tag,
+    from
+        posts
+        cross join unnest(posts.tags) as tag
+}"
+    );
+}
+
+#[test]
+fn select_with_raw_interpolation() {
+    assert_pretty!(Statement:
+        "{
+    select $\"ts_rank({ posts.search }, plainto_tsquery({:q}))\" as rank: f32 from posts
+}",
+        "{
+    select
+        $\"ts_rank({ posts.search }, plainto_tsquery({:q}))\" as rank: f32,
+    from
+        posts
+}"
+    );
+}
+
+#[test]
+fn select_with_temporal_expressions() {
+    assert_pretty!(Statement:
+        "{
+    select extract( year from created_at ) as year, timestamptz \"2024-01-01 00:00:00+00\" as start
+    from posts
+    where created_at > now() - interval   \"7 days\" and day <> date \"2024-01-01\"
+}",
+        "{
+    select
+        extract(year from created_at) as year,
+        timestamptz \"2024-01-01 00:00:00+00\" as start,
+    from
+        posts
+    where
+        created_at > now() - interval \"7 days\" and day <> date \"2024-01-01\"
+}"
+    );
+}
+
+#[test]
+fn select_with_unary_and_bitwise_operators() {
+    assert_pretty!(Statement:
+        "{
+    select -1 as a, - -id as b, +id as c, -2 ^ 2 as d, id&1 as e, id | 8 as f, id # 3 as g,
+    1 << id as h, id >> 1 as i
+}",
+        "{
+    select
+        -1 as a,
+        --id as b,
+        +id as c,
+        -2 ^ 2 as d,
+        id & 1 as e,
+        id | 8 as f,
+        id # 3 as g,
+        1 << id as h,
+        id >> 1 as i,
+}"
+    );
+}
+
+#[test]
+fn select_with_concat_and_collate() {
+    assert_pretty!(Statement:
+        "{
+    select first || \" \" || last as name from people
+    order by last collate \"C\" desc
+}",
+        "{
+    select
+        first || \" \" || last as name,
+    from
+        people
+    order by
+        last collate \"C\" desc,
+}"
+    );
+}
+
+#[test]
+fn select_with_text_search() {
+    assert_pretty!(Statement:
+        "{
+    select posts.id, ts_rank(posts.search, plainto_tsquery(\"english\", :query)) as rank
+    from posts where posts.search@@to_tsquery(:query)
+}",
+        "{
+    select
+        posts.id,
+        ts_rank(posts.search, plainto_tsquery(\"english\", :query)) as rank,
+    from
+        posts
+    where
+        posts.search @@ to_tsquery(:query)
+}"
+    );
+}
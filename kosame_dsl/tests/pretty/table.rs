@@ -50,6 +50,28 @@ col int primary key,
     );
 }
 
+#[test]
+fn array_columns() {
+    assert_pretty!(Table:
+        "{
+create table test (tags text [ ] not null, grid int[][]);
+}",
+        "{ create table test (tags text[] not null, grid int[][]); }"
+    );
+}
+
+#[test]
+fn array_column_with_not_null_elements() {
+    assert_pretty!(Table:
+        "{
+create table test (#[kosame(elements_not_null)] tags text[] not null);
+}",
+        "{
+    create table test (#[kosame(elements_not_null)] tags text[] not null);
+}"
+    );
+}
+
 #[test]
 fn multi_column_break() {
     assert_pretty!(Table:
@@ -251,6 +273,21 @@ col2 int default 5 // after default
     );
 }
 
+#[test]
+fn relation_with_literal() {
+    assert_pretty!(Table:
+        "{
+create table test ( col int primary key);
+rel : (col,\"test\") <=other(owner_id,owner_type),
+}",
+        "{
+    create table test (col int primary key);
+
+    rel: (col, \"test\") <= other (owner_id, owner_type),
+}"
+    );
+}
+
 #[test]
 fn line_comments_with_relations() {
     assert_pretty!(Table:
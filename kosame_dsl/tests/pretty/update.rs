@@ -189,3 +189,27 @@ returning
 }"
     );
 }
+
+#[test]
+fn update_with_from() {
+    assert_pretty!(Statement:
+        "{
+update schema::comments set upvotes = comments.upvotes + posts.upvotes from schema::posts left join schema::users on users.id = posts.author_id where comments.post_id = posts.id returning comments.id, posts.title, users.name
+}",
+        "{
+    update
+        schema::comments
+    set
+        upvotes = comments.upvotes + posts.upvotes,
+    from
+        schema::posts
+        left join schema::users on users.id = posts.author_id
+    where
+        comments.post_id = posts.id
+    returning
+        comments.id,
+        posts.title,
+        users.name,
+}"
+    );
+}
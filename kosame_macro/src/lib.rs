@@ -9,6 +9,13 @@ pub fn table(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     quote! { #input }.into()
 }
 
+#[proc_macro_error]
+#[proc_macro]
+pub fn function(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(tokens as kosame_dsl::schema::Function);
+    quote! { #input }.into()
+}
+
 #[proc_macro_error]
 #[proc_macro]
 pub fn statement(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -24,7 +31,7 @@ pub fn query(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
 }
 
 #[proc_macro_error]
-#[proc_macro_derive(Row, attributes(star))]
+#[proc_macro_derive(Row, attributes(star, recursive))]
 pub fn derive_row(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(tokens as DeriveInput);
     let name = &input.ident;
@@ -87,6 +94,29 @@ pub fn derive_row(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
             }
         }
         .to_tokens(&mut tokens);
+
+        // Rows of recursive relations are nested into themselves behind a `Box`.
+        let recursive = input
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident("recursive"));
+        if recursive {
+            quote! {
+                impl<'a> ::kosame::driver::postgres_types::FromSql<'a> for Box<#name> {
+                    fn accepts(ty: &::kosame::driver::postgres_types::Type) -> bool {
+                        <#name as ::kosame::driver::postgres_types::FromSql<'a>>::accepts(ty)
+                    }
+
+                    fn from_sql(
+                        ty: &::kosame::driver::postgres_types::Type,
+                        raw: &'a [u8],
+                    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+                        <#name as ::kosame::driver::postgres_types::FromSql<'a>>::from_sql(ty, raw).map(Box::new)
+                    }
+                }
+            }
+            .to_tokens(&mut tokens);
+        }
     }
 
     tokens.into()
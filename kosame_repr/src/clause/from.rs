@@ -1,6 +1,11 @@
 use std::fmt::Write;
 
-use crate::{Ident, command::Command, expr::Expr, part::TableAlias};
+use crate::{
+    Ident,
+    command::Command,
+    expr::{Call, Expr},
+    part::TableAlias,
+};
 
 pub struct From<'a> {
     chain: FromChain<'a>,
@@ -81,6 +86,10 @@ pub enum FromItem<'a> {
         command: &'a Command<'a>,
         alias: Option<TableAlias<'a>>,
     },
+    Function {
+        call: Call<'a>,
+        alias: Option<TableAlias<'a>>,
+    },
 }
 
 impl kosame_sql::FmtSql for FromItem<'_> {
@@ -112,6 +121,13 @@ impl kosame_sql::FmtSql for FromItem<'_> {
                     alias.fmt_sql(formatter)?;
                 }
             }
+            Self::Function { call, alias } => {
+                call.fmt_sql(formatter)?;
+                if let Some(alias) = alias {
+                    formatter.write_str(" as ")?;
+                    alias.fmt_sql(formatter)?;
+                }
+            }
         }
 
         Ok(())
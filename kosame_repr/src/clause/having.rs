@@ -26,6 +26,9 @@ impl kosame_sql::FmtSql for Having<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
+        if self.expr.is_omitted(formatter) {
+            return Ok(());
+        }
         formatter.write_str(" having ")?;
         self.expr.fmt_sql(formatter)?;
         Ok(())
@@ -4,12 +4,14 @@ mod group_by;
 mod having;
 mod limit;
 mod offset;
+mod on_conflict;
 mod order_by;
 mod returning;
 mod select;
 mod set;
 mod values;
 mod r#where;
+mod window;
 mod with;
 
 pub use field::*;
@@ -18,10 +20,12 @@ pub use group_by::*;
 pub use having::*;
 pub use limit::*;
 pub use offset::*;
+pub use on_conflict::*;
 pub use order_by::*;
 pub use returning::*;
 pub use select::*;
 pub use set::*;
 pub use values::*;
 pub use r#where::*;
+pub use window::*;
 pub use with::*;
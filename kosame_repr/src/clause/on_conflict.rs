@@ -0,0 +1,96 @@
+use std::fmt::Write;
+
+use crate::{
+    Ident,
+    clause::{Set, Where},
+    part::ColumnList,
+};
+
+pub struct OnConflict<'a> {
+    target: Option<ConflictTarget<'a>>,
+    action: ConflictAction<'a>,
+}
+
+impl<'a> OnConflict<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(target: Option<ConflictTarget<'a>>, action: ConflictAction<'a>) -> Self {
+        Self { target, action }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn target(&self) -> Option<&ConflictTarget<'a>> {
+        self.target.as_ref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn action(&self) -> &ConflictAction<'a> {
+        &self.action
+    }
+}
+
+impl kosame_sql::FmtSql for OnConflict<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        formatter.write_str(" on conflict")?;
+        self.target.fmt_sql(formatter)?;
+        self.action.fmt_sql(formatter)?;
+        Ok(())
+    }
+}
+
+pub enum ConflictTarget<'a> {
+    Columns {
+        columns: ColumnList<'a>,
+        r#where: Option<Where<'a>>,
+    },
+    Constraint(&'a str),
+}
+
+impl kosame_sql::FmtSql for ConflictTarget<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::Columns { columns, r#where } => {
+                columns.fmt_sql(formatter)?;
+                r#where.fmt_sql(formatter)?;
+            }
+            Self::Constraint(name) => {
+                formatter.write_str(" on constraint ")?;
+                Ident::new(name).fmt_sql(formatter)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub enum ConflictAction<'a> {
+    Nothing,
+    Update {
+        set: Set<'a>,
+        r#where: Option<Where<'a>>,
+    },
+}
+
+impl kosame_sql::FmtSql for ConflictAction<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::Nothing => formatter.write_str(" do nothing")?,
+            Self::Update { set, r#where } => {
+                formatter.write_str(" do update")?;
+                set.fmt_sql(formatter)?;
+                r#where.fmt_sql(formatter)?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -12,6 +12,12 @@ impl<'a> OrderBy<'a> {
     pub const fn new(items: &'a [OrderByItem]) -> Self {
         Self { items }
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn items(&self) -> &'a [OrderByItem<'a>] {
+        self.items
+    }
 }
 
 impl kosame_sql::FmtSql for OrderBy<'_> {
@@ -38,6 +44,24 @@ impl<'a> OrderByItem<'a> {
     pub const fn new(expr: Expr<'a>, dir: Option<OrderByDir>, nulls: Option<OrderByNulls>) -> Self {
         Self { expr, dir, nulls }
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn expr(&self) -> &Expr<'a> {
+        &self.expr
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn dir(&self) -> Option<OrderByDir> {
+        self.dir
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn nulls(&self) -> Option<OrderByNulls> {
+        self.nulls
+    }
 }
 
 impl kosame_sql::FmtSql for OrderByItem<'_> {
@@ -61,11 +85,13 @@ impl kosame_sql::FmtSql for OrderByItem<'_> {
     }
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum OrderByDir {
     Asc,
     Desc,
 }
 
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum OrderByNulls {
     First,
     Last,
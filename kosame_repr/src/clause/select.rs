@@ -1,6 +1,6 @@
 use std::fmt::Write;
 
-use crate::clause::{self, Fields, From, GroupBy, Having, Where};
+use crate::clause::{self, Fields, From, GroupBy, Having, Where, Window};
 
 pub struct Select<'a> {
     fields: Fields<'a>,
@@ -38,6 +38,7 @@ pub struct SelectCore<'a> {
     r#where: Option<Where<'a>>,
     group_by: Option<GroupBy<'a>>,
     having: Option<Having<'a>>,
+    window: Option<Window<'a>>,
 }
 
 impl<'a> SelectCore<'a> {
@@ -49,6 +50,7 @@ impl<'a> SelectCore<'a> {
         r#where: Option<Where<'a>>,
         group_by: Option<GroupBy<'a>>,
         having: Option<Having<'a>>,
+        window: Option<Window<'a>>,
     ) -> Self {
         Self {
             select,
@@ -56,6 +58,7 @@ impl<'a> SelectCore<'a> {
             r#where,
             group_by,
             having,
+            window,
         }
     }
 
@@ -88,6 +91,12 @@ impl<'a> SelectCore<'a> {
     pub const fn having(&self) -> Option<&Having<'a>> {
         self.having.as_ref()
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn window(&self) -> Option<&Window<'a>> {
+        self.window.as_ref()
+    }
 }
 
 impl kosame_sql::FmtSql for SelectCore<'_> {
@@ -100,6 +109,7 @@ impl kosame_sql::FmtSql for SelectCore<'_> {
         self.r#where.fmt_sql(formatter)?;
         self.group_by.fmt_sql(formatter)?;
         self.having.fmt_sql(formatter)?;
+        self.window.fmt_sql(formatter)?;
         Ok(())
     }
 }
@@ -26,8 +26,71 @@ impl kosame_sql::FmtSql for Where<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
+        if self.expr.is_omitted(formatter) {
+            return Ok(());
+        }
         formatter.write_str(" where ")?;
         self.expr.fmt_sql(formatter)?;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use kosame_sql::FmtSql;
+
+    use super::Where;
+    use crate::expr::{BinOp, Binary, BindParam, ColumnRef, Expr, Optional};
+
+    type Dialect = kosame_sql::postgres::Dialect;
+
+    const TITLE: Expr = Expr::ColumnRef(ColumnRef::new(None, "title"));
+    const UPVOTES: Expr = Expr::ColumnRef(ColumnRef::new(None, "upvotes"));
+    const SEARCH: [BindParam; 1] = [BindParam::new("search", 0)];
+    const MAX_UPVOTES: [BindParam; 1] = [BindParam::new("max_upvotes", 1)];
+    const SEARCH_EXPR: Expr = Expr::BindParam(BindParam::new("search", 0));
+    const MAX_UPVOTES_EXPR: Expr = Expr::BindParam(BindParam::new("max_upvotes", 1));
+    const SEARCH_COND: Expr = Expr::Binary(Binary::new(&TITLE, BinOp::Ilike, &SEARCH_EXPR));
+    const MAX_UPVOTES_COND: Expr = Expr::Binary(Binary::new(
+        &UPVOTES,
+        BinOp::LessThanOrEq,
+        &MAX_UPVOTES_EXPR,
+    ));
+    const SEARCH_OPTIONAL: Expr = Expr::Optional(Optional::new(&SEARCH, &SEARCH_COND));
+    const MAX_UPVOTES_OPTIONAL: Expr =
+        Expr::Optional(Optional::new(&MAX_UPVOTES, &MAX_UPVOTES_COND));
+    const WHERE: Where = Where::new(Expr::Binary(Binary::new(
+        &SEARCH_OPTIONAL,
+        BinOp::And,
+        &MAX_UPVOTES_OPTIONAL,
+    )));
+
+    fn to_sql(omitted_bind_params: &[u32]) -> String {
+        WHERE
+            .to_sql_string_with_omitted_bind_params::<Dialect>(omitted_bind_params)
+            .unwrap()
+    }
+
+    #[test]
+    fn no_omitted_bind_params() {
+        assert_eq!(
+            to_sql(&[]),
+            r#" where "title" ilike $1 and "upvotes" <= $2"#
+        );
+    }
+
+    #[test]
+    fn omitted_bind_param_renumbers_the_rest() {
+        assert_eq!(to_sql(&[0]), r#" where "upvotes" <= $1"#);
+    }
+
+    #[test]
+    fn trailing_omitted_bind_param() {
+        assert_eq!(to_sql(&[1]), r#" where "title" ilike $1"#);
+    }
+
+    #[test]
+    fn all_bind_params_omitted() {
+        assert_eq!(to_sql(&[0, 1]), "");
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt::Write;
+
+use crate::{Ident, part::WindowSpec};
+
+pub struct Window<'a> {
+    items: &'a [WindowItem<'a>],
+}
+
+impl<'a> Window<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(items: &'a [WindowItem]) -> Self {
+        Self { items }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn items(&self) -> &'a [WindowItem<'a>] {
+        self.items
+    }
+}
+
+impl kosame_sql::FmtSql for Window<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str(" window ")?;
+        kosame_sql::Punctuated::new(self.items, ",").fmt_sql(formatter)?;
+        Ok(())
+    }
+}
+
+pub struct WindowItem<'a> {
+    name: &'a str,
+    spec: WindowSpec<'a>,
+}
+
+impl<'a> WindowItem<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(name: &'a str, spec: WindowSpec<'a>) -> Self {
+        Self { name, spec }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn spec(&self) -> &WindowSpec<'a> {
+        &self.spec
+    }
+}
+
+impl kosame_sql::FmtSql for WindowItem<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        Ident::new(self.name).fmt_sql(formatter)?;
+        formatter.write_str(" as (")?;
+        self.spec.fmt_sql(formatter)?;
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
@@ -1,13 +1,16 @@
 use std::fmt::Write;
 
 use crate::{
-    clause::{Returning, Values},
-    part::TargetTable,
+    clause::{OnConflict, Returning, Values},
+    command::Command,
+    part::{ColumnList, TargetTable},
 };
 
 pub struct Insert<'a> {
     target_table: TargetTable<'a>,
-    values: Values<'a>,
+    columns: Option<ColumnList<'a>>,
+    source: InsertSource<'a>,
+    on_conflict: Option<OnConflict<'a>>,
     returning: Option<Returning<'a>>,
 }
 
@@ -16,12 +19,16 @@ impl<'a> Insert<'a> {
     #[must_use]
     pub const fn new(
         target_table: TargetTable<'a>,
-        values: Values<'a>,
+        columns: Option<ColumnList<'a>>,
+        source: InsertSource<'a>,
+        on_conflict: Option<OnConflict<'a>>,
         returning: Option<Returning<'a>>,
     ) -> Self {
         Self {
             target_table,
-            values,
+            columns,
+            source,
+            on_conflict,
             returning,
         }
     }
@@ -34,8 +41,20 @@ impl<'a> Insert<'a> {
 
     #[inline]
     #[must_use]
-    pub const fn values(&self) -> &Values<'a> {
-        &self.values
+    pub const fn columns(&self) -> Option<&ColumnList<'a>> {
+        self.columns.as_ref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn source(&self) -> &InsertSource<'a> {
+        &self.source
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn on_conflict(&self) -> Option<&OnConflict<'a>> {
+        self.on_conflict.as_ref()
     }
 
     #[inline]
@@ -52,10 +71,35 @@ impl kosame_sql::FmtSql for Insert<'_> {
     {
         formatter.write_str("insert into ")?;
         self.target_table.fmt_sql(formatter)?;
+        self.columns.fmt_sql(formatter)?;
 
-        self.values.fmt_sql(formatter)?;
+        self.source.fmt_sql(formatter)?;
+        self.on_conflict.fmt_sql(formatter)?;
         self.returning.fmt_sql(formatter)?;
 
         Ok(())
     }
 }
+
+pub enum InsertSource<'a> {
+    Values(Values<'a>),
+    Select(&'a Command<'a>),
+    DefaultValues,
+}
+
+impl kosame_sql::FmtSql for InsertSource<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::Values(values) => values.fmt_sql(formatter)?,
+            Self::Select(command) => {
+                formatter.write_str(" ")?;
+                command.fmt_sql(formatter)?;
+            }
+            Self::DefaultValues => formatter.write_str(" default values")?,
+        }
+        Ok(())
+    }
+}
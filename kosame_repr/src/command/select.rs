@@ -95,6 +95,8 @@ impl kosame_sql::FmtSql for SelectChain<'_> {
     }
 }
 
+// Select items are only ever constructed in constants, so boxing the core would not save anything.
+#[allow(clippy::large_enum_variant)]
 pub enum SelectItem<'a> {
     Core(SelectCore<'a>),
     Paren(&'a Command<'a>),
@@ -0,0 +1,34 @@
+use std::fmt::Write;
+
+use crate::command::Command;
+
+use super::Expr;
+
+pub enum Array<'a> {
+    /// `array[1, 2, 3]`
+    List(&'a [Expr<'a>]),
+    /// `array(select id from posts)`
+    Subquery(&'a Command<'a>),
+}
+
+impl kosame_sql::FmtSql for Array<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        match self {
+            Self::List(elements) => {
+                formatter.write_str("array[")?;
+                kosame_sql::Punctuated::new(elements, ",").fmt_sql(formatter)?;
+                formatter.write_str("]")?;
+            }
+            Self::Subquery(command) => {
+                formatter.write_str("array(")?;
+                command.fmt_sql(formatter)?;
+                formatter.write_str(")")?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Between<'a> {
+    lhs: &'a Expr<'a>,
+    not: bool,
+    low: &'a Expr<'a>,
+    high: &'a Expr<'a>,
+}
+
+impl<'a> Between<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(lhs: &'a Expr<'a>, not: bool, low: &'a Expr<'a>, high: &'a Expr<'a>) -> Self {
+        Self {
+            lhs,
+            not,
+            low,
+            high,
+        }
+    }
+}
+
+impl kosame_sql::FmtSql for Between<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.lhs.fmt_sql(formatter)?;
+        if self.not {
+            formatter.write_str(" not")?;
+        }
+        formatter.write_str(" between ")?;
+        self.low.fmt_sql(formatter)?;
+        formatter.write_str(" and ")?;
+        self.high.fmt_sql(formatter)?;
+        Ok(())
+    }
+}
@@ -14,6 +14,24 @@ impl<'a> Binary<'a> {
     pub const fn new(left: &'a Expr<'a>, op: BinOp, right: &'a Expr<'a>) -> Self {
         Self { left, op, right }
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn left(&self) -> &'a Expr<'a> {
+        self.left
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn op(&self) -> &BinOp {
+        &self.op
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn right(&self) -> &'a Expr<'a> {
+        self.right
+    }
 }
 
 impl kosame_sql::FmtSql for Binary<'_> {
@@ -22,6 +40,15 @@ impl kosame_sql::FmtSql for Binary<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> std::fmt::Result {
+        // One side of a conjunction may be left out because of omitted bind parameters.
+        if let BinOp::And = self.op {
+            if self.left.is_omitted(formatter) {
+                return self.right.fmt_sql(formatter);
+            }
+            if self.right.is_omitted(formatter) {
+                return self.left.fmt_sql(formatter);
+            }
+        }
         self.left.fmt_sql(formatter)?;
         self.op.fmt_sql(formatter)?;
         self.right.fmt_sql(formatter)?;
@@ -30,6 +57,8 @@ impl kosame_sql::FmtSql for Binary<'_> {
 }
 
 pub enum BinOp {
+    // exponentiation
+    Power,
     // multiplication, division, modulo
     Multiply,
     Divide,
@@ -37,6 +66,34 @@ pub enum BinOp {
     // addition, subtraction
     Add,
     Subtract,
+    // json access
+    JsonGet,
+    JsonGetText,
+    JsonPath,
+    JsonPathText,
+    // containment
+    Contains,
+    ContainedBy,
+    Overlaps,
+    // text search
+    Match,
+    // json key existence
+    HasKey,
+    HasAnyKey,
+    HasAllKeys,
+    // concatenation
+    Concat,
+    // bitwise operators
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    // pattern matching
+    Like,
+    NotLike,
+    Ilike,
+    NotIlike,
     // comparison operators
     Eq,
     Uneq,
@@ -60,11 +117,33 @@ impl kosame_sql::FmtSql for BinOp {
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
         match self {
+            Self::Power => formatter.write_str(" ^ "),
             Self::Multiply => formatter.write_str(" * "),
             Self::Divide => formatter.write_str(" / "),
             Self::Modulo => formatter.write_str(" % "),
             Self::Add => formatter.write_str(" + "),
             Self::Subtract => formatter.write_str(" - "),
+            Self::JsonGet => formatter.write_str(" -> "),
+            Self::JsonGetText => formatter.write_str(" ->> "),
+            Self::JsonPath => formatter.write_str(" #> "),
+            Self::JsonPathText => formatter.write_str(" #>> "),
+            Self::Contains => formatter.write_str(" @> "),
+            Self::ContainedBy => formatter.write_str(" <@ "),
+            Self::HasKey => formatter.write_str(" ? "),
+            Self::HasAnyKey => formatter.write_str(" ?| "),
+            Self::HasAllKeys => formatter.write_str(" ?& "),
+            Self::Overlaps => formatter.write_str(" && "),
+            Self::Match => formatter.write_str(" @@ "),
+            Self::Concat => formatter.write_str(" || "),
+            Self::BitAnd => formatter.write_str(" & "),
+            Self::BitOr => formatter.write_str(" | "),
+            Self::BitXor => formatter.write_str(" # "),
+            Self::ShiftLeft => formatter.write_str(" << "),
+            Self::ShiftRight => formatter.write_str(" >> "),
+            Self::Like => formatter.write_str(" like "),
+            Self::NotLike => formatter.write_str(" not like "),
+            Self::Ilike => formatter.write_str(" ilike "),
+            Self::NotIlike => formatter.write_str(" not ilike "),
             Self::Eq => formatter.write_str(" = "),
             Self::Uneq => formatter.write_str(" <> "),
             Self::LessThan => formatter.write_str(" < "),
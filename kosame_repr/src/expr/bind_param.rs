@@ -9,6 +9,18 @@ impl<'a> BindParam<'a> {
     pub const fn new(name: &'a str, ordinal: u32) -> Self {
         Self { name, ordinal }
     }
+
+    #[inline]
+    #[must_use]
+    pub const fn name(&self) -> &'a str {
+        self.name
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn ordinal(&self) -> u32 {
+        self.ordinal
+    }
 }
 
 impl kosame_sql::FmtSql for BindParam<'_> {
@@ -17,6 +29,7 @@ impl kosame_sql::FmtSql for BindParam<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
-        D::fmt_bind_param(formatter, self.name, self.ordinal)
+        let ordinal = formatter.bind_param_ordinal(self.ordinal);
+        D::fmt_bind_param(formatter, self.name, ordinal)
     }
 }
@@ -1,23 +1,36 @@
 use std::fmt::Write;
 
-use crate::Ident;
+use crate::{Ident, part::Over};
 
 use super::Expr;
 
 pub struct Call<'a> {
     function: &'a str,
     params: &'a [Expr<'a>],
+    star: bool,
     keyword: bool,
+    filter: Option<&'a Expr<'a>>,
+    over: Option<&'a Over<'a>>,
 }
 
 impl<'a> Call<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(function: &'a str, params: &'a [Expr], keyword: bool) -> Self {
+    pub const fn new(
+        function: &'a str,
+        params: &'a [Expr],
+        star: bool,
+        keyword: bool,
+        filter: Option<&'a Expr<'a>>,
+        over: Option<&'a Over<'a>>,
+    ) -> Self {
         Self {
             function,
             params,
+            star,
             keyword,
+            filter,
+            over,
         }
     }
 }
@@ -37,8 +50,19 @@ impl kosame_sql::FmtSql for Call<'_> {
         }
 
         formatter.write_str("(")?;
+        if self.star {
+            formatter.write_str("*")?;
+        }
         kosame_sql::Punctuated::new(self.params, ",").fmt_sql(formatter)?;
         formatter.write_str(")")?;
+        if let Some(filter) = self.filter {
+            formatter.write_str(" filter (where ")?;
+            filter.fmt_sql(formatter)?;
+            formatter.write_str(")")?;
+        }
+        if let Some(over) = self.over {
+            over.fmt_sql(formatter)?;
+        }
         Ok(())
     }
 }
@@ -0,0 +1,75 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Case<'a> {
+    operand: Option<&'a Expr<'a>>,
+    whens: &'a [When<'a>],
+    r#else: Option<&'a Expr<'a>>,
+}
+
+impl<'a> Case<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        operand: Option<&'a Expr<'a>>,
+        whens: &'a [When<'a>],
+        r#else: Option<&'a Expr<'a>>,
+    ) -> Self {
+        Self {
+            operand,
+            whens,
+            r#else,
+        }
+    }
+}
+
+impl kosame_sql::FmtSql for Case<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str("case")?;
+        if let Some(operand) = self.operand {
+            formatter.write_str(" ")?;
+            operand.fmt_sql(formatter)?;
+        }
+        for when in self.whens {
+            when.fmt_sql(formatter)?;
+        }
+        if let Some(r#else) = self.r#else {
+            formatter.write_str(" else ")?;
+            r#else.fmt_sql(formatter)?;
+        }
+        formatter.write_str(" end")?;
+        Ok(())
+    }
+}
+
+pub struct When<'a> {
+    condition: &'a Expr<'a>,
+    result: &'a Expr<'a>,
+}
+
+impl<'a> When<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(condition: &'a Expr<'a>, result: &'a Expr<'a>) -> Self {
+        Self { condition, result }
+    }
+}
+
+impl kosame_sql::FmtSql for When<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str(" when ")?;
+        self.condition.fmt_sql(formatter)?;
+        formatter.write_str(" then ")?;
+        self.result.fmt_sql(formatter)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Collate<'a> {
+    expr: &'a Expr<'a>,
+    collation: &'a str,
+}
+
+impl<'a> Collate<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(expr: &'a Expr<'a>, collation: &'a str) -> Self {
+        Self { expr, collation }
+    }
+}
+
+impl kosame_sql::FmtSql for Collate<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.expr.fmt_sql(formatter)?;
+        formatter.write_str(" collate ")?;
+        D::fmt_ident(formatter, self.collation)
+    }
+}
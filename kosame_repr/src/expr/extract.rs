@@ -0,0 +1,31 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Extract<'a> {
+    field: &'a str,
+    source: &'a Expr<'a>,
+}
+
+impl<'a> Extract<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(field: &'a str, source: &'a Expr) -> Self {
+        Self { field, source }
+    }
+}
+
+impl kosame_sql::FmtSql for Extract<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        formatter.write_str("extract(")?;
+        formatter.write_str(self.field)?;
+        formatter.write_str(" from ")?;
+        self.source.fmt_sql(formatter)?;
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
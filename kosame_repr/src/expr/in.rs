@@ -0,0 +1,168 @@
+use std::fmt::Write;
+
+use crate::command::Command;
+
+use super::{BindParam, Expr};
+
+pub struct In<'a> {
+    lhs: &'a Expr<'a>,
+    not: bool,
+    list: InList<'a>,
+}
+
+impl<'a> In<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(lhs: &'a Expr<'a>, not: bool, list: InList<'a>) -> Self {
+        Self { lhs, not, list }
+    }
+}
+
+pub enum InList<'a> {
+    Exprs(&'a [Expr<'a>]),
+    Subquery(&'a Command<'a>),
+    BindParam(BindParam<'a>),
+}
+
+impl kosame_sql::FmtSql for In<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.lhs.fmt_sql(formatter)?;
+        match &self.list {
+            InList::Exprs(exprs) => {
+                if self.not {
+                    formatter.write_str(" not")?;
+                }
+                formatter.write_str(" in (")?;
+                kosame_sql::Punctuated::new(exprs, ",").fmt_sql(formatter)?;
+                formatter.write_str(")")?;
+            }
+            InList::Subquery(command) => {
+                if self.not {
+                    formatter.write_str(" not")?;
+                }
+                formatter.write_str(" in (")?;
+                command.fmt_sql(formatter)?;
+                formatter.write_str(")")?;
+            }
+            InList::BindParam(bind_param) if D::SUPPORTS_ARRAYS => {
+                // The whole list is bound as a single array parameter.
+                if self.not {
+                    formatter.write_str(" <> all(")?;
+                } else {
+                    formatter.write_str(" = any(")?;
+                }
+                bind_param.fmt_sql(formatter)?;
+                formatter.write_str(")")?;
+            }
+            InList::BindParam(bind_param) => {
+                // Without arrays, each element of the list gets its own placeholder. An empty
+                // list cannot be expressed this way.
+                let len = formatter
+                    .list_len(bind_param.ordinal())
+                    .filter(|len| *len > 0)
+                    .ok_or(std::fmt::Error)?;
+                if self.not {
+                    formatter.write_str(" not")?;
+                }
+                formatter.write_str(" in (")?;
+                let ordinal = formatter.bind_param_ordinal(bind_param.ordinal());
+                for index in 0..len {
+                    if index > 0 {
+                        formatter.write_str(", ")?;
+                    }
+                    D::fmt_bind_param(
+                        formatter,
+                        &format!("{}_{index}", bind_param.name()),
+                        ordinal + u32::try_from(index).expect("list length should fit into u32"),
+                    )?;
+                }
+                formatter.write_str(")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use kosame_sql::FmtSql;
+
+    use super::{In, InList};
+    use crate::expr::{BinOp, Binary, BindParam, ColumnRef, Expr};
+
+    /// A dialect without arrays that numbers its placeholders, to check the renumbering.
+    struct Numbered;
+
+    impl kosame_sql::Dialect for Numbered {
+        const SUPPORTS_ARRAYS: bool = false;
+
+        fn fmt_ident(formatter: &mut impl Write, name: &str) -> std::fmt::Result {
+            write!(formatter, "\"{name}\"")
+        }
+
+        fn fmt_bind_param(
+            formatter: &mut impl Write,
+            _name: &str,
+            ordinal: u32,
+        ) -> std::fmt::Result {
+            write!(formatter, "${}", ordinal + 1)
+        }
+    }
+
+    const ID: Expr = Expr::ColumnRef(ColumnRef::new(None, "id"));
+    const UPVOTES: Expr = Expr::ColumnRef(ColumnRef::new(None, "upvotes"));
+    const IN: Expr = Expr::In(In::new(
+        &ID,
+        false,
+        InList::BindParam(BindParam::new("ids", 0)),
+    ));
+    const MIN_UPVOTES: Expr = Expr::BindParam(BindParam::new("min_upvotes", 1));
+    const GREATER: Expr = Expr::Binary(Binary::new(&UPVOTES, BinOp::GreaterThan, &MIN_UPVOTES));
+    const CONDITION: Expr = Expr::Binary(Binary::new(&IN, BinOp::And, &GREATER));
+
+    #[test]
+    fn postgres_binds_an_array() {
+        assert_eq!(
+            CONDITION
+                .to_sql_string::<kosame_sql::postgres::Dialect>()
+                .unwrap(),
+            r#""id" = any($1) and "upvotes" > $2"#
+        );
+    }
+
+    #[test]
+    fn expands_to_named_placeholders() {
+        assert_eq!(
+            CONDITION
+                .to_sql_string_with_bind_params::<kosame_sql::sqlite::Dialect>(&[], &[(0, 3)])
+                .unwrap(),
+            r#""id" in (:ids_0, :ids_1, :ids_2) and "upvotes" > :min_upvotes"#
+        );
+    }
+
+    #[test]
+    fn renumbers_after_expanded_list() {
+        assert_eq!(
+            CONDITION
+                .to_sql_string_with_bind_params::<Numbered>(&[], &[(0, 3)])
+                .unwrap(),
+            r#""id" in ($1, $2, $3) and "upvotes" > $4"#
+        );
+    }
+
+    #[test]
+    fn empty_or_unknown_list_fails() {
+        assert!(
+            CONDITION
+                .to_sql_string_with_bind_params::<Numbered>(&[], &[(0, 0)])
+                .is_err()
+        );
+        assert!(CONDITION.to_sql_string::<Numbered>().is_err());
+    }
+}
@@ -6,6 +6,8 @@ pub enum Lit {
     Str(&'static str),
     Bool(bool),
     Null,
+    /// A string literal of the given type, e.g. `date '2024-01-01'`.
+    Typed(&'static str, &'static str),
 }
 
 impl kosame_sql::FmtSql for Lit {
@@ -20,6 +22,9 @@ impl kosame_sql::FmtSql for Lit {
             Self::Str(inner) => write!(formatter, "'{}'", inner.replace('\'', "''")),
             Self::Bool(inner) => write!(formatter, "{inner}"),
             Self::Null => formatter.write_str("null"),
+            Self::Typed(type_name, inner) => {
+                write!(formatter, "{type_name} '{}'", inner.replace('\'', "''"))
+            }
         }
     }
 }
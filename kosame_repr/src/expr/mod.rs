@@ -1,46 +1,105 @@
+mod array;
+mod between;
 mod binary;
 mod bind_param;
 mod call;
+mod case;
 mod cast;
+mod collate;
 mod column_ref;
+mod exists;
+mod extract;
+mod r#in;
 mod lit;
+mod optional;
 mod paren;
+mod quantified;
 mod raw;
+mod subquery;
+mod subscript;
 mod unary;
 
+pub use array::*;
+pub use between::*;
 pub use binary::*;
 pub use bind_param::*;
 pub use call::*;
+pub use case::*;
 pub use cast::*;
+pub use collate::*;
 pub use column_ref::*;
+pub use exists::*;
+pub use extract::*;
+pub use r#in::*;
 pub use lit::*;
+pub use optional::*;
 pub use paren::*;
+pub use quantified::*;
 pub use raw::*;
+pub use subquery::*;
+pub use subscript::*;
 pub use unary::*;
 
 pub enum Expr<'a> {
+    Array(Array<'a>),
+    Between(Between<'a>),
     Binary(Binary<'a>),
     BindParam(BindParam<'a>),
     Call(Call<'a>),
+    Case(Case<'a>),
     Cast(Cast<'a>),
+    Collate(Collate<'a>),
     ColumnRef(ColumnRef<'a>),
+    Exists(Exists<'a>),
+    Extract(Extract<'a>),
+    In(In<'a>),
     Lit(Lit),
+    Optional(Optional<'a>),
     Paren(Paren<'a>),
+    Quantified(Quantified<'a>),
     Raw(Raw<'a>),
+    Subquery(Subquery<'a>),
+    Subscript(Subscript<'a>),
     Unary(Unary<'a>),
 }
 
+impl Expr<'_> {
+    /// Whether the expression is left out of the SQL because it only consists of conditions
+    /// that refer to omitted bind parameters.
+    #[must_use]
+    pub fn is_omitted<D: kosame_sql::Dialect>(&self, formatter: &kosame_sql::Formatter<D>) -> bool {
+        match self {
+            Self::Optional(optional) => optional.is_omitted(formatter),
+            Self::Binary(binary) if matches!(binary.op(), BinOp::And) => {
+                binary.left().is_omitted(formatter) && binary.right().is_omitted(formatter)
+            }
+            _ => false,
+        }
+    }
+}
+
 macro_rules! variants {
     ($macro:ident!()) => {
         $macro!(
+            Array
+            Between
             Binary
             BindParam
             Call
+            Case
             Cast
+            Collate
             ColumnRef
+            Exists
+            Extract
+            In
             Lit
+            Optional
             Paren
+            Quantified
             Raw
+            Subquery
+            Subscript
             Unary
         )
     };
@@ -0,0 +1,48 @@
+use super::{BindParam, Expr};
+
+/// A condition that refers to optional bind parameters, e.g. `title ilike :q?`. It is left out of
+/// the SQL if any of these bind parameters is omitted.
+pub struct Optional<'a> {
+    bind_params: &'a [BindParam<'a>],
+    expr: &'a Expr<'a>,
+}
+
+impl<'a> Optional<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(bind_params: &'a [BindParam<'a>], expr: &'a Expr<'a>) -> Self {
+        Self { bind_params, expr }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn bind_params(&self) -> &'a [BindParam<'a>] {
+        self.bind_params
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn expr(&self) -> &'a Expr<'a> {
+        self.expr
+    }
+
+    #[must_use]
+    pub fn is_omitted<D: kosame_sql::Dialect>(&self, formatter: &kosame_sql::Formatter<D>) -> bool {
+        self.bind_params
+            .iter()
+            .any(|bind_param| formatter.is_bind_param_omitted(bind_param.ordinal()))
+    }
+}
+
+impl kosame_sql::FmtSql for Optional<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        if self.is_omitted(formatter) {
+            return Ok(());
+        }
+        self.expr.fmt_sql(formatter)
+    }
+}
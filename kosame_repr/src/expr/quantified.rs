@@ -0,0 +1,51 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+/// An array comparison such as the right-hand side of `id = any(:ids)`.
+pub struct Quantified<'a> {
+    quantifier: Quantifier,
+    expr: &'a Expr<'a>,
+}
+
+impl<'a> Quantified<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(quantifier: Quantifier, expr: &'a Expr<'a>) -> Self {
+        Self { quantifier, expr }
+    }
+}
+
+impl kosame_sql::FmtSql for Quantified<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.quantifier.fmt_sql(formatter)?;
+        formatter.write_str("(")?;
+        self.expr.fmt_sql(formatter)?;
+        formatter.write_str(")")?;
+        Ok(())
+    }
+}
+
+pub enum Quantifier {
+    Any,
+    All,
+    Some,
+}
+
+impl kosame_sql::FmtSql for Quantifier {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        match self {
+            Self::Any => formatter.write_str("any"),
+            Self::All => formatter.write_str("all"),
+            Self::Some => formatter.write_str("some"),
+        }
+    }
+}
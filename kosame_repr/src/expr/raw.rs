@@ -1,14 +1,16 @@
 use std::fmt::Write;
 
+use super::Expr;
+
 pub struct Raw<'a> {
-    string: &'a str,
+    segments: &'a [RawSegment<'a>],
 }
 
 impl<'a> Raw<'a> {
     #[inline]
     #[must_use]
-    pub const fn new(string: &'a str) -> Self {
-        Self { string }
+    pub const fn new(segments: &'a [RawSegment<'a>]) -> Self {
+        Self { segments }
     }
 }
 
@@ -18,6 +20,28 @@ impl kosame_sql::FmtSql for Raw<'_> {
         &self,
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
-        formatter.write_str(self.string)
+        for segment in self.segments {
+            segment.fmt_sql(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+/// A part of raw SQL text, either inserted verbatim or an interpolated expression.
+pub enum RawSegment<'a> {
+    Text(&'a str),
+    Expr(Expr<'a>),
+}
+
+impl kosame_sql::FmtSql for RawSegment<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        match self {
+            Self::Text(text) => formatter.write_str(text),
+            Self::Expr(expr) => expr.fmt_sql(formatter),
+        }
     }
 }
@@ -0,0 +1,50 @@
+use std::fmt::Write;
+
+use super::Expr;
+
+pub struct Subscript<'a> {
+    expr: &'a Expr<'a>,
+    lower: Option<&'a Expr<'a>>,
+    upper: Option<&'a Expr<'a>>,
+    slice: bool,
+}
+
+impl<'a> Subscript<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        expr: &'a Expr<'a>,
+        lower: Option<&'a Expr<'a>>,
+        upper: Option<&'a Expr<'a>>,
+        slice: bool,
+    ) -> Self {
+        Self {
+            expr,
+            lower,
+            upper,
+            slice,
+        }
+    }
+}
+
+impl kosame_sql::FmtSql for Subscript<'_> {
+    #[inline]
+    fn fmt_sql<D: kosame_sql::Dialect>(
+        &self,
+        formatter: &mut kosame_sql::Formatter<D>,
+    ) -> kosame_sql::Result {
+        self.expr.fmt_sql(formatter)?;
+        formatter.write_str("[")?;
+        if let Some(lower) = self.lower {
+            lower.fmt_sql(formatter)?;
+        }
+        if self.slice {
+            formatter.write_str(":")?;
+        }
+        if let Some(upper) = self.upper {
+            upper.fmt_sql(formatter)?;
+        }
+        formatter.write_str("]")?;
+        Ok(())
+    }
+}
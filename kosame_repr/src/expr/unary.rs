@@ -24,6 +24,16 @@ impl kosame_sql::FmtSql for Unary<'_> {
         match self.op.position() {
             Position::Prefix => {
                 self.op.fmt_sql(formatter)?;
+                // `--` would start a comment.
+                if let (
+                    UnaryOp::Minus,
+                    Expr::Unary(Unary {
+                        op: UnaryOp::Minus, ..
+                    }),
+                ) = (&self.op, self.operand)
+                {
+                    formatter.write_str(" ")?;
+                }
                 self.operand.fmt_sql(formatter)?;
             }
             Position::Postfix => {
@@ -36,6 +46,8 @@ impl kosame_sql::FmtSql for Unary<'_> {
 }
 
 pub enum UnaryOp {
+    Plus,
+    Minus,
     Not,
 }
 
@@ -49,7 +61,7 @@ impl UnaryOp {
     #[must_use]
     pub fn position(&self) -> Position {
         match self {
-            Self::Not => Position::Prefix,
+            Self::Plus | Self::Minus | Self::Not => Position::Prefix,
         }
     }
 }
@@ -61,6 +73,8 @@ impl kosame_sql::FmtSql for UnaryOp {
         formatter: &mut kosame_sql::Formatter<D>,
     ) -> kosame_sql::Result {
         match self {
+            Self::Plus => formatter.write_str("+"),
+            Self::Minus => formatter.write_str("-"),
             Self::Not => formatter.write_str("not "),
         }
     }
@@ -8,6 +8,12 @@ impl<'a> Ident<'a> {
         Self(ident)
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn as_str(&self) -> &'a str {
+        self.0
+    }
+
     #[inline]
     #[must_use]
     pub const fn from_option(ident: Option<&'a str>) -> Option<Self> {
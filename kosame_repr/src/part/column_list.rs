@@ -1,5 +1,7 @@
 use std::fmt::Write;
 
+use crate::Ident;
+
 pub struct ColumnList<'a> {
     columns: &'a [&'a str],
 }
@@ -24,7 +26,12 @@ impl kosame_sql::FmtSql for ColumnList<'_> {
         D: kosame_sql::Dialect,
     {
         formatter.write_str(" (")?;
-        kosame_sql::Punctuated::new(self.columns, ",").fmt_sql(formatter)?;
+        for (index, column) in self.columns.iter().enumerate() {
+            if index > 0 {
+                formatter.write_str(", ")?;
+            }
+            Ident::new(column).fmt_sql(formatter)?;
+        }
         formatter.write_str(")")?;
         Ok(())
     }
@@ -0,0 +1,36 @@
+use std::fmt::Write;
+
+use crate::expr::Expr;
+
+pub struct Distinct<'a> {
+    on: Option<&'a [Expr<'a>]>,
+}
+
+impl<'a> Distinct<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(on: Option<&'a [Expr<'a>]>) -> Self {
+        Self { on }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn on(&self) -> Option<&'a [Expr<'a>]> {
+        self.on
+    }
+}
+
+impl kosame_sql::FmtSql for Distinct<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        formatter.write_str("distinct ")?;
+        if let Some(on) = self.on {
+            formatter.write_str("on (")?;
+            kosame_sql::Punctuated::new(on, ",").fmt_sql(formatter)?;
+            formatter.write_str(") ")?;
+        }
+        Ok(())
+    }
+}
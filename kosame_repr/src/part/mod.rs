@@ -1,9 +1,13 @@
 mod column_list;
+mod distinct;
 mod set_op;
 mod table_alias;
 mod target_table;
+mod window_spec;
 
 pub use column_list::*;
+pub use distinct::*;
 pub use set_op::*;
 pub use table_alias::*;
 pub use target_table::*;
+pub use window_spec::*;
@@ -0,0 +1,210 @@
+use std::fmt::Write;
+
+use crate::{Ident, clause::OrderByItem, expr::Expr};
+
+/// The window of a window function call, either a named window or an inline window definition.
+pub enum Over<'a> {
+    Window(&'a str),
+    Spec(WindowSpec<'a>),
+}
+
+impl kosame_sql::FmtSql for Over<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        formatter.write_str(" over ")?;
+        match self {
+            Self::Window(name) => Ident::new(name).fmt_sql(formatter)?,
+            Self::Spec(spec) => {
+                formatter.write_str("(")?;
+                spec.fmt_sql(formatter)?;
+                formatter.write_str(")")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A window definition like `partition by post_id order by upvotes desc`, without the
+/// surrounding parentheses.
+pub struct WindowSpec<'a> {
+    existing: Option<&'a str>,
+    partition_by: &'a [Expr<'a>],
+    order_by: &'a [OrderByItem<'a>],
+    frame: Option<Frame<'a>>,
+}
+
+impl<'a> WindowSpec<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(
+        existing: Option<&'a str>,
+        partition_by: &'a [Expr<'a>],
+        order_by: &'a [OrderByItem<'a>],
+        frame: Option<Frame<'a>>,
+    ) -> Self {
+        Self {
+            existing,
+            partition_by,
+            order_by,
+            frame,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn existing(&self) -> Option<&'a str> {
+        self.existing
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn partition_by(&self) -> &'a [Expr<'a>] {
+        self.partition_by
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn order_by(&self) -> &'a [OrderByItem<'a>] {
+        self.order_by
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn frame(&self) -> Option<&Frame<'a>> {
+        self.frame.as_ref()
+    }
+}
+
+impl kosame_sql::FmtSql for WindowSpec<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        let mut separator = "";
+        if let Some(existing) = self.existing {
+            Ident::new(existing).fmt_sql(formatter)?;
+            separator = " ";
+        }
+        if !self.partition_by.is_empty() {
+            formatter.write_str(separator)?;
+            formatter.write_str("partition by ")?;
+            kosame_sql::Punctuated::new(self.partition_by, ",").fmt_sql(formatter)?;
+            separator = " ";
+        }
+        if !self.order_by.is_empty() {
+            formatter.write_str(separator)?;
+            formatter.write_str("order by ")?;
+            kosame_sql::Punctuated::new(self.order_by, ",").fmt_sql(formatter)?;
+            separator = " ";
+        }
+        if let Some(frame) = &self.frame {
+            formatter.write_str(separator)?;
+            frame.fmt_sql(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+/// The frame clause of a window definition, e.g. `rows between unbounded preceding and current
+/// row`.
+pub struct Frame<'a> {
+    unit: FrameUnit,
+    start: FrameBound<'a>,
+    end: Option<FrameBound<'a>>,
+}
+
+impl<'a> Frame<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(unit: FrameUnit, start: FrameBound<'a>, end: Option<FrameBound<'a>>) -> Self {
+        Self { unit, start, end }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn unit(&self) -> FrameUnit {
+        self.unit
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn start(&self) -> &FrameBound<'a> {
+        &self.start
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn end(&self) -> Option<&FrameBound<'a>> {
+        self.end.as_ref()
+    }
+}
+
+impl kosame_sql::FmtSql for Frame<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        self.unit.fmt_sql(formatter)?;
+        if let Some(end) = &self.end {
+            formatter.write_str(" between ")?;
+            self.start.fmt_sql(formatter)?;
+            formatter.write_str(" and ")?;
+            end.fmt_sql(formatter)?;
+        } else {
+            formatter.write_str(" ")?;
+            self.start.fmt_sql(formatter)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy)]
+pub enum FrameUnit {
+    Range,
+    Rows,
+    Groups,
+}
+
+impl kosame_sql::FmtSql for FrameUnit {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::Range => formatter.write_str("range"),
+            Self::Rows => formatter.write_str("rows"),
+            Self::Groups => formatter.write_str("groups"),
+        }
+    }
+}
+
+pub enum FrameBound<'a> {
+    UnboundedPreceding,
+    Preceding(Expr<'a>),
+    CurrentRow,
+    Following(Expr<'a>),
+    UnboundedFollowing,
+}
+
+impl kosame_sql::FmtSql for FrameBound<'_> {
+    fn fmt_sql<D>(&self, formatter: &mut kosame_sql::Formatter<D>) -> kosame_sql::Result
+    where
+        D: kosame_sql::Dialect,
+    {
+        match self {
+            Self::UnboundedPreceding => formatter.write_str("unbounded preceding"),
+            Self::Preceding(offset) => {
+                offset.fmt_sql(formatter)?;
+                formatter.write_str(" preceding")
+            }
+            Self::CurrentRow => formatter.write_str("current row"),
+            Self::Following(offset) => {
+                offset.fmt_sql(formatter)?;
+                formatter.write_str(" following")
+            }
+            Self::UnboundedFollowing => formatter.write_str("unbounded following"),
+        }
+    }
+}
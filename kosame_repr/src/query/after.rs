@@ -0,0 +1,20 @@
+use crate::expr::BindParam;
+
+pub struct After<'a> {
+    params: &'a [BindParam<'a>],
+}
+
+impl<'a> After<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn new(params: &'a [BindParam<'a>]) -> Self {
+        Self { params }
+    }
+
+    /// The bind parameters holding the cursor, one for each item of the node's `order by` clause.
+    #[inline]
+    #[must_use]
+    pub const fn params(&self) -> &'a [BindParam<'a>] {
+        self.params
+    }
+}
@@ -4,6 +4,8 @@ use crate::{
     schema::{Column, Relation},
 };
 
+// Fields are only ever constructed in constants, so boxing the node would not save anything.
+#[allow(clippy::large_enum_variant)]
 pub enum Field<'a> {
     Column {
         column: &'a Column<'a>,
@@ -13,6 +15,8 @@ pub enum Field<'a> {
         relation: &'a Relation<'a>,
         node: Node<'a>,
         alias: Option<&'a str>,
+        /// Set for recursive relations, holding the maximum number of levels to load.
+        depth: Option<u32>,
     },
     Expr {
         expr: Expr<'a>,
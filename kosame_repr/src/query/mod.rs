@@ -1,5 +1,7 @@
+mod after;
 mod field;
 mod node;
 
+pub use after::*;
 pub use field::*;
 pub use node::*;
@@ -1,15 +1,20 @@
 use crate::{
-    clause::{Limit, Offset, OrderBy, Where},
+    clause::{GroupBy, Having, Limit, Offset, OrderBy, Where},
+    part::Distinct,
     schema::Table,
 };
 
-use super::Field;
+use super::{After, Field};
 
 pub struct Node<'a> {
     table: &'a Table<'a>,
+    distinct: Option<Distinct<'a>>,
     star: bool,
     fields: &'a [Field<'a>],
     r#where: Option<Where<'a>>,
+    after: Option<After<'a>>,
+    group_by: Option<GroupBy<'a>>,
+    having: Option<Having<'a>>,
     order_by: Option<OrderBy<'a>>,
     limit: Option<Limit<'a>>,
     offset: Option<Offset<'a>>,
@@ -18,20 +23,29 @@ pub struct Node<'a> {
 impl<'a> Node<'a> {
     #[inline]
     #[must_use]
+    #[allow(clippy::too_many_arguments)]
     pub const fn new(
         table: &'a Table<'a>,
+        distinct: Option<Distinct<'a>>,
         star: bool,
         fields: &'a [Field<'a>],
         r#where: Option<Where<'a>>,
+        after: Option<After<'a>>,
+        group_by: Option<GroupBy<'a>>,
+        having: Option<Having<'a>>,
         order_by: Option<OrderBy<'a>>,
         limit: Option<Limit<'a>>,
         offset: Option<Offset<'a>>,
     ) -> Self {
         Self {
             table,
+            distinct,
             star,
             fields,
             r#where,
+            after,
+            group_by,
+            having,
             order_by,
             limit,
             offset,
@@ -44,6 +58,12 @@ impl<'a> Node<'a> {
         self.table
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn distinct(&self) -> Option<&Distinct<'_>> {
+        self.distinct.as_ref()
+    }
+
     #[inline]
     #[must_use]
     pub const fn star(&self) -> bool {
@@ -62,6 +82,24 @@ impl<'a> Node<'a> {
         self.r#where.as_ref()
     }
 
+    #[inline]
+    #[must_use]
+    pub const fn after(&self) -> Option<&After<'_>> {
+        self.after.as_ref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn group_by(&self) -> Option<&GroupBy<'_>> {
+        self.group_by.as_ref()
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn having(&self) -> Option<&Having<'_>> {
+        self.having.as_ref()
+    }
+
     #[inline]
     #[must_use]
     pub const fn order_by(&self) -> Option<&OrderBy<'_>> {
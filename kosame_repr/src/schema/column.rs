@@ -56,4 +56,15 @@ impl<'a> Column<'a> {
     pub const fn default(&self) -> Option<&'a Expr<'_>> {
         self.default
     }
+
+    /// Whether an insert must provide a value for the column, because it is not null and has
+    /// no default.
+    #[must_use]
+    pub const fn is_required(&self) -> bool {
+        let serial = matches!(
+            self.data_type.as_str().as_bytes(),
+            b"smallserial" | b"serial" | b"bigserial"
+        );
+        (self.not_null || self.primary_key) && self.default.is_none() && !serial
+    }
 }
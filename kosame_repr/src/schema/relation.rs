@@ -1,13 +1,13 @@
-use crate::Ident;
+use crate::{Ident, expr::Lit};
 
 use super::Column;
 
 pub struct Relation<'a> {
     name: Ident<'a>,
     source_table: Ident<'a>,
-    source_columns: &'a [&'a Column<'a>],
+    source_columns: &'a [RelationColumn<'a>],
     target_table: Ident<'a>,
-    target_columns: &'a [&'a Column<'a>],
+    target_columns: &'a [RelationColumn<'a>],
 }
 
 impl<'a> Relation<'a> {
@@ -16,9 +16,9 @@ impl<'a> Relation<'a> {
     pub const fn new(
         name: &'a str,
         source_table: &'a str,
-        source_columns: &'a [&'a Column],
+        source_columns: &'a [RelationColumn<'a>],
         target_table: &'a str,
-        target_columns: &'a [&'a Column],
+        target_columns: &'a [RelationColumn<'a>],
     ) -> Self {
         Self {
             name: Ident::new(name),
@@ -43,7 +43,7 @@ impl<'a> Relation<'a> {
 
     #[inline]
     #[must_use]
-    pub const fn source_columns(&self) -> &'a [&'a Column<'a>] {
+    pub const fn source_columns(&self) -> &'a [RelationColumn<'a>] {
         self.source_columns
     }
 
@@ -55,15 +55,51 @@ impl<'a> Relation<'a> {
 
     #[inline]
     #[must_use]
-    pub const fn target_columns(&self) -> &'a [&'a Column<'_>] {
+    pub const fn target_columns(&self) -> &'a [RelationColumn<'a>] {
         self.target_columns
     }
 
+    /// Returns `true` if the relation points back at the table it is declared on.
+    #[must_use]
+    pub const fn is_self_referencing(&self) -> bool {
+        let source = self.source_table.as_str().as_bytes();
+        let target = self.target_table.as_str().as_bytes();
+        if source.len() != target.len() {
+            return false;
+        }
+        let mut index = 0;
+        while index < source.len() {
+            if source[index] != target[index] {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Returns the pairs of source and target columns that must be equal for two rows to be
+    /// related. Either side of a pair may be a constant, such as the discriminator of a
+    /// polymorphic relation.
     #[inline]
-    pub fn column_pairs(&self) -> impl Iterator<Item = (&'a Column<'a>, &'a Column<'a>)> {
-        self.source_columns
-            .iter()
-            .zip(self.target_columns)
-            .map(|(a, b)| (*a, *b))
+    pub fn column_pairs(
+        &self,
+    ) -> impl Iterator<Item = (&'a RelationColumn<'a>, &'a RelationColumn<'a>)> {
+        self.source_columns.iter().zip(self.target_columns)
+    }
+}
+
+pub enum RelationColumn<'a> {
+    Column(&'a Column<'a>),
+    Lit(Lit),
+}
+
+impl<'a> RelationColumn<'a> {
+    #[inline]
+    #[must_use]
+    pub const fn column(&self) -> Option<&'a Column<'a>> {
+        match self {
+            Self::Column(column) => Some(column),
+            Self::Lit(_) => None,
+        }
     }
 }
@@ -40,4 +40,142 @@ impl<'a> Table<'a> {
     pub const fn relations(&self) -> &'a [&'a Relation<'a>] {
         self.relations
     }
+
+    /// Panics if an insert into the given columns leaves out a required column. Called in a
+    /// const context, this turns into a compile error.
+    pub const fn assert_insert_columns(&self, columns: &[&str]) {
+        let mut index = 0;
+        while index < self.columns.len() {
+            let column = self.columns[index];
+            if column.is_required() && !contains(columns, column.name.as_str()) {
+                panic_missing_column(column.name.as_str());
+            }
+            index += 1;
+        }
+    }
+
+    /// Panics if an insert without a column list, which provides values for the first `count`
+    /// columns of the table, leaves out a required column.
+    pub const fn assert_insert_column_count(&self, count: usize) {
+        assert!(
+            count <= self.columns.len(),
+            "insert has more values than the table has columns"
+        );
+        let mut index = count;
+        while index < self.columns.len() {
+            let column = self.columns[index];
+            if column.is_required() {
+                panic_missing_column(column.name.as_str());
+            }
+            index += 1;
+        }
+    }
+}
+
+const fn contains(columns: &[&str], column: &str) -> bool {
+    let mut index = 0;
+    while index < columns.len() {
+        if str_eq(columns[index], column) {
+            return true;
+        }
+        index += 1;
+    }
+    false
+}
+
+const fn str_eq(a: &str, b: &str) -> bool {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut index = 0;
+    while index < a.len() {
+        if a[index] != b[index] {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
+
+/// Panics with a message naming the column. Const panics cannot format their arguments, so the
+/// message is assembled by hand.
+const fn panic_missing_column(column: &str) -> ! {
+    const PREFIX: &[u8] = b"insert does not provide a value for the column `";
+    const SUFFIX: &[u8] = b"`, which is not null and has no default";
+
+    let mut buffer = [0; 256];
+    let mut length = 0;
+    let parts = [PREFIX, column.as_bytes(), SUFFIX];
+    let mut part = 0;
+    while part < parts.len() {
+        let mut index = 0;
+        while index < parts[part].len() && length < buffer.len() {
+            buffer[length] = parts[part][index];
+            length += 1;
+            index += 1;
+        }
+        part += 1;
+    }
+
+    match core::str::from_utf8(buffer.split_at(length).0) {
+        Ok(message) => panic!("{}", message),
+        Err(_) => panic!("insert does not provide a value for a required column"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Table;
+    use crate::{
+        expr::{Expr, Lit},
+        schema::Column,
+    };
+
+    const ZERO: Expr = Expr::Lit(Lit::Int(0));
+    const POSTS: Table = Table::new(
+        "posts",
+        &[
+            &Column::new("id", "serial", true, false, None),
+            &Column::new("title", "text", false, true, None),
+            &Column::new("upvotes", "int", false, true, Some(&ZERO)),
+            &Column::new("content", "text", false, false, None),
+        ],
+        &[],
+    );
+
+    // Evaluated by the compiler, like the assertions generated for `insert` statements.
+    const _: () = POSTS.assert_insert_columns(&["title"]);
+    const _: () = POSTS.assert_insert_column_count(2);
+
+    #[test]
+    fn insert_columns() {
+        POSTS.assert_insert_columns(&["id", "title", "upvotes", "content"]);
+        POSTS.assert_insert_columns(&["content", "title"]);
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "insert does not provide a value for the column `title`, which is not null and has no default"
+    )]
+    fn insert_columns_missing_required() {
+        POSTS.assert_insert_columns(&["id", "upvotes", "content"]);
+    }
+
+    #[test]
+    fn insert_column_count() {
+        POSTS.assert_insert_column_count(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "the column `title`")]
+    fn insert_column_count_missing_required() {
+        POSTS.assert_insert_column_count(1);
+    }
+
+    #[test]
+    #[should_panic(expected = "insert has more values than the table has columns")]
+    fn insert_column_count_too_many() {
+        POSTS.assert_insert_column_count(5);
+    }
 }
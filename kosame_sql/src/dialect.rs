@@ -1,6 +1,10 @@
 use std::fmt::Write;
 
 pub trait Dialect {
+    /// Whether a single bind parameter can hold an array. Dialects without arrays expand list
+    /// parameters, such as the one in `in :ids`, into one placeholder per element.
+    const SUPPORTS_ARRAYS: bool;
+
     fn fmt_ident(formatter: &mut impl Write, name: &str) -> std::fmt::Result;
     fn fmt_bind_param(formatter: &mut impl Write, name: &str, ordinal: u32) -> std::fmt::Result;
 }
@@ -8,11 +8,38 @@ pub trait FmtSql {
         D: Dialect;
 
     fn to_sql_string<D>(&self) -> Result<String, crate::Error>
+    where
+        D: Dialect,
+    {
+        self.to_sql_string_with_bind_params::<D>(&[], &[])
+    }
+
+    /// Like [`FmtSql::to_sql_string`], but leaves out the conditions referring to the omitted
+    /// bind parameters and renumbers the remaining ones.
+    fn to_sql_string_with_omitted_bind_params<D>(
+        &self,
+        omitted_bind_params: &[u32],
+    ) -> Result<String, crate::Error>
+    where
+        D: Dialect,
+    {
+        self.to_sql_string_with_bind_params::<D>(omitted_bind_params, &[])
+    }
+
+    /// Like [`FmtSql::to_sql_string_with_omitted_bind_params`], but also expands list
+    /// parameters to the given number of elements for dialects without array support.
+    fn to_sql_string_with_bind_params<D>(
+        &self,
+        omitted_bind_params: &[u32],
+        list_lens: &[(u32, usize)],
+    ) -> Result<String, crate::Error>
     where
         D: Dialect,
     {
         let mut result = String::new();
-        let mut formatter = Formatter::<D>::new(&mut result);
+        let mut formatter = Formatter::<D>::new(&mut result)
+            .with_omitted_bind_params(omitted_bind_params)
+            .with_list_lens(list_lens);
         self.fmt_sql(&mut formatter)?;
         Ok(result)
     }
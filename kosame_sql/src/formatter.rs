@@ -4,6 +4,8 @@ use crate::Dialect;
 
 pub struct Formatter<'a, D> {
     buf: &'a mut (dyn Write + 'a),
+    list_lens: &'a [(u32, usize)],
+    omitted_bind_params: &'a [u32],
     _dialect: PhantomData<D>,
 }
 
@@ -14,9 +16,66 @@ where
     pub fn new(buf: &'a mut (dyn Write + 'a)) -> Self {
         Self {
             buf,
+            list_lens: &[],
+            omitted_bind_params: &[],
             _dialect: PhantomData,
         }
     }
+
+    /// Sets the number of elements bound to the list parameters with the given ordinals. This is
+    /// required to expand list parameters for dialects without array support.
+    #[must_use]
+    pub fn with_list_lens(mut self, list_lens: &'a [(u32, usize)]) -> Self {
+        self.list_lens = list_lens;
+        self
+    }
+
+    /// Omits the bind parameters with the given ordinals, e.g. optional bind parameters that are
+    /// `None`. Conditions referring to them are left out of the SQL, and the remaining bind
+    /// parameters are renumbered to close the gaps.
+    #[must_use]
+    pub fn with_omitted_bind_params(mut self, ordinals: &'a [u32]) -> Self {
+        self.omitted_bind_params = ordinals;
+        self
+    }
+
+    #[must_use]
+    pub fn list_len(&self, ordinal: u32) -> Option<usize> {
+        self.list_lens
+            .iter()
+            .find(|(list_ordinal, _)| *list_ordinal == ordinal)
+            .map(|(_, len)| *len)
+    }
+
+    #[must_use]
+    pub fn is_bind_param_omitted(&self, ordinal: u32) -> bool {
+        self.omitted_bind_params.contains(&ordinal)
+    }
+
+    /// The ordinal of a bind parameter after the omitted bind parameters before it are removed.
+    /// For dialects without array support, the list parameters before it take up one ordinal per
+    /// element.
+    #[must_use]
+    pub fn bind_param_ordinal(&self, ordinal: u32) -> u32 {
+        let omitted_before = self
+            .omitted_bind_params
+            .iter()
+            .filter(|omitted| **omitted < ordinal)
+            .count();
+        let expanded_before = if D::SUPPORTS_ARRAYS {
+            0
+        } else {
+            self.list_lens
+                .iter()
+                .filter(|(list_ordinal, _)| {
+                    *list_ordinal < ordinal && !self.is_bind_param_omitted(*list_ordinal)
+                })
+                .map(|(_, len)| len.saturating_sub(1))
+                .sum()
+        };
+        ordinal + u32::try_from(expanded_before).expect("bind param count should fit into u32")
+            - u32::try_from(omitted_before).expect("bind param count should fit into u32")
+    }
 }
 
 impl<D> Write for Formatter<'_, D> {
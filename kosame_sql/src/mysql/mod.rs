@@ -1,6 +1,10 @@
+use std::fmt::Write;
+
 pub enum Dialect {}
 
 impl crate::Dialect for Dialect {
+    const SUPPORTS_ARRAYS: bool = false;
+
     fn fmt_ident(formatter: &mut impl Write, name: &str) -> std::fmt::Result {
         write!(formatter, "`{name}`")
     }
@@ -3,6 +3,8 @@ use std::fmt::Write;
 pub enum Dialect {}
 
 impl crate::Dialect for Dialect {
+    const SUPPORTS_ARRAYS: bool = true;
+
     fn fmt_ident(formatter: &mut impl Write, name: &str) -> std::fmt::Result {
         write!(formatter, "\"{name}\"")
     }